@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines()
+            .map(|s| s.parse::<i32>().unwrap())
+            .collect()
+    }
+
+    fn part1(module_mass: &Self::Input<'_>) -> impl Display {
+        module_mass.iter()
+            .map(|m| (m / 3) - 2)
+            .sum::<i32>()
+    }
+
+    fn part2(module_mass: &Self::Input<'_>) -> impl Display {
+        module_mass.iter()
+            .map(|m| {
+                let mut m = *m;
+                let mut fuel = 0;
+                while m > 0 {
+                    let new_m = (m / 3) - 2;
+                    if new_m > 0 {
+                        fuel += new_m;
+                    }
+                    m = new_m;
+                }
+
+                fuel
+            })
+            .sum::<i32>()
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt::Display;
+use std::iter;
+
+use aoc::Solution;
+use intcode::Program;
+
+
+pub fn run_program(mut program: Program, noun: i64, verb: i64) -> i64 {
+    program[1] = noun;
+    program[2] = verb;
+
+    program.run(iter::empty(), |_| {});
+
+    program[0]
+}
+
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        run_program(program.clone(), 12, 2)
+    }
+
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        for noun in 0..100 {
+            for verb in 0..100 {
+                if run_program(program.clone(), noun, verb) == 19690720 {
+                    return noun * 100 + verb;
+                }
+            }
+        }
+
+        panic!("No noun and verb found");
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::{HashMap, hash_map::Entry};
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+fn get_wire_positions(wire: &[&str]) -> HashMap<(i32, i32), i32> {
+    let mut wire_locations = HashMap::new();
+
+    let mut current_pos = (0, 0);
+    let mut current_steps = 0;
+    for instruction in wire {
+        let (i, dist) = instruction.split_at(1);
+        let dist: i32 = dist.parse().unwrap();
+
+        let (dx, dy) = match i {
+            "R" => (1, 0),
+            "U" => (0, 1),
+            "L" => (-1, 0),
+            "D" => (0, -1),
+            _ => panic!("Invalid instruction"),
+        };
+
+        for i in 1..(dist + 1) {
+            current_pos.0 += dx;
+            current_pos.1 += dy;
+
+            if let Entry::Vacant(e) = wire_locations.entry(current_pos) {
+                e.insert(current_steps + i);
+            }
+        }
+
+        current_steps += dist;
+    }
+
+    wire_locations
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 3;
+
+    /// Crossings of the two wires, with the steps of each wire to get there.
+    type Input<'a> = Vec<((i32, i32), i32, i32)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let wires: Vec<_> = input
+            .lines()
+            .map(|s| s.split(',').collect::<Vec<_>>())
+            .collect();
+
+        let wire1_locations = get_wire_positions(&wires[0]);
+        let wire2_locations = get_wire_positions(&wires[1]);
+        wire1_locations.into_iter()
+            .filter_map(|(pos, steps)| {
+                wire2_locations.get(&pos)
+                    .map(|s| (pos, steps, *s))
+            })
+            .collect()
+    }
+
+    fn part1(crossings: &Self::Input<'_>) -> impl Display {
+        crossings.iter()
+            .map(|((x, y), _, _)| {
+                let x: i32 = *x;
+                let y: i32 = *y;
+
+                x.abs() + y.abs()
+            })
+            .min().unwrap()
+    }
+
+    fn part2(crossings: &Self::Input<'_>) -> impl Display {
+        crossings.iter()
+            .map(|(_, s1, s2)| s1 + s2)
+            .min().unwrap()
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+fn digits(number: &str) -> [i32; 6] {
+    let mut digits = [0; 6];
+    for (d, c) in digits.iter_mut().zip(number.chars()) {
+        *d = c.to_digit(10).unwrap() as i32;
+    }
+    digits
+}
+
+/// Counts the valid passwords within the range, which is decided by the sizes of the groups of equal digits.
+fn count_valid_passwords(min: [i32; 6], max: [i32; 6], is_valid: impl Fn(&[i32]) -> bool) -> u32 {
+    // Start at the first number with never decreasing digits, e.g. 245182 becomes 245555
+    let mut current = min;
+    if let Some(i) = (1..6).find(|&i| current[i] < current[i - 1]) {
+        let fill = current[i - 1];
+        current[i..].fill(fill);
+    }
+
+    let mut valid_passwords = 0;
+    while current <= max {
+        // Create vector with the amount of items in each number group.
+        let mut counts = Vec::with_capacity(5);
+        let mut index = 0;
+        while index < 6 {
+            let mut c = 0;
+            let current_number = current[index];
+            while index < 6 && current[index] == current_number {
+                c += 1;
+                index += 1;
+            }
+            counts.push(c);
+        }
+
+        if is_valid(&counts) {
+            valid_passwords += 1;
+        }
+
+
+        if current == [9; 6] {
+            break;
+        }
+
+        // Check from the left where to start incrementing
+        let mut start_i = 5;
+        while current[start_i] == 9 {
+            start_i -= 1;
+        }
+
+        // Increment from left to right
+        for i in start_i..6 {
+            if current[i] == 9 {
+                current[i] = current[i - 1];
+            } else {
+                current[i] += 1;
+            }
+        }
+    }
+
+    valid_passwords
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 4;
+
+    /// Digits of the lowest and highest password of the range
+    type Input<'a> = ([i32; 6], [i32; 6]);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let (min, max) = input.trim().split_once('-').unwrap();
+        (digits(min), digits(max))
+    }
+
+    fn part1(&(min, max): &Self::Input<'_>) -> impl Display {
+        // Some adjacent digits are the same
+        count_valid_passwords(min, max, |counts| counts.len() < 6)
+    }
+
+    fn part2(&(min, max): &Self::Input<'_>) -> impl Display {
+        // Some pair of adjacent digits is not part of a larger group
+        count_valid_passwords(min, max, |counts| counts.contains(&2))
+    }
+}
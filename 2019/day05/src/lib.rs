@@ -0,0 +1,34 @@
+use std::fmt::Display;
+use std::iter;
+
+use aoc::Solution;
+use intcode::Program;
+
+
+fn run_program(mut program: Program, input: i64) -> i64 {
+    let mut output = 0;
+    program.run(iter::once(input), |v| output = v);
+
+    output
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        run_program(program.clone(), 1)
+    }
+
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        run_program(program.clone(), 5)
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::Display;
+use std::ops::Range;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use aoc::Solution;
+use intcode::Program;
+use itertools::Itertools;
+
+
+fn run_program_thread(mut program: Program, input: Receiver<i64>, output: Sender<i64>) {
+    // Receive input from channel and send output on channel.
+    // Sending may fail once the next amp has halted, which is fine.
+    program.run(input.iter(), |v| {
+        let _ = output.send(v);
+    });
+}
+
+fn run_amps(program: &Program, phase_range: Range<i64>) -> i64 {
+    let mut max_output = 0;
+
+    for phase_setting in phase_range.permutations(5) {
+        // Create channels for communication between the amps.
+        let (input, ia) = mpsc::channel();
+        let (oa, ib) = mpsc::channel();
+        let (ob, ic) = mpsc::channel();
+        let (oc, id) = mpsc::channel();
+        let (od, ie) = mpsc::channel();
+        let (oe, output) = mpsc::channel();
+
+        // Send phase settings
+        input.send(phase_setting[0]).unwrap();
+        oa.send(phase_setting[1]).unwrap();
+        ob.send(phase_setting[2]).unwrap();
+        oc.send(phase_setting[3]).unwrap();
+        od.send(phase_setting[4]).unwrap();
+
+        // Create threads for each amp
+
+        let amp_a_program = program.clone();
+        let _amp_a = thread::spawn(move || {
+            run_program_thread(amp_a_program, ia, oa);
+        });
+
+        let amp_b_program = program.clone();
+        let _amp_b = thread::spawn(move || {
+            run_program_thread(amp_b_program, ib, ob);
+        });
+
+        let amp_c_program = program.clone();
+        let _amp_c = thread::spawn(move || {
+            run_program_thread(amp_c_program, ic, oc);
+        });
+
+        let amp_d_program = program.clone();
+        let _amp_d = thread::spawn(move || {
+            run_program_thread(amp_d_program, id, od);
+        });
+
+        let amp_e_program = program.clone();
+        let _amp_e = thread::spawn(move || {
+            run_program_thread(amp_e_program, ie, oe);
+        });
+
+        // Start the program by sending the initial input
+        input.send(0).unwrap();
+
+        // Listen for outputs of amp e.
+        for o in output {
+            // Try to send the output back to amp a
+            if input.send(o).is_err() {
+                // If sending results in an error, the receiver is probably
+                // gone, which means that the program has halted.
+                // Thus, we are finished.
+
+                // Check the final output
+                if o > max_output {
+                    max_output = o;
+                }
+
+                break;
+            };
+        }
+    }
+
+    max_output
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        run_amps(program, 0..5)
+    }
+
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        run_amps(program, 5..10)
+    }
+}
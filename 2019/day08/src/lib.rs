@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use aoc::{ocr, Solution};
+use itertools::Itertools;
+
+const IMAGE_WIDTH: usize  = 25;
+const IMAGE_HEIGHT: usize =  6;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap())
+            .collect()
+    }
+
+    /// Corruption check
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let mut input_clone = input.clone();
+        let best_layer = input_clone
+            .chunks_mut(IMAGE_WIDTH * IMAGE_HEIGHT)
+            .map(|layer| {
+                layer.sort_unstable();
+
+                layer.iter_mut().dedup_with_count().collect_vec()
+            })
+            .min_by(|layer1, layer2| {
+                let layer1_zeros = if *layer1[0].1 == 0 { layer1[0].0 } else { usize::MAX };
+                let layer2_zeros = if *layer2[0].1 == 0 { layer2[0].0 } else { usize::MAX };
+
+                layer1_zeros.cmp(&layer2_zeros)
+            })
+            .unwrap();
+
+        best_layer[1].0 * best_layer[2].0
+    }
+
+    /// Message in the final image
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut final_image = [false; IMAGE_WIDTH * IMAGE_HEIGHT];
+        let layers: Vec<_> = input.chunks(IMAGE_WIDTH * IMAGE_HEIGHT).collect();
+
+        for (i, p) in final_image.iter_mut().enumerate() {
+            let mut layer_index = 0;
+            while layers[layer_index][i] == 2 {
+                layer_index += 1;
+            }
+
+            *p = layers[layer_index][i] == 1;
+        }
+
+        ocr::text(&final_image.chunks(IMAGE_WIDTH).collect_vec())
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::iter;
+
+use aoc::{ocr, Solution};
+use grid::{Direction, Heading};
+use intcode::{Program, Status};
+
+
+/// Runs the painting robot on the given canvas.
+fn paint(mut program: Program, mut canvas: HashMap<(i32, i32), i64>) -> HashMap<(i32, i32), i64> {
+    let mut position = (0, 0);
+    let mut facing = Direction::North;
+
+    let mut output = Vec::new();
+    loop {
+        // Provide the color of the current panel
+        let color = *canvas.get(&position).unwrap_or(&0);
+        let status = program.run(iter::once(color), |v| output.push(v));
+
+        for instr in output.chunks_exact(2) {
+            // Getting the color
+            canvas.insert(position, instr[0]);
+
+            // Getting the rotate instruction
+            facing = match instr[1] {
+                0 => facing.turn_left(),
+                1 => facing.turn_right(),
+                _ => unreachable!(),
+            };
+
+            // Move forward
+            let (dx, dy) = facing.offset();
+            position.0 += dx as i32;
+            position.1 += dy as i32;
+        }
+        output.clear();
+
+        if status == Status::Halted {
+            return canvas;
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    /// Painted panels
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        paint(program.clone(), HashMap::new()).len()
+    }
+
+    /// Registration identifier
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        // Make sure to start on a white square
+        let canvas = paint(program.clone(), HashMap::from([((0, 0), 1)]));
+
+        // Get the dimensions of the canvas
+        let mut min_x = i32::MAX;
+        let mut max_x = i32::MIN;
+        let mut min_y = i32::MAX;
+        let mut max_y = i32::MIN;
+        for (x, y) in canvas.keys() {
+            if *x < min_x {
+                min_x = *x;
+            }
+            if *x > max_x {
+                max_x = *x;
+            }
+            if *y < min_y {
+                min_y = *y;
+            }
+            if *y > max_y {
+                max_y = *y;
+            }
+        }
+
+        // Read the canvas
+        let pixels: Vec<Vec<_>> = (min_y..(max_y+1))
+            .map(|y| (min_x..(max_x+1))
+                .map(|x| canvas.get(&(x, y)) == Some(&1))
+                .collect())
+            .collect();
+
+        ocr::text(&pixels)
+    }
+}
@@ -0,0 +1,81 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::iter;
+
+use aoc::Solution;
+use intcode::{Program, Status};
+
+
+#[derive(Debug, Default)]
+struct Arcade {
+    block_tiles: i32,
+    paddle_location: (i64, i64),
+    ball_location: (i64, i64),
+    score: i64,
+}
+
+impl Arcade {
+    /// Plays the game until the program halts.
+    pub fn play(mut program: Program) -> Self {
+        let mut arcade = Self::default();
+
+        let mut output = Vec::new();
+        loop {
+            // Tilt joystick based on where the paddle is compared to the ball
+            let joystick =
+                match arcade.paddle_location.0.cmp(&arcade.ball_location.0) {
+                    Ordering::Less    => 1,
+                    Ordering::Equal   => 0,
+                    Ordering::Greater => -1,
+                };
+
+            let status = program.run(iter::once(joystick), |v| output.push(v));
+            for instr in output.chunks_exact(3) {
+                arcade.draw(instr[0], instr[1], instr[2]);
+            }
+            output.clear();
+
+            if status == Status::Halted {
+                return arcade;
+            }
+        }
+    }
+
+    fn draw(&mut self, x: i64, y: i64, tile: i64) {
+        if x == -1 && y == 0 {
+            self.score = tile;
+        } else {
+            match tile {
+                2 => self.block_tiles += 1,
+                3 => self.paddle_location = (x, y),
+                4 => self.ball_location = (x, y),
+                _ => (),
+            }
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        Arcade::play(program.clone()).block_tiles
+    }
+
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        // Play for free
+        let mut program = program.clone();
+        program[0] = 2;
+
+        Arcade::play(program).score
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::{VecDeque, HashSet};
+use std::fmt::Display;
+
+use aoc::Solution;
+use intcode::{Program, State};
+
+fn new_position(dir: i64, (x, y): (i32, i32)) -> (i32, i32) {
+    match dir {
+        1 => (x, y - 1),
+        2 => (x, y + 1),
+        3 => (x - 1, y),
+        4 => (x + 1, y),
+        _ => panic!("Invalid direction")
+    }
+}
+
+/// Moves the droid in the given direction and returns its status code.
+fn move_droid(program: &mut Program, dir: i64) -> i64 {
+    assert_eq!(program.resume(), State::NeedsInput);
+    program.input(dir);
+
+    match program.resume() {
+        State::Output(status) => status,
+        state => panic!("Expected status code, got {state:?}"),
+    }
+}
+
+/// Finds the oxygen system, returns the number of moves to get there,
+/// and the position and program of the droid next to it.
+fn find_oxygen_system(program: &Program) -> (i32, (i32, i32), Program) {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    queue.push_back((0, (0, 0), program.clone()));
+    visited.insert((0, 0));
+
+    while let Some((depth, pos, p)) = queue.pop_front() {
+        for dir in [1, 2, 3, 4] {
+            let mut new_p = p.clone();
+            let out = move_droid(&mut new_p, dir);
+
+            match out {
+                0 => continue,
+                1 => {
+                    let new_pos = new_position(dir, pos);
+                    if !visited.insert(new_pos) {
+                        continue;
+                    }
+
+                    queue.push_back((depth + 1, new_pos, new_p));
+                },
+                2 => return (depth + 1, pos, new_p),
+                _ => panic!("Invalid output"),
+            }
+        }
+    }
+
+    panic!("No oxygen system found");
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    /// Minimum moves required
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        find_oxygen_system(program).0
+    }
+
+    /// Minutes to fill the area
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        let (_, final_position, final_program) = find_oxygen_system(program);
+
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        queue.push_back((0, final_position, final_program));
+        visited.insert(final_position);
+
+        let mut max_depth = 0;
+
+        while let Some((depth, pos, p)) = queue.pop_front() {
+            if depth > max_depth {
+                max_depth = depth;
+            }
+
+            for dir in [1, 2, 3, 4] {
+                let mut new_p = p.clone();
+                let out = move_droid(&mut new_p, dir);
+
+                match out {
+                    0 => continue,
+                    1 | 2 => {
+                        let new_pos = new_position(dir, pos);
+                        if !visited.insert(new_pos) {
+                            continue;
+                        }
+
+                        queue.push_back((depth + 1, new_pos, new_p));
+                    },
+                    _ => panic!("Invalid output"),
+                }
+            }
+        }
+
+        max_depth
+    }
+}
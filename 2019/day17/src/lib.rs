@@ -0,0 +1,85 @@
+use std::fmt::Display;
+use std::iter;
+
+use aoc::Solution;
+use intcode::{ascii_input, Program};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    /// Alignment sum of the intersections
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        let mut output = Vec::new();
+        program.clone().run(iter::empty(), |v| output.push(v));
+
+        let image: Vec<Vec<char>> = output.split(|&v| v == 10)
+            .filter(|row| !row.is_empty())
+            .map(|row| row.iter().map(|&v| v as u8 as char).collect())
+            .collect();
+
+        let width = image[0].len();
+        let height = image.len();
+
+        let mut intersections = Vec::new();
+        for (y, row) in image.iter().enumerate().skip(1).take(height - 2) {
+            for (x, c) in row.iter().enumerate().skip(1).take(width - 2) {
+                // Check if this is an intersection
+                if *c == '#' && image[y - 1][x] == '#' && image[y][x - 1] == '#'
+                    && image[y][x + 1] == '#' && image[y + 1][x] == '#'
+                {
+                    intersections.push((x, y));
+                }
+            }
+        }
+
+        intersections.iter()
+            .map(|(x, y)| x * y)
+            .sum::<usize>()
+    }
+
+    /// Collected dust
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        // Found manually
+        let _path = [
+            "R", "8", "R", "10", "R", "10", // A
+            "R", "4", "R", "8", "R", "10", "R", "12",  // B
+            "R", "8", "R", "10", "R", "10", // A
+            "R", "12", "R", "4", "L", "12", "L", "12", // C
+            "R", "8", "R", "10", "R", "10",  // A
+            "R", "4", "R", "8", "R", "10", "R", "12",  // B
+            "R", "12", "R", "4", "L", "12", "L", "12", // C
+            "R", "8", "R", "10", "R", "10", // A
+            "R", "4", "R", "8", "R", "10", "R", "12", // B
+            "R", "12", "R", "4", "L", "12", "L", "12" // C
+        ];
+
+        let main = ["A", "B", "A", "C", "A", "B", "C", "A", "B", "C"];
+        let a = ["R", "8", "R", "10", "R", "10"];
+        let b = ["R", "4", "R", "8", "R", "10", "R", "12"];
+        let c = ["R", "12", "R", "4", "L", "12", "L", "12"];
+
+
+        let input_string = format!("{main}\n{a}\n{b}\n{c}\nn\n", main=main.join(","), a=a.join(","), b=b.join(","), c=c.join(","));
+
+        let mut program = program.clone();
+        program[0] = 2;
+        let mut collected_dust = 0;
+        program.run(ascii_input(&input_string), |v| {
+            // The dust amount is the only value outside of the ascii range
+            if v > 255 {
+                collected_dust = v;
+            }
+        });
+
+        collected_dust
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use intcode::{Profiler, Program};
+
+/// Deploys a drone at the given coordinates and returns whether it is pulled.
+fn is_pulled(program: &Program, profiler: &mut Option<Profiler>, x: i64, y: i64) -> i64 {
+    let mut program = program.clone();
+    let mut pulled = 0;
+
+    match profiler {
+        Some(profiler) => profiler.run(&mut program, [x, y].into_iter(), |v| pulled = v),
+        None => program.run([x, y].into_iter(), |v| pulled = v),
+    };
+
+    pulled
+}
+
+/// Counts the points affected by the tractor beam in the 50x50 area.
+pub fn affected_points(program: &Program, profiler: &mut Option<Profiler>) -> i64 {
+    let mut affected_points = 0;
+    for x in 0..50 {
+        for y in 0..50 {
+            affected_points += is_pulled(program, profiler, x, y);
+        }
+    }
+
+    affected_points
+}
+
+/// Finds the closest 100x100 square fitting in the beam.
+pub fn closest_square(program: &Program, profiler: &mut Option<Profiler>) -> i64 {
+    let mut current = (0, 99);
+    loop {
+        let o = is_pulled(program, profiler, current.0, current.1);
+        if o == 0 {
+            current.0 += 1;
+        } else if is_pulled(program, profiler, current.0 + 99, current.1 - 99) == 1 {
+            // Assume the entire thing fits
+            return current.0 * 10_000 + current.1 - 99;
+        } else {
+            current.1 += 1;
+        }
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 19;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        affected_points(program, &mut None)
+    }
+
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        closest_square(program, &mut None)
+    }
+}
@@ -1,40 +1,32 @@
-use intcode::Program;
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+
+use aoc::Solution;
+use day19_2019::{affected_points, closest_square, Day19};
+use intcode::Profiler;
 
-mod intcode;
 
 fn main() {
-    let program = Program::new(
-        include_str!("../input.txt")
-            .split(',')
-            .map(|s| s.parse::<i64>().unwrap())
-            .collect()
-    );
-
-    // Part 1
-
-    let mut affected_points = 0;
-    for x in 0..50 {
-        for y in 0..50 {
-            affected_points += program.clone().run([x, y]);
-        }
+    let input = aoc::input::<Day19>();
+
+    if !env::args().any(|a| a == "--profile") {
+        aoc::run::<Day19>(&input);
+        return;
     }
 
-    println!("Affected points: {affected_points}");
+    // Profile all drone deployments
+    let program = Day19::parse(&input);
+    let mut profiler = Some(Profiler::new());
 
+    println!("[Part 1] Affected points: {}", affected_points(&program, &mut profiler));
+    println!("[Part 2] Answer: {}", closest_square(&program, &mut profiler));
 
-    // Part 2
+    let profiler = profiler.unwrap();
+    println!();
+    print!("{profiler}");
 
-    let mut current = (0, 99);
-    loop {
-        let o = program.clone().run([current.0, current.1]);
-        if o == 0 {
-            current.0 += 1;
-        } else if program.clone().run([current.0 + 99, current.1 - 99]) == 1 {
-            // Assume the entire thing fits
-            println!("Answer: {}", current.0 * 10_000 + current.1 - 99);
-            break;
-        } else {
-            current.1 += 1;
-        }
-    }
+    profiler.write_trace(BufWriter::new(File::create("trace.jsonl").unwrap()))
+        .expect("Could not write trace");
+    println!("Trace written to trace.jsonl");
 }
@@ -0,0 +1,257 @@
+use std::fmt::Display;
+use std::{collections::{HashMap, hash_map::Entry}, str::FromStr};
+
+use aoc::Solution;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Space {
+    Wall,
+    Open,
+    Portal(String),
+}
+
+
+#[derive(Debug)]
+pub struct Maze {
+    map: Vec<Vec<Space>>,
+    portals: HashMap<(usize, usize), (usize, usize)>,
+    start: (usize, usize),
+    end: (usize, usize),
+
+    adjacency_list: Vec<Vec<(usize, u32)>>,
+    is_outer: Vec<bool>,
+    portal_lookup: Vec<usize>,
+    start_portal: usize,
+    end_portal: usize,
+}
+
+impl Maze {
+    pub fn shortest_path(&self) -> u32 {
+        let height = self.map.len();
+        let width = self.map[0].len();
+
+        let moves = |&(y, x): &(usize, usize)| {
+            [(y.wrapping_sub(1), x), (y, x + 1), (y + 1, x), (y, x.wrapping_sub(1))].into_iter()
+                // Check if we are out of bounds
+                .filter(move |&(ny, nx)| ny < height && nx < width)
+                .filter_map(|(ny, nx)| match &self.map[ny][nx] {
+                    Space::Wall => None,
+                    Space::Open => Some(((ny, nx), 1)),
+                    Space::Portal(name) if name == "AA" || name == "ZZ" => Some(((ny, nx), 1)),
+                    // Go through the portal
+                    Space::Portal(_) => Some((self.portals[&(ny, nx)], 2)),
+                })
+        };
+
+        search::dijkstra([self.start], moves, |&p| p == self.end).unwrap().cost
+    }
+
+    pub fn dijkstra_shortest_path(&self) -> u32 {
+        let moves = |&(portal_index, level): &(usize, u32)| {
+            let mut moves = Vec::new();
+            for &(np, dist) in &self.adjacency_list[portal_index] {
+                // Check if this an inner or an outer portal
+                if !self.is_outer[np] {
+                    // Inner portal
+                    // Restrict depth of the search
+                    if level > 10_000 {
+                        continue;
+                    }
+
+                    moves.push(((self.portal_lookup[np], level + 1), dist + 1));
+                } else if level != 0 && np != self.end_portal {
+                    // Outer portal and not on the top level
+                    moves.push(((self.portal_lookup[np], level - 1), dist + 1));
+                } else if np == self.end_portal {
+                    // Outer portal and on top level, check if we go to the end
+                    moves.push(((np, level), dist));
+                }
+            }
+            moves
+        };
+
+        search::dijkstra([(self.start_portal, 0)], moves, |&(p, level)| p == self.end_portal && level == 0)
+            .unwrap()
+            .cost
+    }
+}
+
+impl FromStr for Maze {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let str_map: Vec<_> = s.lines()
+            .map(|l| l.chars().collect::<Vec<_>>())
+            .collect();
+
+        let mut map = Vec::new();
+        let mut portal_lookup: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+
+        let height = str_map.len() - 4;
+        let width = str_map[0].len() - 4;
+
+        for (y, line) in str_map.iter().skip(2).take(height).enumerate() {
+            map.push(Vec::new());
+
+            for (x, c) in line.iter().skip(2).take(width).enumerate() {
+                let mut space =
+                    match c {
+                        '.' => Space::Open,
+                        _   => Space::Wall,
+                    };
+
+                // Check for portals
+                if space == Space::Open {
+                    // Look up
+                    let portal_name =
+                        if str_map[y + 2 - 1][x + 2].is_ascii_alphabetic() {
+                            // Looking up
+                            Some(format!("{}{}", str_map[y + 2 - 2][x + 2], str_map[y + 2 - 1][x + 2]))
+                        } else if str_map[y + 2][x + 2 + 1].is_ascii_alphabetic() {
+                            // Looking right
+                            Some(format!("{}{}", str_map[y + 2][x + 2 + 1], str_map[y + 2][x + 2 + 2]))
+                        } else if str_map[y + 2 + 1][x + 2].is_ascii_alphabetic() {
+                            // Looking down
+                            Some(format!("{}{}", str_map[y + 2 + 1][x + 2], str_map[y + 2 + 2][x + 2]))
+                        } else if str_map[y + 2][x + 2 - 1].is_ascii_alphabetic() {
+                            // Looking left
+                            Some(format!("{}{}", str_map[y + 2][x + 2 - 2], str_map[y + 2][x + 2 - 1]))
+                        } else {
+                            None
+                        };
+
+                    if let Some(portal_name) = portal_name {
+                        portal_lookup.entry(portal_name.clone())
+                            .or_default()
+                            .push((y, x));
+
+                        space = Space::Portal(portal_name);
+                    }
+                }
+
+                // Update the map
+                map[y].push(space);
+            }
+        }
+
+        let mut start = None;
+        let mut end = None;
+        let mut portals = HashMap::new();
+
+        for (name, coords) in portal_lookup.into_iter() {
+            if name == "AA" {
+                start = Some(coords[0]);
+            } else if name == "ZZ" {
+                end = Some(coords[0]);
+            } else {
+                portals.insert(coords[0], coords[1]);
+                portals.insert(coords[1], coords[0]);
+            }
+        }
+
+        // Build adjacency list
+        let start = start.ok_or("No start square")?;
+        let end = end.ok_or("No end square")?;
+
+        let mut adjacency_list = Vec::new();
+        let mut is_outer = Vec::new();
+        let mut portal_index_lookup = HashMap::new();
+        let mut index_id = 0;
+        for from in [start].iter().chain(portals.values()) {
+            let from_index =
+                match portal_index_lookup.entry(*from) {
+                    Entry::Occupied(e) => *e.get(),
+                    Entry::Vacant(e) => {
+                        e.insert(index_id);
+                        index_id += 1;
+
+                        adjacency_list.push(Vec::new());
+                        is_outer.push(from.0 == 0 || from.1 == 0 || from.0 == height - 1 || from.1 == width - 1);
+
+                        index_id - 1
+                    },
+                };
+
+
+            for to in [end].iter().chain(portals.values()) {
+                let to_index =
+                    match portal_index_lookup.entry(*to) {
+                        Entry::Occupied(e) => *e.get(),
+                        Entry::Vacant(e) => {
+                            e.insert(index_id);
+                            index_id += 1;
+
+                            adjacency_list.push(Vec::new());
+                            is_outer.push(to.0 == 0 || to.1 == 0 || to.0 == height - 1 || to.1 == width - 1);
+
+                            index_id - 1
+                        },
+                    };
+
+                // Perform BFS to find out the distance
+                if let Some(distance) = bfs(&map, *from, *to) {
+                    if distance != 0 {
+                        adjacency_list[from_index].push((to_index, distance));
+                    }
+                }
+            }
+        }
+
+        let mut portal_lookup = vec![0; adjacency_list.len()];
+        for (coord, id) in portal_index_lookup.iter() {
+            if let Some(other_coord) = portals.get(coord) {
+                portal_lookup[*id] = portal_index_lookup[other_coord];
+            }
+        }
+
+
+        Ok(Self {
+            map,
+            portals,
+            start, end,
+
+            adjacency_list,
+            portal_lookup,
+            is_outer,
+            start_portal: portal_index_lookup[&start],
+            end_portal: portal_index_lookup[&end],
+        })
+    }
+}
+
+#[allow(clippy::ptr_arg)]
+fn bfs(map: &Vec<Vec<Space>>, start: (usize, usize), end: (usize, usize)) -> Option<u32> {
+    let height = map.len();
+    let width = map[0].len();
+
+    let moves = |&(y, x): &(usize, usize)| {
+        [(y.wrapping_sub(1), x), (y, x + 1), (y + 1, x), (y, x.wrapping_sub(1))].into_iter()
+            // Check if we are out of bounds
+            .filter(|&(ny, nx)| ny < height && nx < width && map[ny][nx] != Space::Wall)
+    };
+
+    search::bfs([start], moves, |&p| p == end).map(|reached| reached.cost as u32)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 20;
+
+    type Input<'a> = Maze;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Maze::from_str(input).unwrap()
+    }
+
+    /// Shortest path
+    fn part1(maze: &Self::Input<'_>) -> impl Display {
+        maze.shortest_path()
+    }
+
+    /// Shortest path through the recursive levels
+    fn part2(maze: &Self::Input<'_>) -> impl Display {
+        maze.dijkstra_shortest_path()
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use intcode::{ascii_input, Program};
+
+/// Runs the springscript and returns the reported hull damage.
+fn run_springscript(mut program: Program, instructions: &[&str]) -> i64 {
+    let mut damage = 0;
+    program.run(ascii_input(&instructions.join("\n")), |v| {
+        // Damage is the only value outside of the ascii range
+        if v > 255 {
+            damage = v;
+        }
+    });
+
+    damage
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 21;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    /// Damage
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        // When to jump:
+        // (!A || !B || !C) && D
+
+        let part1_instructions = [
+            "NOT A J",
+            "NOT B T",
+            "OR T J",
+            "NOT C T",
+            "OR T J",
+            "AND D J",
+            "WALK\n",
+        ];
+
+        run_springscript(program.clone(), &part1_instructions)
+    }
+
+    /// Damage
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        // When to jump:
+        // (!A || !B || !C) && D && (E || H)
+
+        let part2_instructions = [
+            "NOT A J",
+            "NOT B T",
+            "OR T J",
+            "NOT C T",
+            "OR T J",
+            "AND D J",
+
+            "NOT J T",
+            "OR E T",
+            "OR H T",
+            "AND T J",
+
+            "RUN\n",
+        ];
+
+        run_springscript(program.clone(), &part2_instructions)
+    }
+}
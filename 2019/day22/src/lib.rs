@@ -0,0 +1,209 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::i128;
+use nom::combinator::map;
+use nom::sequence::preceded;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    DealNew,
+    Cut(i128),
+    DealIncrement(i128),
+}
+
+impl Instruction {
+    pub fn execute<T: Default + Clone + Copy>(&self, deck: &mut [T]) {
+        match self {
+            Instruction::DealNew => {
+                deck.reverse();
+            },
+            Instruction::Cut(n) => {
+                if *n >= 0 {
+                    deck.rotate_left(*n as usize);
+                } else {
+                    deck.rotate_right(n.unsigned_abs() as usize);
+                }
+            },
+            Instruction::DealIncrement(n) => {
+                let mut tmp_deck = vec![T::default(); deck.len()];
+                let mut current_index = 0;
+
+                for card in deck.iter() {
+                    tmp_deck[current_index] = *card;
+
+                    current_index += *n as usize;
+                    current_index %= deck.len();
+                }
+
+                deck.copy_from_slice(&tmp_deck);
+            },
+        }
+    }
+
+    pub fn parse(line: &str) -> IResult<&str, Self> {
+        alt((
+            map(tag("deal into new stack"), |_| Instruction::DealNew),
+            map(preceded(tag("cut "), i128), Instruction::Cut),
+            map(preceded(tag("deal with increment "), i128), Instruction::DealIncrement),
+        ))(line)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Deck {
+    top_card: i128,
+    step: i128,
+    size: i128,
+}
+
+impl Deck {
+    // Deck: (t + sx) % size
+    // t := top card
+    // s := step size
+    //
+    // DealNew:
+    // t <- (t + s(size - 1)) % size
+    // s <- (s * -1).rem_euclid(size)
+    //
+    // Cut n:
+    // t <- (t + n).rem_euclid(size)
+    //
+    // Deal increment n
+    // Item at pos 1 is now at pos: i where ni % size = 1
+    // s <- (t + si) % size - t
+
+    pub fn new(size: i128) -> Self {
+        Self { top_card: 0, step: 1, size }
+    }
+
+    pub fn apply(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::DealNew => {
+                self.top_card += self.step * (self.size - 1);
+                self.top_card = self.top_card.rem_euclid(self.size);
+
+                self.step = (-self.step).rem_euclid(self.size);
+            },
+            Instruction::Cut(n) => {
+                self.top_card = (self.top_card + (n * self.step)).rem_euclid(self.size);
+            },
+            Instruction::DealIncrement(n) => {
+                let pos = math::mod_inv(n as i64, self.size as i64)
+                    .expect("Increment shares a divisor with the deck size") as i128;
+
+                let pos_card = (self.top_card + self.step * pos) % self.size;
+                self.step = (pos_card - self.top_card).rem_euclid(self.size);
+            },
+        }
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 22;
+
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| Instruction::parse(s).unwrap().1)
+            .collect()
+    }
+
+    /// Position of card 2019
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let mut deck: Vec<_> = (0..10_007_u32).collect();
+        for instruction in input {
+            instruction.execute(&mut deck);
+        }
+
+        deck.iter().position(|c| *c == 2019).unwrap()
+    }
+
+    /// Card at position 2020
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        const DECK_SIZE: i128 = 119_315_717_514_047;
+        const SHUFFLES: u64  = 101_741_582_076_661;
+
+        let mut deck = Deck::new(DECK_SIZE);
+        for instruction in input {
+            deck.apply(*instruction);
+        }
+
+        let t = deck.top_card as i64;
+        let s = deck.step as i64;
+        let m = deck.size as i64;
+
+        // new_t := t + t * s + t * s * s + ... + t * s^(SHUFFLES - 1)
+        // Can be solved with geometric series and modulo arithmetic, unless every step is 1
+        let new_s = math::mod_pow(s, SHUFFLES, m);
+        let series = match math::mod_inv(s - 1, m) {
+            Some(inverse) => math::mod_mul(new_s - 1, inverse, m),
+            None => SHUFFLES as i64 % m,
+        };
+        let new_t = math::mod_mul(t, series, m);
+
+        (new_t + math::mod_mul(new_s, 2020, m)) % m
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn example1() {
+        let mut deck = Deck::new(10);
+        deck.apply(Instruction::DealIncrement(7));
+        deck.apply(Instruction::DealNew);
+        deck.apply(Instruction::DealNew);
+
+        assert_eq!(deck, Deck { top_card: 0, step: 3, size: 10 });
+    }
+
+    #[test]
+    pub fn example2() {
+        let mut deck = Deck::new(10);
+        deck.apply(Instruction::Cut(6));
+        deck.apply(Instruction::DealIncrement(7));
+        deck.apply(Instruction::DealNew);
+
+        assert_eq!(deck, Deck { top_card: 3, step: 7, size: 10 });
+    }
+
+    #[test]
+    pub fn example3() {
+        let mut deck = Deck::new(10);
+        deck.apply(Instruction::DealIncrement(7));
+        deck.apply(Instruction::DealIncrement(9));
+        deck.apply(Instruction::Cut(-2));
+
+        assert_eq!(deck, Deck { top_card: 6, step: 7, size: 10 });
+    }
+
+    #[test]
+    pub fn example4() {
+        let mut deck = Deck::new(10);
+        deck.apply(Instruction::DealNew);
+        deck.apply(Instruction::Cut(-2));
+        deck.apply(Instruction::DealIncrement(7));
+        deck.apply(Instruction::Cut(8));
+        deck.apply(Instruction::Cut(-4));
+        deck.apply(Instruction::DealIncrement(7));
+        deck.apply(Instruction::Cut(3));
+        deck.apply(Instruction::DealIncrement(9));
+        deck.apply(Instruction::DealIncrement(3));
+        deck.apply(Instruction::Cut(-1));
+
+        assert_eq!(deck, Deck { top_card: 9, step: 3, size: 10 });
+    }
+
+}
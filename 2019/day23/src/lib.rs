@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use intcode::{Network, NetworkEvent, Program};
+
+
+const NETWORK_SIZE: usize = 50;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 23;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    /// Y value of the first packet sent to the NAT
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        Network::new(program, NETWORK_SIZE)
+            .find_map(|event| match event {
+                NetworkEvent::NatReceived(packet) => Some(packet.y),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    /// First Y value delivered by the NAT twice in a row
+    fn part2(program: &Self::Input<'_>) -> impl Display {
+        let mut last_y_send = None;
+
+        for event in Network::new(program, NETWORK_SIZE) {
+            match event {
+                NetworkEvent::NatReceived(_) => (),
+                NetworkEvent::NatSent(packet) => {
+                    if Some(packet.y) == last_y_send {
+                        return packet.y;
+                    }
+
+                    last_y_send = Some(packet.y);
+                },
+                NetworkEvent::Dropped(packet) => panic!("Packet to unknown address: {packet:?}"),
+            }
+        }
+
+        panic!("Network stopped");
+    }
+}
@@ -0,0 +1,93 @@
+use intcode::{ascii_input, Program, State};
+
+
+/// Maximum number of instructions to execute for a single command,
+/// some items make the program loop forever.
+const STEP_LIMIT: usize = 10_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// The droid is waiting for the next command.
+    Prompt(String),
+    /// The game ended.
+    Ended(String),
+    /// The program did not ask for a new command in time.
+    Stuck,
+}
+
+#[derive(Debug, Clone)]
+pub struct Droid {
+    program: Program,
+}
+
+impl Droid {
+    pub fn new(program: Program) -> Self {
+        Self { program }
+    }
+
+    /// Sends a command to the droid, an empty command only reads the pending output.
+    pub fn command(&mut self, command: &str) -> Reply {
+        let input = if command.is_empty() { String::new() } else { format!("{command}\n") };
+        let mut input = ascii_input(&input);
+
+        let mut output = String::new();
+        for _ in 0..STEP_LIMIT {
+            match self.program.step() {
+                State::Running => (),
+                State::NeedsInput => match input.next() {
+                    Some(c) => self.program.input(c),
+                    None => return Reply::Prompt(output),
+                },
+                State::Output(c) => output.push(c as u8 as char),
+                State::Halted => return Reply::Ended(output),
+            }
+        }
+
+        Reply::Stuck
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Room {
+    pub name: String,
+    pub doors: Vec<String>,
+    pub items: Vec<String>,
+}
+
+impl Room {
+    /// Parses the last room description in the output.
+    pub fn parse(output: &str) -> Option<Self> {
+        let start = output.rfind("== ")?;
+        let mut lines = output[start..].lines();
+
+        let name = lines.next()?
+            .trim_matches(|c| c == '=' || c == ' ')
+            .to_owned();
+
+        let mut doors = Vec::new();
+        let mut items = Vec::new();
+        let mut list = None;
+        for line in lines {
+            match line {
+                "Doors here lead:" => list = Some(&mut doors),
+                "Items here:" => list = Some(&mut items),
+                _ => match (line.strip_prefix("- "), list.as_mut()) {
+                    (Some(entry), Some(list)) => list.push(entry.to_owned()),
+                    _ => list = None,
+                },
+            }
+        }
+
+        Some(Self { name, doors, items })
+    }
+}
+
+pub fn opposite(door: &str) -> &'static str {
+    match door {
+        "north" => "south",
+        "south" => "north",
+        "east"  => "west",
+        "west"  => "east",
+        _ => panic!("Invalid door: {door}"),
+    }
+}
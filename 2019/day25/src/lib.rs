@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc::Solution;
+use droid::{opposite, Droid, Reply, Room};
+use intcode::Program;
+use itertools::Itertools;
+
+mod droid;
+
+
+const CHECKPOINT: &str = "Security Checkpoint";
+
+#[derive(Debug)]
+struct Explorer {
+    droid: Droid,
+    visited: HashSet<String>,
+    items: Vec<String>,
+    /// Path to the checkpoint, and the door to the pressure-sensitive floor
+    checkpoint: Option<(Vec<String>, String)>,
+}
+
+impl Explorer {
+    pub fn new(droid: Droid) -> Self {
+        Self {
+            droid,
+            visited: HashSet::new(),
+            items: Vec::new(),
+            checkpoint: None,
+        }
+    }
+
+    /// Explores the ship from the current room while collecting all safe items,
+    /// and returns to the room afterwards.
+    pub fn explore(&mut self, room: &Room, path: &mut Vec<String>) {
+        self.visited.insert(room.name.clone());
+
+        for item in &room.items {
+            if self.is_safe(item, room) {
+                self.expect_prompt(&format!("take {item}"));
+                self.items.push(item.clone());
+            }
+        }
+
+        if room.name == CHECKPOINT {
+            // Going further requires the right items, so only remember the way
+            let back = path.last().map(|d| opposite(d));
+            let door = room.doors.iter()
+                .find(|d| Some(d.as_str()) != back)
+                .expect("Checkpoint should lead somewhere");
+
+            self.checkpoint = Some((path.clone(), door.clone()));
+            return;
+        }
+
+        for door in &room.doors {
+            let output = self.expect_prompt(door);
+            let next = Room::parse(&output).expect("Should have entered a room");
+
+            if !self.visited.contains(&next.name) {
+                path.push(door.clone());
+                self.explore(&next, path);
+                path.pop();
+            }
+
+            self.expect_prompt(opposite(door));
+        }
+    }
+
+    /// Checks on a copy of the droid if taking the item does not end or block the game.
+    fn is_safe(&self, item: &str, room: &Room) -> bool {
+        let mut droid = self.droid.clone();
+
+        if !matches!(droid.command(&format!("take {item}")), Reply::Prompt(_)) {
+            return false;
+        }
+
+        // Some items prevent moving
+        match droid.command(&room.doors[0]) {
+            Reply::Prompt(output) => !output.contains("can't move"),
+            _ => false,
+        }
+    }
+
+    fn expect_prompt(&mut self, command: &str) -> String {
+        match self.droid.command(command) {
+            Reply::Prompt(output) => output,
+            reply => panic!("Unexpected reply on '{command}': {reply:?}"),
+        }
+    }
+
+    /// Goes to the checkpoint and tries item combinations until the droid gets through.
+    /// Returns the final output of the game.
+    pub fn pass_checkpoint(mut self) -> Option<String> {
+        let (path, door) = self.checkpoint.take()?;
+        for step in &path {
+            self.expect_prompt(step);
+        }
+
+        for items in self.items.iter().powerset() {
+            let mut droid = self.droid.clone();
+            for item in self.items.iter().filter(|i| !items.contains(i)) {
+                droid.command(&format!("drop {item}"));
+            }
+
+            if let Reply::Ended(output) = droid.command(&door) {
+                return Some(output);
+            }
+        }
+
+        None
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 25;
+
+    type Input<'a> = Program;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    /// Security code to get through the checkpoint
+    fn part1(program: &Self::Input<'_>) -> impl Display {
+        let mut droid = Droid::new(program.clone());
+        let Reply::Prompt(output) = droid.command("") else {
+            panic!("Game did not start");
+        };
+        let start = Room::parse(&output).expect("Should start in a room");
+
+        let mut explorer = Explorer::new(droid);
+        explorer.explore(&start, &mut Vec::new());
+
+        let output = explorer.pass_checkpoint()
+            .expect("No item combination gets through the checkpoint");
+
+        // Santa tells the code to type on the keypad
+        output.split("typing ")
+            .nth(1)
+            .unwrap_or(&output)
+            .chars()
+            .skip_while(|c| !c.is_ascii_digit())
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>()
+    }
+}
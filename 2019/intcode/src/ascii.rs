@@ -0,0 +1,42 @@
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::iter;
+
+use crate::program::{Program, Status};
+
+
+/// Encodes a string as ASCII input for a program.
+pub fn ascii_input(s: &str) -> impl Iterator<Item = i64> + '_ {
+    s.bytes().map(i64::from)
+}
+
+/// Connects the ASCII input and output of the program to stdin and stdout.
+///
+/// Input is sent line by line. Output outside of the ASCII range is printed as a number.
+/// Returns when the program halts or stdin is closed.
+pub fn terminal(program: &mut Program) -> Status {
+    let mut buffer = VecDeque::new();
+    let input = iter::from_fn(|| {
+        if buffer.is_empty() {
+            io::stdout().flush().unwrap();
+
+            let mut line = String::new();
+            if io::stdin().read_line(&mut line).ok()? == 0 {
+                return None;
+            }
+
+            buffer.extend(ascii_input(line.trim_end_matches(['\r', '\n'])));
+            buffer.push_back(10);
+        }
+
+        buffer.pop_front()
+    });
+
+    let status = program.run(input, |v| match u8::try_from(v) {
+        Ok(c) if c.is_ascii() => print!("{}", c as char),
+        _ => println!("{v}"),
+    });
+    io::stdout().flush().unwrap();
+
+    status
+}
@@ -0,0 +1,253 @@
+use std::io::{self, BufRead, Write};
+use std::{env, fs};
+
+use intcode::{Debugger, Program, Stop};
+
+
+const HELP: &str = "\
+Commands:
+  s, step [n]         Execute n (default 1) instructions
+  c, continue         Run until a breakpoint, watchpoint, input request or halt
+  b, break <addr>     Set a breakpoint
+  db <addr>           Delete a breakpoint
+  w, watch <addr>     Watch a memory cell for changes
+  dw <addr>           Delete a watchpoint
+  r, regs             Show ip, relative base and the current instruction
+  x <addr> [n]        Dump n (default 8) memory cells
+  set <addr> <value>  Write a value to memory
+  l, list [addr] [n]  Disassemble n (default 10) instructions from addr (default ip)
+  i, input <values>   Queue input; in ascii mode the rest of the line is sent as text
+  ascii [on|off]      Toggle ascii mode for input and output
+  save <file>         Save a snapshot of the program state
+  load <file>         Restore the program state from a snapshot
+  info                Show breakpoints, watchpoints and pending input
+  h, help             Show this help
+  q, quit             Exit the debugger";
+
+/// Prints the output of the program, as text when in ascii mode.
+fn print_output(output: &[i64], ascii: bool) {
+    if output.is_empty() {
+        return;
+    }
+
+    if ascii {
+        for &value in output {
+            if (0..128).contains(&value) {
+                print!("{}", value as u8 as char);
+            } else {
+                // Values outside of the ascii range are usually answers
+                println!("{value}");
+            }
+        }
+        if output.last() != Some(&10) {
+            println!();
+        }
+    } else {
+        println!("Output: {}", output.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(","));
+    }
+}
+
+fn print_stop(stop: Stop, debugger: &Debugger) {
+    match stop {
+        Stop::Stepped => (),
+        Stop::Breakpoint(address) => println!("Breakpoint at {address}"),
+        Stop::Watchpoint { address, old, new } => println!("Watchpoint [{address}]: {old} -> {new}"),
+        Stop::NeedsInput => println!("Waiting for input"),
+        Stop::Halted => println!("Program halted"),
+        Stop::Invalid(address) => println!("Can not execute the instruction at {address}"),
+    }
+    print_current(debugger);
+}
+
+fn print_current(debugger: &Debugger) {
+    let ip = debugger.program.ip();
+    match debugger.program.instruction_at(ip) {
+        Some(instruction) => println!("{ip:>6}: {instruction}"),
+        None => println!("{ip:>6}: <invalid instruction {}>", debugger.program[ip]),
+    }
+}
+
+/// Checks that the addresses of a command are within reach of the memory in use.
+fn check_reach(debugger: &Debugger, address: usize, count: usize) -> Result<(), String> {
+    match address.checked_add(count.saturating_sub(1)) {
+        Some(last) if debugger.in_reach(last) => Ok(()),
+        _ => Err(format!("Address out of reach, the program uses {} memory cells", debugger.program.memory_size())),
+    }
+}
+
+fn parse_arg<T: std::str::FromStr>(arg: Option<&str>, name: &str) -> Result<T, String> {
+    arg.ok_or_else(|| format!("Missing {name}"))?
+        .parse()
+        .map_err(|_| format!("Invalid {name}"))
+}
+
+/// Executes a single command. Returns `Ok(false)` when the debugger should exit.
+fn execute(debugger: &mut Debugger, ascii: &mut bool, line: &str) -> Result<bool, String> {
+    let mut args = line.split_whitespace();
+    let Some(command) = args.next() else {
+        return Ok(true);
+    };
+
+    match command {
+        "s" | "step" => {
+            let count = args.next().map_or(Ok(1), |n| parse_arg(Some(n), "count"))?;
+            let stop = debugger.step(count);
+
+            print_output(&debugger.take_output(), *ascii);
+            print_stop(stop, debugger);
+        },
+        "c" | "continue" => {
+            let stop = debugger.resume();
+
+            print_output(&debugger.take_output(), *ascii);
+            print_stop(stop, debugger);
+        },
+        "b" | "break" => {
+            let address = parse_arg(args.next(), "address")?;
+            if !debugger.add_breakpoint(address) {
+                println!("Breakpoint at {address} already exists");
+            }
+        },
+        "db" => {
+            let address = parse_arg(args.next(), "address")?;
+            if !debugger.remove_breakpoint(address) {
+                println!("No breakpoint at {address}");
+            }
+        },
+        "w" | "watch" => {
+            let address = parse_arg(args.next(), "address")?;
+            if !debugger.add_watchpoint(address) {
+                println!("Watchpoint on {address} already exists");
+            }
+        },
+        "dw" => {
+            let address = parse_arg(args.next(), "address")?;
+            if !debugger.remove_watchpoint(address) {
+                println!("No watchpoint on {address}");
+            }
+        },
+        "r" | "regs" => {
+            println!("ip: {}, relative base: {}", debugger.program.ip(), debugger.program.relative_base());
+            print_current(debugger);
+        },
+        "x" => {
+            let address: usize = parse_arg(args.next(), "address")?;
+            let count = args.next().map_or(Ok(8), |n| parse_arg(Some(n), "count"))?;
+            check_reach(debugger, address, count)?;
+
+            let values: Vec<_> = (address..address + count)
+                .map(|a| debugger.program[a].to_string())
+                .collect();
+            println!("{address:>6}: {}", values.join(", "));
+        },
+        "set" => {
+            let address = parse_arg(args.next(), "address")?;
+            let value = parse_arg(args.next(), "value")?;
+            check_reach(debugger, address, 1)?;
+            debugger.write(address, value);
+        },
+        "l" | "list" => {
+            let mut address = args.next().map_or(Ok(debugger.program.ip()), |a| parse_arg(Some(a), "address"))?;
+            let count = args.next().map_or(Ok(10), |n| parse_arg(Some(n), "count"))?;
+
+            for _ in 0..count {
+                let marker = if address == debugger.program.ip() { '>' } else { ' ' };
+                match debugger.program.instruction_at(address) {
+                    Some(instruction) => {
+                        println!("{marker}{address:>6}: {instruction}");
+                        address += instruction.size();
+                    },
+                    None => {
+                        println!("{marker}{address:>6}: data {}", debugger.program[address]);
+                        address += 1;
+                    },
+                }
+            }
+        },
+        "i" | "input" => {
+            if *ascii {
+                let text = line.trim_start()
+                    .split_once(char::is_whitespace)
+                    .map_or("", |(_, text)| text.trim_start());
+
+                for c in text.bytes().chain([b'\n']) {
+                    debugger.push_input(c as i64);
+                }
+            } else {
+                for value in args {
+                    debugger.push_input(parse_arg(Some(value), "input value")?);
+                }
+            }
+        },
+        "ascii" => {
+            *ascii = match args.next() {
+                Some("on") => true,
+                Some("off") => false,
+                None => !*ascii,
+                Some(_) => return Err(String::from("Expected on or off")),
+            };
+            println!("Ascii mode {}", if *ascii { "on" } else { "off" });
+        },
+        "save" => {
+            let path = args.next().ok_or("Missing file")?;
+            debugger.program.save_to_file(path).map_err(|e| e.to_string())?;
+            println!("Saved snapshot to {path}");
+        },
+        "load" => {
+            let path = args.next().ok_or("Missing file")?;
+            debugger.program = Program::load_from_file(path).map_err(|e| e.to_string())?;
+            print_current(debugger);
+        },
+        "info" => {
+            println!("Breakpoints: {:?}", debugger.breakpoints());
+            println!("Watchpoints: {:?}", debugger.watchpoints().collect::<Vec<_>>());
+            println!("Pending input: {}", debugger.pending_input());
+        },
+        "h" | "help" => println!("{HELP}"),
+        "q" | "quit" => return Ok(false),
+        _ => return Err(format!("Unknown command '{command}', try 'help'")),
+    }
+
+    Ok(true)
+}
+
+fn main() {
+    let mut ascii = false;
+    let mut path = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--ascii" => ascii = true,
+            _ => path = Some(arg),
+        }
+    }
+
+    let Some(path) = path else {
+        eprintln!("Usage: debug [--ascii] <program>");
+        return;
+    };
+
+    let program: Program = fs::read_to_string(path)
+        .expect("Could not read program")
+        .parse()
+        .expect("Invalid program");
+    let mut debugger = Debugger::new(program);
+
+    print_current(&debugger);
+
+    let stdin = io::stdin();
+    loop {
+        print!("(icdb) ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+
+        match execute(&mut debugger, &mut ascii, line.trim_end_matches('\n')) {
+            Ok(true) => (),
+            Ok(false) => break,
+            Err(e) => println!("{e}"),
+        }
+    }
+}
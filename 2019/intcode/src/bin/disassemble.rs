@@ -0,0 +1,23 @@
+use std::io::{self, Read};
+use std::{env, fs};
+
+use intcode::disassemble;
+
+
+fn main() {
+    // Read the program from the given file, or from stdin otherwise
+    let source = match env::args().nth(1) {
+        Some(path) => fs::read_to_string(path).expect("Could not read program"),
+        None => {
+            let mut source = String::new();
+            io::stdin().read_to_string(&mut source).expect("Could not read program");
+            source
+        },
+    };
+
+    let memory = intcode::parse(&source).expect("Invalid program");
+
+    for line in disassemble(&memory) {
+        println!("{line}");
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::instruction::Mode;
+use crate::program::{Program, State};
+
+
+/// Cells beyond the memory in use which the debugger lets a program or command reach,
+/// so a wrong address stops the debugger instead of allocating without limit.
+pub const MEMORY_SLACK: usize = 1 << 20;
+
+
+/// Reason for the debugger to hand back control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The requested number of instructions got executed.
+    Stepped,
+    Breakpoint(usize),
+    /// A watched memory cell changed its value.
+    Watchpoint { address: usize, old: i64, new: i64 },
+    /// The program wants to read a value, but the input queue is empty.
+    NeedsInput,
+    Halted,
+    /// The instruction at the address can not be executed, like an unknown opcode or an address out of reach.
+    Invalid(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    pub program: Program,
+    breakpoints: BTreeSet<usize>,
+    /// Watched addresses with their last known value
+    watchpoints: BTreeMap<usize, i64>,
+    input: VecDeque<i64>,
+    output: Vec<i64>,
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Self {
+        Self {
+            program,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeMap::new(),
+            input: VecDeque::new(),
+            output: Vec::new(),
+        }
+    }
+
+    pub fn breakpoints(&self) -> &BTreeSet<usize> {
+        &self.breakpoints
+    }
+
+    /// Adds a breakpoint, returns `false` if it already existed.
+    pub fn add_breakpoint(&mut self, address: usize) -> bool {
+        self.breakpoints.insert(address)
+    }
+
+    /// Removes a breakpoint, returns `false` if it did not exist.
+    pub fn remove_breakpoint(&mut self, address: usize) -> bool {
+        self.breakpoints.remove(&address)
+    }
+
+    pub fn watchpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.watchpoints.keys().copied()
+    }
+
+    /// Adds a watchpoint, returns `false` if it already existed.
+    pub fn add_watchpoint(&mut self, address: usize) -> bool {
+        self.watchpoints.insert(address, self.program[address]).is_none()
+    }
+
+    /// Removes a watchpoint, returns `false` if it did not exist.
+    pub fn remove_watchpoint(&mut self, address: usize) -> bool {
+        self.watchpoints.remove(&address).is_some()
+    }
+
+    /// Writes to memory, without triggering watchpoints.
+    pub fn write(&mut self, address: usize, value: i64) {
+        self.program[address] = value;
+
+        if let Some(last) = self.watchpoints.get_mut(&address) {
+            *last = value;
+        }
+    }
+
+    /// Whether the address is in use or close enough to it, see [`MEMORY_SLACK`].
+    pub fn in_reach(&self, address: usize) -> bool {
+        address < self.program.memory_size() + MEMORY_SLACK
+    }
+
+    /// Queues a value for the input instruction.
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    pub fn pending_input(&self) -> usize {
+        self.input.len()
+    }
+
+    /// Takes the output the program produced since the last call.
+    pub fn take_output(&mut self) -> Vec<i64> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Executes (at most) `count` instructions.
+    pub fn step(&mut self, count: usize) -> Stop {
+        for _ in 0..count {
+            if let Some(stop) = self.execute() {
+                return stop;
+            }
+        }
+
+        Stop::Stepped
+    }
+
+    /// Runs until a breakpoint or watchpoint is hit, input is needed or the program halts.
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            if let Some(stop) = self.execute() {
+                return stop;
+            }
+
+            let ip = self.program.ip();
+            if self.breakpoints.contains(&ip) {
+                return Stop::Breakpoint(ip);
+            }
+        }
+    }
+
+    /// Executes a single instruction.
+    fn execute(&mut self) -> Option<Stop> {
+        let ip = self.program.ip();
+        if !self.executable(ip) {
+            return Some(Stop::Invalid(ip));
+        }
+
+        match self.program.step() {
+            State::Running => (),
+            State::NeedsInput => {
+                let Some(value) = self.input.pop_front() else {
+                    return Some(Stop::NeedsInput);
+                };
+                self.program.input(value);
+            },
+            State::Output(value) => self.output.push(value),
+            State::Halted => return Some(Stop::Halted),
+        }
+
+        self.check_watchpoints()
+    }
+
+    /// Whether the instruction at the address decodes and its parameters point within reach,
+    /// as [`Program::step`] panics or allocates without limit otherwise.
+    fn executable(&self, address: usize) -> bool {
+        let Some(instruction) = self.program.instruction_at(address) else {
+            return false;
+        };
+
+        instruction.parameters.iter().all(|p| {
+            let target = match p.mode {
+                Mode::Position => p.value,
+                Mode::Immediate => return true,
+                Mode::Relative => p.value + self.program.relative_base(),
+            };
+            usize::try_from(target).is_ok_and(|t| self.in_reach(t))
+        })
+    }
+
+    fn check_watchpoints(&mut self) -> Option<Stop> {
+        for (&address, last) in self.watchpoints.iter_mut() {
+            let new = self.program[address];
+            if new != *last {
+                let old = *last;
+                *last = new;
+
+                return Some(Stop::Watchpoint { address, old, new });
+            }
+        }
+
+        None
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn stops() {
+        // Reads a value, doubles it and outputs it
+        let program: Program = "3,11,1002,11,2,11,4,11,99,0,0,0".parse().unwrap();
+        let mut debugger = Debugger::new(program);
+
+        debugger.add_breakpoint(8);
+        debugger.add_watchpoint(11);
+
+        assert_eq!(debugger.resume(), Stop::NeedsInput);
+        debugger.push_input(21);
+
+        assert_eq!(debugger.step(1), Stop::Watchpoint { address: 11, old: 0, new: 21 });
+        assert_eq!(debugger.resume(), Stop::Watchpoint { address: 11, old: 21, new: 42 });
+        assert_eq!(debugger.resume(), Stop::Breakpoint(8));
+        assert_eq!(debugger.take_output(), [42]);
+
+        assert_eq!(debugger.resume(), Stop::Halted);
+    }
+
+    #[test]
+    pub fn invalid() {
+        // Unknown opcode, immediate mode for the written parameter and a negative address
+        for program in ["42", "11101,1,1,0,99", "1,-1,0,0,99"] {
+            let mut debugger = Debugger::new(program.parse().unwrap());
+            assert_eq!(debugger.resume(), Stop::Invalid(0));
+            assert_eq!(debugger.step(1), Stop::Invalid(0));
+        }
+
+        // Jumping to a negative address
+        let mut debugger = Debugger::new("1105,1,-5".parse().unwrap());
+        assert_eq!(debugger.step(1), Stop::Stepped);
+        assert_eq!(debugger.step(1), Stop::Invalid(-5_i64 as usize));
+
+        let mut debugger = Debugger::new("1,1000000000000,0,0,99".parse().unwrap());
+        assert_eq!(debugger.resume(), Stop::Invalid(0));
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::instruction::{Instruction, Mode, Opcode};
+
+
+/// Maximum number of data values on a single line of the listing.
+const DATA_PER_LINE: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Code { address: usize, instruction: Instruction },
+    Data { address: usize, values: Vec<i64> },
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Code { address, instruction } => write!(f, "{address:>6}: {instruction}"),
+            Line::Data { address, values } => {
+                write!(f, "{address:>6}: data ")?;
+                write!(f, "{}", values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", "))?;
+
+                // Show printable text, as programs often contain messages
+                if values.iter().all(|v| (32..127).contains(v) || *v == 10) {
+                    let text: String = values.iter()
+                        .map(|&v| v as u8 as char)
+                        .collect();
+                    write!(f, "  ; {text:?}")?;
+                }
+
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Finds the instructions that are reachable from address 0.
+///
+/// Only jumps with an immediate target are followed, as other targets are only known at runtime.
+pub fn reachable_code(memory: &[i64]) -> BTreeMap<usize, Instruction> {
+    let mut code = BTreeMap::new();
+
+    let mut stack = vec![0];
+    while let Some(address) = stack.pop() {
+        if code.contains_key(&address) {
+            continue;
+        }
+        let Some(instruction) = Instruction::decode(memory, address) else {
+            continue;
+        };
+
+        let next = address + instruction.size();
+        match instruction.opcode {
+            Opcode::Halt => (),
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let [condition, target] = [instruction.parameters[0], instruction.parameters[1]];
+
+                // Constant conditions either always or never jump
+                let (always, never) = if condition.mode == Mode::Immediate {
+                    let jumps = (condition.value != 0) == (instruction.opcode == Opcode::JumpIfTrue);
+                    (jumps, !jumps)
+                } else {
+                    (false, false)
+                };
+
+                if !always {
+                    stack.push(next);
+                }
+                if !never && target.mode == Mode::Immediate && target.value >= 0 {
+                    stack.push(target.value as usize);
+                }
+            },
+            _ => stack.push(next),
+        }
+
+        code.insert(address, instruction);
+    }
+
+    code
+}
+
+/// Creates an annotated listing of the program.
+/// Memory that is not reachable as code from address 0 is listed as data.
+pub fn disassemble(memory: &[i64]) -> Vec<Line> {
+    let mut code = reachable_code(memory);
+
+    let mut lines = Vec::new();
+    let mut address = 0;
+    while address < memory.len() {
+        if let Some(instruction) = code.remove(&address) {
+            let size = instruction.size();
+            lines.push(Line::Code { address, instruction });
+
+            address += size;
+            continue;
+        }
+
+        // Group data until the next instruction
+        let next_code = code.range(address..)
+            .next()
+            .map(|(a, _)| *a)
+            .unwrap_or(memory.len());
+        let end = next_code.min(address + DATA_PER_LINE);
+
+        lines.push(Line::Data { address, values: memory[address..end].to_vec() });
+        address = end;
+    }
+
+    lines
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn listing() {
+        let memory = crate::parse("1105,1,7,72,105,33,10,204,-1,2,3,10,11,99").unwrap();
+
+        let listing: Vec<_> = disassemble(&memory).iter()
+            .map(|l| l.to_string())
+            .collect();
+
+        assert_eq!(listing, [
+            "     0: jt   #1, #7",
+            "     3: data 72, 105, 33, 10  ; \"Hi!\\n\"",
+            "     7: out  rb-1",
+            "     9: mul  [3], [10], [11]",
+            "    13: hlt",
+        ]);
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 10] = [
+        Opcode::Add,
+        Opcode::Multiply,
+        Opcode::Input,
+        Opcode::Output,
+        Opcode::JumpIfTrue,
+        Opcode::JumpIfFalse,
+        Opcode::LessThan,
+        Opcode::Equals,
+        Opcode::AdjustRelativeBase,
+        Opcode::Halt,
+    ];
+
+    pub fn from_code(code: i64) -> Option<Self> {
+        match code {
+            1  => Some(Opcode::Add),
+            2  => Some(Opcode::Multiply),
+            3  => Some(Opcode::Input),
+            4  => Some(Opcode::Output),
+            5  => Some(Opcode::JumpIfTrue),
+            6  => Some(Opcode::JumpIfFalse),
+            7  => Some(Opcode::LessThan),
+            8  => Some(Opcode::Equals),
+            9  => Some(Opcode::AdjustRelativeBase),
+            99 => Some(Opcode::Halt),
+            _  => None,
+        }
+    }
+
+    pub fn code(self) -> i64 {
+        match self {
+            Opcode::Add                => 1,
+            Opcode::Multiply           => 2,
+            Opcode::Input              => 3,
+            Opcode::Output             => 4,
+            Opcode::JumpIfTrue         => 5,
+            Opcode::JumpIfFalse        => 6,
+            Opcode::LessThan           => 7,
+            Opcode::Equals             => 8,
+            Opcode::AdjustRelativeBase => 9,
+            Opcode::Halt               => 99,
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Self::ALL.into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Add                => "add",
+            Opcode::Multiply           => "mul",
+            Opcode::Input              => "in",
+            Opcode::Output             => "out",
+            Opcode::JumpIfTrue         => "jt",
+            Opcode::JumpIfFalse        => "jf",
+            Opcode::LessThan           => "lt",
+            Opcode::Equals             => "eq",
+            Opcode::AdjustRelativeBase => "arb",
+            Opcode::Halt               => "hlt",
+        }
+    }
+
+    /// Number of parameters the instruction takes.
+    pub fn parameters(self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output | Opcode::AdjustRelativeBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    /// Index of the parameter the instruction writes to, if any.
+    pub fn writes(self) -> Option<usize> {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => Some(2),
+            Opcode::Input => Some(0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl Mode {
+    pub fn from_code(code: i64) -> Option<Self> {
+        match code {
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> i64 {
+        match self {
+            Mode::Position  => 0,
+            Mode::Immediate => 1,
+            Mode::Relative  => 2,
+        }
+    }
+
+    /// Mode of the `param`th (0-based) parameter of the given instruction value.
+    pub fn of(instruction: i64, param: usize) -> Option<Self> {
+        Self::from_code((instruction / 10_i64.pow(param as u32 + 2)) % 10)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameter {
+    pub mode: Mode,
+    pub value: i64,
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            Mode::Position => write!(f, "[{}]", self.value),
+            Mode::Immediate => write!(f, "#{}", self.value),
+            Mode::Relative if self.value < 0 => write!(f, "rb{}", self.value),
+            Mode::Relative => write!(f, "rb+{}", self.value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub parameters: Vec<Parameter>,
+}
+
+impl Instruction {
+    /// Decodes the instruction at `address`.
+    /// Returns `None` if there is no valid instruction.
+    pub fn decode(memory: &[i64], address: usize) -> Option<Self> {
+        let instruction = *memory.get(address)?;
+        if instruction < 0 {
+            return None;
+        }
+
+        let opcode = Opcode::from_code(instruction % 100)?;
+        // Mode digits beyond the parameters should not be set
+        if instruction / 10_i64.pow(opcode.parameters() as u32 + 2) != 0 {
+            return None;
+        }
+
+        let parameters = (0..opcode.parameters())
+            .map(|i| {
+                let mode = Mode::of(instruction, i)?;
+                // Parameters that are written to can't be immediate
+                if mode == Mode::Immediate && opcode.writes() == Some(i) {
+                    return None;
+                }
+
+                Some(Parameter { mode, value: *memory.get(address + i + 1)? })
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self { opcode, parameters })
+    }
+
+    /// Encodes the instruction into memory cells, the inverse of [`Instruction::decode`].
+    pub fn encode(&self) -> Vec<i64> {
+        let instruction = self.parameters.iter()
+            .enumerate()
+            .map(|(i, p)| p.mode.code() * 10_i64.pow(i as u32 + 2))
+            .sum::<i64>() + self.opcode.code();
+
+        [instruction].into_iter()
+            .chain(self.parameters.iter().map(|p| p.value))
+            .collect()
+    }
+
+    /// Number of memory cells the instruction occupies.
+    pub fn size(&self) -> usize {
+        self.parameters.len() + 1
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.opcode.mnemonic())?;
+
+        for (i, parameter) in self.parameters.iter().enumerate() {
+            if i == 0 {
+                write!(f, "{:pad$}", "", pad = 4 - self.opcode.mnemonic().len())?;
+            } else {
+                write!(f, ",")?;
+            }
+            write!(f, " {parameter}")?;
+        }
+
+        Ok(())
+    }
+}
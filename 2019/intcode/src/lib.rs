@@ -0,0 +1,28 @@
+use std::num::ParseIntError;
+
+mod ascii;
+mod debugger;
+mod disassemble;
+mod instruction;
+mod network;
+mod profiler;
+mod program;
+mod snapshot;
+
+pub use ascii::{ascii_input, terminal};
+pub use debugger::{Debugger, Stop};
+pub use disassemble::{disassemble, reachable_code, Line};
+pub use instruction::{Instruction, Mode, Opcode, Parameter};
+pub use network::{Network, NetworkEvent, Packet, NAT_ADDRESS};
+pub use profiler::{Event, EventKind, Profiler};
+pub use program::{Input, Output, Program, State, Status};
+pub use snapshot::SnapshotError;
+
+
+/// Parses a comma-separated Intcode program.
+pub fn parse(s: &str) -> Result<Vec<i64>, ParseIntError> {
+    s.trim()
+        .split(',')
+        .map(|s| s.trim().parse())
+        .collect()
+}
@@ -0,0 +1,210 @@
+use std::collections::VecDeque;
+
+use crate::program::{Program, State};
+
+
+/// Address of the NAT, which is not part of the computers in the network.
+pub const NAT_ADDRESS: i64 = 255;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packet {
+    pub destination: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkEvent {
+    /// The NAT received a packet.
+    NatReceived(Packet),
+    /// The network was idle, so the NAT sent its last packet to address 0.
+    NatSent(Packet),
+    /// A packet was sent to an address outside of the network.
+    Dropped(Packet),
+}
+
+#[derive(Debug, Clone)]
+struct Computer {
+    program: Program,
+    queue: VecDeque<i64>,
+    output: Vec<i64>,
+    halted: bool,
+    /// Reading `-1` from the empty queue brought the computer back to the same state without sending anything,
+    /// so it keeps doing that until it receives a packet.
+    idle: bool,
+}
+
+impl Computer {
+    /// Runs the computer until it reads from its empty queue a second time,
+    /// after having read `-1` from it once.
+    fn run(&mut self, packets: &mut Vec<Packet>) {
+        let mut polled = None;
+        let mut sent = false;
+
+        while !self.halted {
+            match self.program.resume() {
+                State::NeedsInput => {
+                    if let Some(value) = self.queue.pop_front() {
+                        self.program.input(value);
+                        polled = None;
+                    } else if polled.is_none() {
+                        polled = Some(self.program.clone());
+                        sent = false;
+                        self.program.input(-1);
+                    } else {
+                        break;
+                    }
+                },
+                State::Output(value) => {
+                    self.output.push(value);
+                    if let [destination, x, y] = self.output[..] {
+                        packets.push(Packet { destination, x, y });
+                        self.output.clear();
+                    }
+                    sent = true;
+                },
+                State::Halted => self.halted = true,
+                State::Running => unreachable!(),
+            }
+        }
+
+        self.idle = self.halted || (!sent && polled.is_some_and(|before| before == self.program));
+    }
+}
+
+/// Network of computers running the same program, scheduled round-robin on a single thread.
+///
+/// Each round, every computer reads its packet queue, reads `-1` from it once it is empty,
+/// and runs until it reads from it again.
+/// When every queue is empty and reading `-1` left every computer in the same state without sending anything,
+/// the network is idle and the NAT sends the last packet it received to address 0.
+///
+/// Iterating the network runs it until the next [`NetworkEvent`].
+/// Iteration stops when all computers halted, or the network is idle without the NAT having a packet.
+#[derive(Debug, Clone)]
+pub struct Network {
+    computers: Vec<Computer>,
+    nat: Option<Packet>,
+    events: VecDeque<NetworkEvent>,
+}
+
+impl Network {
+    /// Boots `size` computers, each receiving its address as first input.
+    pub fn new(program: &Program, size: usize) -> Self {
+        let computers = (0..size)
+            .map(|address| Computer {
+                program: program.clone(),
+                queue: VecDeque::from([address as i64]),
+                output: Vec::with_capacity(3),
+                halted: false,
+                idle: false,
+            })
+            .collect();
+
+        Self {
+            computers,
+            nat: None,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Runs every computer once, and returns whether the network is idle.
+    pub fn round(&mut self) -> bool {
+        let mut packets = Vec::new();
+
+        for i in 0..self.computers.len() {
+            self.computers[i].run(&mut packets);
+
+            for packet in packets.drain(..) {
+                self.route(packet);
+            }
+        }
+
+        self.computers.iter().all(|c| c.idle && c.queue.is_empty())
+    }
+
+    fn route(&mut self, packet: Packet) {
+        if packet.destination == NAT_ADDRESS {
+            self.nat = Some(packet);
+            self.events.push_back(NetworkEvent::NatReceived(packet));
+        } else if let Some(computer) = usize::try_from(packet.destination).ok()
+            .and_then(|address| self.computers.get_mut(address))
+        {
+            computer.queue.extend([packet.x, packet.y]);
+        } else {
+            self.events.push_back(NetworkEvent::Dropped(packet));
+        }
+    }
+}
+
+impl Iterator for Network {
+    type Item = NetworkEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.events.is_empty() {
+            if self.computers.iter().all(|c| c.halted) {
+                return None;
+            }
+            if !self.round() {
+                continue;
+            }
+
+            // Wake up the network
+            let packet = Packet { destination: 0, ..self.nat? };
+            self.route(packet);
+            self.events.push_back(NetworkEvent::NatSent(packet));
+        }
+
+        self.events.pop_front()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Computers other than 0 send (address, 10 * address) to computer 0,
+    // which forwards all packets it receives to the NAT.
+    const FORWARD: &str = "3,38,1006,38,15,1002,38,10,41,104,0,4,38,4,41,3,39,1008,39,-1,41,\
+        1005,41,15,3,40,1005,38,15,104,255,4,39,4,40,1105,1,15,0,0,0,0";
+
+    #[test]
+    pub fn nat() {
+        let program: Program = FORWARD.parse().unwrap();
+        let events: Vec<_> = Network::new(&program, 3).take(6).collect();
+
+        let nat = |x, y| Packet { destination: NAT_ADDRESS, x, y };
+        let zero = |x, y| Packet { destination: 0, x, y };
+        assert_eq!(events, [
+            NetworkEvent::NatReceived(nat(1, 10)),
+            NetworkEvent::NatReceived(nat(2, 20)),
+            NetworkEvent::NatSent(zero(2, 20)),
+            NetworkEvent::NatReceived(nat(2, 20)),
+            NetworkEvent::NatSent(zero(2, 20)),
+            NetworkEvent::NatReceived(nat(2, 20)),
+        ]);
+    }
+
+    #[test]
+    pub fn late_packet() {
+        // Sends (address, 7) to the NAT after the third time it reads -1, counting the reads in between,
+        // and afterwards only reads
+        let program: Program = "3,50,3,51,1001,53,1,53,1008,53,3,52,1006,52,2,104,255,4,50,104,7,3,51,1105,1,21"
+            .parse().unwrap();
+        let events: Vec<_> = Network::new(&program, 2).take(3).collect();
+
+        assert_eq!(events, [
+            NetworkEvent::NatReceived(Packet { destination: NAT_ADDRESS, x: 0, y: 7 }),
+            NetworkEvent::NatReceived(Packet { destination: NAT_ADDRESS, x: 1, y: 7 }),
+            NetworkEvent::NatSent(Packet { destination: 0, x: 1, y: 7 }),
+        ]);
+    }
+
+    #[test]
+    pub fn without_nat() {
+        // Computer 0 never forwards anything in a network of just itself
+        let program: Program = FORWARD.parse().unwrap();
+        assert_eq!(Network::new(&program, 1).next(), None);
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::instruction::{Instruction, Opcode};
+use crate::program::{Input, Output, Program, State, Status};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum EventKind {
+    Start,
+    Input { value: i64 },
+    Output { value: i64 },
+    /// Memory grew to the given number of cells.
+    MemoryGrowth { size: usize },
+    /// Ran out of input.
+    Paused,
+    Halted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Event {
+    /// Index of the run, a profiler can be used for many programs.
+    pub run: usize,
+    /// Number of instructions executed by the profiler, including the one causing this event.
+    pub step: u64,
+    /// Address of the instruction causing this event.
+    pub ip: usize,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+/// Records execution statistics of the programs it runs.
+#[derive(Debug, Clone, Default)]
+pub struct Profiler {
+    runs: usize,
+    steps: u64,
+    elapsed: Duration,
+    opcode_counts: HashMap<Opcode, u64>,
+    /// Execution count per address
+    address_counts: Vec<u64>,
+    instructions: HashMap<usize, Instruction>,
+    memory_growths: usize,
+    max_memory: usize,
+    events: Vec<Event>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs the program like [`Program::run`], while recording its execution.
+    pub fn run(&mut self, program: &mut Program, mut input: impl Input, mut output: impl Output) -> Status {
+        let start = Instant::now();
+        self.record(program.ip(), EventKind::Start);
+
+        let mut memory_size = program.memory_size();
+        let status = loop {
+            let ip = program.ip();
+            let opcode = Opcode::from_code(program[ip] % 100);
+
+            let event = match program.step() {
+                State::Running => None,
+                State::NeedsInput => match input.read() {
+                    Some(value) => {
+                        program.input(value);
+                        Some(EventKind::Input { value })
+                    },
+                    None => {
+                        self.record(ip, EventKind::Paused);
+                        break Status::NeedsInput;
+                    },
+                },
+                State::Output(value) => {
+                    output.write(value);
+                    Some(EventKind::Output { value })
+                },
+                State::Halted => Some(EventKind::Halted),
+            };
+
+            self.count(program, ip, opcode);
+            if let Some(kind) = event {
+                self.record(ip, kind);
+            }
+
+            if program.memory_size() > memory_size {
+                memory_size = program.memory_size();
+                self.memory_growths += 1;
+                self.record(ip, EventKind::MemoryGrowth { size: memory_size });
+            }
+
+            if event == Some(EventKind::Halted) {
+                break Status::Halted;
+            }
+        };
+
+        self.max_memory = self.max_memory.max(program.memory_size());
+        self.elapsed += start.elapsed();
+        self.runs += 1;
+
+        status
+    }
+
+    /// Counts the execution of the instruction at `ip`.
+    fn count(&mut self, program: &Program, ip: usize, opcode: Option<Opcode>) {
+        self.steps += 1;
+        if let Some(opcode) = opcode {
+            *self.opcode_counts.entry(opcode).or_default() += 1;
+        }
+
+        if ip >= self.address_counts.len() {
+            self.address_counts.resize(ip + 1, 0);
+        }
+        self.address_counts[ip] += 1;
+
+        if self.address_counts[ip] == 1 {
+            if let Some(instruction) = program.instruction_at(ip) {
+                self.instructions.insert(ip, instruction);
+            }
+        }
+    }
+
+    fn record(&mut self, ip: usize, kind: EventKind) {
+        self.events.push(Event { run: self.runs, step: self.steps, ip, kind });
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Addresses with their execution count, the most executed first.
+    pub fn hot_spots(&self) -> Vec<(usize, u64)> {
+        let mut hot_spots: Vec<_> = self.address_counts.iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(address, &count)| (address, count))
+            .collect();
+        hot_spots.sort_by(|(a1, c1), (a2, c2)| c2.cmp(c1).then(a1.cmp(a2)));
+
+        hot_spots
+    }
+
+    /// Writes the event timeline as JSON lines.
+    pub fn write_trace(&self, mut writer: impl Write) -> io::Result<()> {
+        for event in &self.events {
+            serde_json::to_writer(&mut writer, event)?;
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Summary of the recorded statistics.
+impl fmt::Display for Profiler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const HOT_SPOTS: usize = 10;
+
+        writeln!(f, "Runs: {}, instructions: {}, time: {:?}", self.runs, self.steps, self.elapsed)?;
+        writeln!(f, "Memory growths: {}, max memory: {} cells", self.memory_growths, self.max_memory)?;
+
+        writeln!(f, "Opcodes:")?;
+        let mut opcodes: Vec<_> = self.opcode_counts.iter().collect();
+        opcodes.sort_by(|a, b| b.1.cmp(a.1));
+        for (opcode, count) in opcodes {
+            let percentage = *count as f64 / self.steps as f64 * 100.0;
+            writeln!(f, "  {:<4} {count:>12} {percentage:>6.2}%", opcode.mnemonic())?;
+        }
+
+        writeln!(f, "Hot spots:")?;
+        for (address, count) in self.hot_spots().into_iter().take(HOT_SPOTS) {
+            let percentage = count as f64 / self.steps as f64 * 100.0;
+            write!(f, "  {address:>6}: {count:>12} {percentage:>6.2}%")?;
+            match self.instructions.get(&address) {
+                Some(instruction) => writeln!(f, "  {instruction}")?,
+                None => writeln!(f)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn counts() {
+        // Outputs 3, 2, 1 and then halts
+        let program: Program = "1101,0,3,20,4,20,1001,20,-1,20,1005,20,4,99".parse().unwrap();
+
+        let mut profiler = Profiler::new();
+        let mut output = Vec::new();
+        for _ in 0..2 {
+            profiler.run(&mut program.clone(), std::iter::empty(), |v| output.push(v));
+        }
+
+        assert_eq!(output, [3, 2, 1, 3, 2, 1]);
+        assert_eq!(profiler.steps(), 2 * (1 + 3 * 3 + 1));
+        assert_eq!(profiler.hot_spots()[0], (4, 6));
+
+        // Start, growth, 3 outputs and halt per run
+        assert_eq!(profiler.events().len(), 12);
+        assert_eq!(profiler.events()[1], Event { run: 0, step: 1, ip: 0, kind: EventKind::MemoryGrowth { size: 21 } });
+
+        let mut trace = Vec::new();
+        profiler.write_trace(&mut trace).unwrap();
+        let output_line = String::from_utf8(trace).unwrap().lines().nth(2).unwrap().to_owned();
+        assert_eq!(output_line, r#"{"run":0,"step":2,"ip":4,"event":"output","value":3}"#);
+    }
+}
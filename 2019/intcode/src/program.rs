@@ -0,0 +1,347 @@
+use std::num::ParseIntError;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::instruction::{Instruction, Mode, Opcode};
+
+
+/// Source of values for the input instruction.
+pub trait Input {
+    /// Returns the next input value, or `None` when there is no input available (yet).
+    fn read(&mut self) -> Option<i64>;
+}
+
+impl<I: Iterator<Item = i64>> Input for I {
+    fn read(&mut self) -> Option<i64> {
+        self.next()
+    }
+}
+
+/// Sink for values produced by the output instruction.
+pub trait Output {
+    fn write(&mut self, value: i64);
+}
+
+impl<F: FnMut(i64)> Output for F {
+    fn write(&mut self, value: i64) {
+        self(value)
+    }
+}
+
+/// Reason for [`Program::resume`] or [`Program::step`] to hand back control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// An instruction got executed and the program can continue.
+    Running,
+    /// The program wants to read a value, provide it with [`Program::input`].
+    NeedsInput,
+    /// The program produced a value.
+    Output(i64),
+    /// The program executed the halt instruction.
+    Halted,
+}
+
+/// Reason for [`Program::run`] to stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The program executed the halt instruction.
+    Halted,
+    /// The program wants to read a value, but the input is exhausted.
+    /// Running the program again continues at the input instruction.
+    NeedsInput,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    pub(crate) program: Vec<i64>,
+    pub(crate) memory: Vec<i64>,
+    pub(crate) relative_base: i64,
+    pub(crate) ip: usize,
+}
+
+impl Program {
+    pub fn new(program: Vec<i64>) -> Self {
+        Self {
+            program,
+            memory: Vec::new(),
+            relative_base: 0,
+            ip: 0,
+        }
+    }
+
+    /// Runs the program until it halts or runs out of input.
+    pub fn run(&mut self, mut input: impl Input, mut output: impl Output) -> Status {
+        loop {
+            match self.resume() {
+                State::NeedsInput => match input.read() {
+                    Some(value) => self.input(value),
+                    None => return Status::NeedsInput,
+                },
+                State::Output(value) => output.write(value),
+                State::Halted => return Status::Halted,
+                State::Running => unreachable!(),
+            }
+        }
+    }
+
+    /// Runs the program until it needs input, produces output or halts.
+    /// Never returns [`State::Running`].
+    pub fn resume(&mut self) -> State {
+        loop {
+            match self.step() {
+                State::Running => continue,
+                state => return state,
+            }
+        }
+    }
+
+    /// Executes a single instruction.
+    ///
+    /// The input instruction is not executed by this method,
+    /// instead [`State::NeedsInput`] is returned until a value is given with [`Program::input`].
+    pub fn step(&mut self) -> State {
+        let Some(opcode) = Opcode::from_code(self[self.ip] % 100) else {
+            panic!("Invalid program: unknown opcode {} at {}", self[self.ip], self.ip);
+        };
+
+        match opcode {
+            Opcode::Add => {
+                let [val1, val2] = self.get_values(self.ip);
+                let loc3 = self.get_location(self.ip, 3);
+
+                self[loc3] = val1 + val2;
+                self.ip += 4;
+            },
+            Opcode::Multiply => {
+                let [val1, val2] = self.get_values(self.ip);
+                let loc3 = self.get_location(self.ip, 3);
+
+                self[loc3] = val1 * val2;
+                self.ip += 4;
+            },
+            Opcode::Input => return State::NeedsInput,
+            Opcode::Output => {
+                let [val1] = self.get_values(self.ip);
+                self.ip += 2;
+
+                return State::Output(val1);
+            },
+            Opcode::JumpIfTrue => {
+                let [val1, val2] = self.get_values(self.ip);
+
+                if val1 != 0 {
+                    self.ip = val2 as usize;
+                } else {
+                    self.ip += 3;
+                }
+            },
+            Opcode::JumpIfFalse => {
+                let [val1, val2] = self.get_values(self.ip);
+
+                if val1 == 0 {
+                    self.ip = val2 as usize;
+                } else {
+                    self.ip += 3;
+                }
+            },
+            Opcode::LessThan => {
+                let [val1, val2] = self.get_values(self.ip);
+                let loc3 = self.get_location(self.ip, 3);
+
+                self[loc3] = (val1 < val2) as i64;
+                self.ip += 4;
+            },
+            Opcode::Equals => {
+                let [val1, val2] = self.get_values(self.ip);
+                let loc3 = self.get_location(self.ip, 3);
+
+                self[loc3] = (val1 == val2) as i64;
+                self.ip += 4;
+            },
+            Opcode::AdjustRelativeBase => {
+                let [val1] = self.get_values(self.ip);
+                self.relative_base += val1;
+
+                self.ip += 2;
+            },
+            Opcode::Halt => return State::Halted,
+        }
+
+        State::Running
+    }
+
+    /// Executes the pending input instruction with the given value.
+    ///
+    /// # Panics
+    /// When the program is not waiting for input.
+    pub fn input(&mut self, value: i64) {
+        assert_eq!(Opcode::from_code(self[self.ip] % 100), Some(Opcode::Input), "Program is not waiting for input");
+
+        let loc1 = self.get_location(self.ip, 1);
+        self[loc1] = value;
+        self.ip += 2;
+    }
+
+    /// Address of the next instruction to execute.
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    /// Number of memory cells in use, including memory that got allocated while running.
+    pub fn memory_size(&self) -> usize {
+        self.program.len() + self.memory.len()
+    }
+
+    /// Decodes the instruction at the given address.
+    pub fn instruction_at(&self, address: usize) -> Option<Instruction> {
+        let cells: Vec<_> = (address..address.saturating_add(4))
+            .map(|a| self[a])
+            .collect();
+
+        Instruction::decode(&cells, 0)
+    }
+
+    fn get_values<const N: usize>(&self, ip: usize) -> [i64; N] {
+        let mut output = [0; N];
+        for (i, item) in output.iter_mut().enumerate() {
+            *item = self.get_value(self.mode(ip, i), ip + i + 1)
+        }
+
+        output
+    }
+
+    fn get_value(&self, mode: Mode, location: usize) -> i64 {
+        match mode {
+            Mode::Position  => self[self[location] as usize],
+            Mode::Immediate => self[location],
+            Mode::Relative  => self[(self[location] + self.relative_base) as usize],
+        }
+    }
+
+    /// Location the `param`th (1-based) parameter of the instruction at `ip` points to.
+    fn get_location(&self, ip: usize, param: usize) -> usize {
+        let location = ip + param;
+        match self.mode(ip, param - 1) {
+            Mode::Position  => self[location] as usize,
+            Mode::Immediate => panic!("Invalid mode for location retrieval"),
+            Mode::Relative  => (self[location] + self.relative_base) as usize,
+        }
+    }
+
+    /// Parameter mode of the `param`th (0-based) parameter of the instruction at `ip`.
+    fn mode(&self, ip: usize, param: usize) -> Mode {
+        Mode::of(self[ip], param)
+            .unwrap_or_else(|| panic!("Invalid parameter mode in {} at {ip}", self[ip]))
+    }
+}
+
+impl FromStr for Program {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse(s).map(Self::new)
+    }
+}
+
+impl Index<usize> for Program {
+    type Output = i64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if index < self.program.len() {
+            &self.program[index]
+        } else if index - self.program.len() < self.memory.len() {
+            &self.memory[index - self.program.len()]
+        } else {
+            &0
+        }
+    }
+}
+
+impl IndexMut<usize> for Program {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index < self.program.len() {
+            &mut self.program[index]
+        } else {
+            let index = index - self.program.len();
+            if index >= self.memory.len() {
+                self.memory.resize(index + 1, 0);
+            }
+
+            &mut self.memory[index]
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::iter;
+
+    use super::*;
+
+    fn run_collect(program: &str, input: &[i64]) -> Vec<i64> {
+        let mut program: Program = program.parse().unwrap();
+
+        let mut output = Vec::new();
+        let status = program.run(input.iter().copied(), |v| output.push(v));
+        assert_eq!(status, Status::Halted);
+
+        output
+    }
+
+    #[test]
+    pub fn position_mode() {
+        let mut program: Program = "1,9,10,3,2,3,11,0,99,30,40,50".parse().unwrap();
+        program.run(iter::empty(), |_| {});
+
+        assert_eq!(program[0], 3500);
+    }
+
+    #[test]
+    pub fn compare() {
+        let program = "3,3,1108,-1,8,3,4,3,99";
+        assert_eq!(run_collect(program, &[8]), [1]);
+        assert_eq!(run_collect(program, &[7]), [0]);
+
+        let program = "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9";
+        assert_eq!(run_collect(program, &[0]), [0]);
+        assert_eq!(run_collect(program, &[5]), [1]);
+    }
+
+    #[test]
+    pub fn relative_base() {
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let expected: Vec<i64> = quine.split(',').map(|s| s.parse().unwrap()).collect();
+
+        assert_eq!(run_collect(quine, &[]), expected);
+        assert_eq!(run_collect("104,1125899906842624,99", &[]), [1125899906842624]);
+    }
+
+    #[test]
+    pub fn resume_after_input() {
+        let mut program: Program = "3,0,4,0,3,0,4,0,99".parse().unwrap();
+
+        let mut output = Vec::new();
+        assert_eq!(program.run(iter::once(1), |v| output.push(v)), Status::NeedsInput);
+        assert_eq!(program.run(iter::once(2), |v| output.push(v)), Status::Halted);
+        assert_eq!(output, [1, 2]);
+    }
+
+    #[test]
+    pub fn resume() {
+        let mut program: Program = "3,0,4,0,99".parse().unwrap();
+
+        assert_eq!(program.resume(), State::NeedsInput);
+        // Without input, the program stays at the input instruction
+        assert_eq!(program.resume(), State::NeedsInput);
+
+        program.input(42);
+        assert_eq!(program.resume(), State::Output(42));
+        assert_eq!(program.resume(), State::Halted);
+        assert_eq!(program.resume(), State::Halted);
+    }
+}
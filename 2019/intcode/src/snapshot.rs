@@ -0,0 +1,158 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::program::Program;
+
+
+const FORMAT: &str = "intcode-snapshot";
+/// Version of the snapshot format, to be increased on incompatible changes.
+const VERSION: u32 = 1;
+
+/// Saved state of a [`Program`], stored as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Snapshot {
+    format: String,
+    version: u32,
+    ip: usize,
+    relative_base: i64,
+    program: Vec<i64>,
+    memory: Vec<i64>,
+}
+
+/// Fields which every version of the format has, to check before the rest is read.
+#[derive(Debug, Deserialize)]
+struct Header {
+    format: String,
+    version: u32,
+}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    NotASnapshot,
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(e) => write!(f, "{e}"),
+            SnapshotError::Json(e) => write!(f, "Invalid snapshot: {e}"),
+            SnapshotError::NotASnapshot => write!(f, "Not an intcode snapshot"),
+            SnapshotError::UnsupportedVersion(v) => write!(f, "Unsupported snapshot version {v}, expected {VERSION}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(e: io::Error) -> Self {
+        SnapshotError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(e: serde_json::Error) -> Self {
+        SnapshotError::Json(e)
+    }
+}
+
+impl Program {
+    /// Writes the full state of the program, so it can be resumed later with [`Program::load`].
+    pub fn save(&self, writer: impl Write) -> Result<(), SnapshotError> {
+        let snapshot = Snapshot {
+            format: FORMAT.to_owned(),
+            version: VERSION,
+            ip: self.ip,
+            relative_base: self.relative_base,
+            program: self.program.clone(),
+            memory: self.memory.clone(),
+        };
+
+        serde_json::to_writer(writer, &snapshot)?;
+        Ok(())
+    }
+
+    /// Restores a program from a snapshot written by [`Program::save`].
+    pub fn load(reader: impl Read) -> Result<Self, SnapshotError> {
+        let value: serde_json::Value = serde_json::from_reader(reader)?;
+
+        let header = Header::deserialize(&value).map_err(|_| SnapshotError::NotASnapshot)?;
+        if header.format != FORMAT {
+            return Err(SnapshotError::NotASnapshot);
+        }
+        if header.version != VERSION {
+            return Err(SnapshotError::UnsupportedVersion(header.version));
+        }
+
+        let snapshot = Snapshot::deserialize(value)?;
+
+        Ok(Self {
+            program: snapshot.program,
+            memory: snapshot.memory,
+            relative_base: snapshot.relative_base,
+            ip: snapshot.ip,
+        })
+    }
+
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.save(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        Self::load(BufReader::new(File::open(path)?))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::program::State;
+
+    use super::*;
+
+    #[test]
+    pub fn save_and_load() {
+        // Counts the inputs it receives, in memory outside of the program
+        let mut program: Program = "3,100,1001,101,1,101,4,101,1105,1,0".parse().unwrap();
+        assert_eq!(program.resume(), State::NeedsInput);
+        program.input(7);
+        assert_eq!(program.resume(), State::Output(1));
+
+        let mut saved = Vec::new();
+        program.save(&mut saved).unwrap();
+        let mut loaded = Program::load(saved.as_slice()).unwrap();
+
+        assert_eq!(loaded.ip(), program.ip());
+        assert_eq!(loaded.memory_size(), program.memory_size());
+        assert_eq!(loaded[100], 7);
+
+        assert_eq!(loaded.resume(), State::NeedsInput);
+        loaded.input(8);
+        assert_eq!(loaded.resume(), State::Output(2));
+    }
+
+    #[test]
+    pub fn versions() {
+        // Later versions can change every field after the header
+        let snapshot = r#"{"format":"intcode-snapshot","version":2,"state":{"pc":0},"image":"99"}"#;
+        assert!(matches!(Program::load(snapshot.as_bytes()), Err(SnapshotError::UnsupportedVersion(2))));
+
+        let snapshot = r#"{"format":"intcode-snapshot","version":1,"ip":0}"#;
+        assert!(matches!(Program::load(snapshot.as_bytes()), Err(SnapshotError::Json(_))));
+        assert!(matches!(Program::load(r#"{"ip":0}"#.as_bytes()), Err(SnapshotError::NotASnapshot)));
+
+        let snapshot = r#"{"format":"other","version":1,"ip":0,"relative_base":0,"program":[99],"memory":[]}"#;
+        assert!(matches!(Program::load(snapshot.as_bytes()), Err(SnapshotError::NotASnapshot)));
+    }
+}
@@ -0,0 +1,374 @@
+//! Assembler for Intcode programs.
+//!
+//! Each line holds an optional label, followed by an instruction or data directive:
+//!
+//! ```text
+//! loop:   jf   [n], #done      ; position mode, immediate mode
+//!         out  rb+0            ; relative mode
+//!         mul  [acc], [n], [acc]
+//!         jt   #1, #loop
+//! done:   hlt
+//! n:      data 5
+//! acc:    data 1, "text\n"
+//! ```
+//!
+//! Labels can be used anywhere a number is expected, optionally with an offset like `table+2`.
+//! Leading addresses, as in the listing of the disassembler, are ignored.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use intcode::{Instruction, Mode, Opcode, Parameter};
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A number, or a label with an offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Number(i64),
+    Label(String, i64),
+}
+
+impl Expr {
+    fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        if let Ok(n) = s.parse() {
+            return Ok(Expr::Number(n));
+        }
+
+        let (label, offset) = match s.find(['+', '-']) {
+            Some(i) => {
+                let offset = s[i..].replace(' ', "")
+                    .parse()
+                    .map_err(|_| format!("Invalid offset in '{s}'"))?;
+                (s[..i].trim(), offset)
+            },
+            None => (s, 0),
+        };
+
+        if !is_identifier(label) {
+            return Err(format!("Invalid expression '{s}'"));
+        }
+        Ok(Expr::Label(label.to_owned(), offset))
+    }
+
+    fn resolve(&self, labels: &HashMap<String, usize>) -> Result<i64, String> {
+        match self {
+            Expr::Number(n) => Ok(*n),
+            Expr::Label(label, offset) => labels.get(label)
+                .map(|&address| address as i64 + offset)
+                .ok_or_else(|| format!("Unknown label '{label}'")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Statement {
+    Instruction(Opcode, Vec<(Mode, Expr)>),
+    Data(Vec<Expr>),
+}
+
+impl Statement {
+    fn parse(s: &str) -> Result<Self, String> {
+        let (mnemonic, operands) = s.split_once(char::is_whitespace)
+            .unwrap_or((s, ""));
+        let operands = split_operands(operands)?;
+
+        if mnemonic == "data" {
+            let mut values = Vec::new();
+            for operand in operands {
+                match operand.strip_prefix('"') {
+                    Some(string) => values.extend(parse_string(string)?.map(Expr::Number)),
+                    None => values.push(Expr::parse(operand)?),
+                }
+            }
+
+            return Ok(Statement::Data(values));
+        }
+
+        let opcode = Opcode::from_mnemonic(mnemonic)
+            .ok_or_else(|| format!("Unknown instruction '{mnemonic}'"))?;
+        if operands.len() != opcode.parameters() {
+            return Err(format!("'{mnemonic}' takes {} parameters, got {}", opcode.parameters(), operands.len()));
+        }
+
+        let parameters = operands.into_iter()
+            .enumerate()
+            .map(|(i, operand)| {
+                let parameter = parse_parameter(operand)?;
+                if parameter.0 == Mode::Immediate && opcode.writes() == Some(i) {
+                    return Err(format!("Parameter {} of '{mnemonic}' is written to, so can't be immediate", i + 1));
+                }
+
+                Ok(parameter)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Statement::Instruction(opcode, parameters))
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Statement::Instruction(_, parameters) => parameters.len() + 1,
+            Statement::Data(values) => values.len(),
+        }
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parses a parameter in one of the forms `[addr]`, `#imm` or `rb+off`.
+fn parse_parameter(s: &str) -> Result<(Mode, Expr), String> {
+    if let Some(inner) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        Ok((Mode::Position, Expr::parse(inner)?))
+    } else if let Some(value) = s.strip_prefix('#') {
+        Ok((Mode::Immediate, Expr::parse(value)?))
+    } else if let Some(offset) = s.strip_prefix("rb") {
+        let offset = offset.trim();
+        let offset = match offset.strip_prefix('+') {
+            _ if offset.is_empty() => Expr::Number(0),
+            Some(offset) => Expr::parse(offset)?,
+            None => Expr::parse(offset)?,
+        };
+
+        Ok((Mode::Relative, offset))
+    } else {
+        Err(format!("Invalid parameter '{s}', expected [addr], #imm or rb+off"))
+    }
+}
+
+/// Parses the contents of a string literal, after the opening quote, into ASCII values.
+fn parse_string(s: &str) -> Result<impl Iterator<Item = i64>, String> {
+    let s = s.strip_suffix('"')
+        .ok_or_else(|| String::from("Unterminated string"))?;
+
+    let mut values = Vec::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        let c = if c == '\\' {
+            match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('0') => '\0',
+                Some(c @ ('\\' | '"')) => c,
+                c => return Err(format!("Invalid escape sequence '\\{}'", c.unwrap_or(' '))),
+            }
+        } else {
+            c
+        };
+
+        if !c.is_ascii() {
+            return Err(format!("Non-ascii character '{c}' in string"));
+        }
+        values.push(c as i64);
+    }
+
+    Ok(values.into_iter())
+}
+
+/// Splits on commas that are not in a string literal.
+fn split_operands(s: &str) -> Result<Vec<&str>, String> {
+    let mut operands = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            ',' if !in_string => {
+                operands.push(s[start..i].trim());
+                start = i + 1;
+            },
+            _ => (),
+        }
+    }
+    if in_string {
+        return Err(String::from("Unterminated string"));
+    }
+
+    let last = s[start..].trim();
+    if !last.is_empty() || !operands.is_empty() {
+        operands.push(last);
+    }
+    if operands.iter().any(|o| o.is_empty()) {
+        return Err(String::from("Empty operand"));
+    }
+
+    Ok(operands)
+}
+
+/// Removes a `;` comment, ignoring semicolons in string literals.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            ';' if !in_string => return &line[..i],
+            _ => (),
+        }
+    }
+
+    line
+}
+
+/// Assembles the source into Intcode.
+pub fn assemble(source: &str) -> Result<Vec<i64>, Error> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+
+    // First pass: parse the statements and find the addresses of labels
+    let mut address = 0;
+    for (i, line) in source.lines().enumerate() {
+        let error = |message| Error { line: i + 1, message };
+
+        let mut rest = strip_comment(line).trim();
+        while let Some((head, tail)) = rest.split_once(':') {
+            let head = head.trim();
+            if is_identifier(head) {
+                if labels.insert(head.to_owned(), address).is_some() {
+                    return Err(error(format!("Duplicate label '{head}'")));
+                }
+            } else if head.parse::<usize>().is_err() {
+                // Not a label nor an address
+                break;
+            }
+
+            rest = tail.trim();
+        }
+
+        if rest.is_empty() {
+            continue;
+        }
+
+        let statement = Statement::parse(rest).map_err(error)?;
+        address += statement.size();
+        statements.push((i + 1, statement));
+    }
+
+    // Second pass: resolve labels and encode
+    let mut memory = Vec::with_capacity(address);
+    for (line, statement) in statements {
+        let error = |message| Error { line, message };
+
+        match statement {
+            Statement::Instruction(opcode, parameters) => {
+                let parameters = parameters.into_iter()
+                    .map(|(mode, expr)| Ok(Parameter { mode, value: expr.resolve(&labels)? }))
+                    .collect::<Result<_, _>>()
+                    .map_err(error)?;
+
+                memory.extend(Instruction { opcode, parameters }.encode());
+            },
+            Statement::Data(values) => {
+                for value in values {
+                    memory.push(value.resolve(&labels).map_err(error)?);
+                }
+            },
+        }
+    }
+
+    Ok(memory)
+}
+
+
+#[cfg(test)]
+mod test {
+    use intcode::{disassemble, Program, Status};
+
+    use super::*;
+
+    const HELLO: &str = r#"
+            arb  #message       ; Point the relative base to the message
+    loop:   jf   rb+0, #end
+            out  rb+0
+            arb  #1
+            jt   #1, #loop
+    end:    hlt
+    message:
+            data "Hello, World!\n", 0
+    "#;
+
+    const FACTORIAL: &str = "
+            in   [n]
+            add  #1, #0, [acc]
+    loop:   jf   [n], #done
+            mul  [acc], [n], [acc]
+            add  [n], #-1, [n]
+            jt   #1, #loop
+    done:   out  [acc]
+            hlt
+    n:      data 0
+    acc:    data 0
+    ";
+
+    fn run(memory: Vec<i64>, input: &[i64]) -> Vec<i64> {
+        let mut output = Vec::new();
+        let status = Program::new(memory).run(input.iter().copied(), |v| output.push(v));
+        assert_eq!(status, Status::Halted);
+
+        output
+    }
+
+    #[test]
+    pub fn encoding() {
+        assert_eq!(assemble("add [9], #2, rb-1").unwrap(), [21001, 9, 2, -1]);
+        assert_eq!(assemble("x: jt #1, #x+2\nhlt").unwrap(), [1105, 1, 2, 99]);
+        assert_eq!(assemble("data \"a;b\", -3 ; comment").unwrap(), [97, 59, 98, -3]);
+    }
+
+    #[test]
+    pub fn execute() {
+        let output = run(assemble(HELLO).unwrap(), &[]);
+        let text: String = output.iter().map(|&c| c as u8 as char).collect();
+        assert_eq!(text, "Hello, World!\n");
+
+        assert_eq!(run(assemble(FACTORIAL).unwrap(), &[5]), [120]);
+        assert_eq!(run(assemble(FACTORIAL).unwrap(), &[0]), [1]);
+    }
+
+    #[test]
+    pub fn round_trip() {
+        for source in [HELLO, FACTORIAL] {
+            let memory = assemble(source).unwrap();
+
+            let listing = disassemble(&memory).iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            assert_eq!(assemble(&listing).unwrap(), memory);
+        }
+    }
+
+    #[test]
+    pub fn errors() {
+        assert_eq!(assemble("jt #1, #nowhere").unwrap_err(), Error { line: 1, message: String::from("Unknown label 'nowhere'") });
+        assert_eq!(assemble("\nin #5").unwrap_err().line, 2);
+        assert!(assemble("add [1], [2]").is_err());
+        assert!(assemble("a: hlt\na: hlt").is_err());
+        assert!(assemble("data \"open").is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use std::io::{self, Read};
+use std::{env, fs, process};
+
+use intcode_asm::assemble;
+
+
+fn main() {
+    // Read the source from the given file, or from stdin otherwise
+    let source = match env::args().nth(1) {
+        Some(path) => fs::read_to_string(path).expect("Could not read source"),
+        None => {
+            let mut source = String::new();
+            io::stdin().read_to_string(&mut source).expect("Could not read source");
+            source
+        },
+    };
+
+    match assemble(&source) {
+        Ok(memory) => {
+            let program: Vec<_> = memory.iter()
+                .map(|v| v.to_string())
+                .collect();
+            println!("{}", program.join(","));
+        },
+        Err(e) => {
+            eprintln!("Error on {e}");
+            process::exit(1);
+        },
+    }
+}
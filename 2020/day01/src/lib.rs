@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+const SUM_TO: i32 = 2020;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| s.parse::<i32>().unwrap())
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        for x in 0..input.len() {
+            for y in x..input.len() {
+                if input[x] + input[y] == SUM_TO {
+                    return input[x] * input[y];
+                }
+            }
+        }
+
+        panic!("No two entries sum to {SUM_TO}");
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        for x in 0..input.len() {
+            for y in x..input.len() {
+                for z in y..input.len() {
+                    if input[x] + input[y] + input[z] == SUM_TO {
+                        return input[x] * input[y] * input[z];
+                    }
+                }
+            }
+        }
+
+        panic!("No three entries sum to {SUM_TO}");
+    }
+}
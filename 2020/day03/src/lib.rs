@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+fn trees_for_slope(map: &[Vec<bool>], (dx, dy): (usize, usize)) -> u32 {
+    let (mut x, mut y) = (0, 0);
+    let mut total_trees = 0;
+    let modulo = map[0].len();
+
+    while y < map.len() - dy {
+        x += dx;
+        y += dy;
+
+        if map[y][x % modulo] {
+            total_trees += 1;
+        }
+    }
+
+    total_trees
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Vec<bool>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| {
+                s.chars()
+                    .map(|c| c == '#')
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Trees for slope (3, 1)
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        trees_for_slope(input, (3, 1))
+    }
+
+    /// Product of trees for the slopes
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        [
+                (1, 1), (3, 1), (5, 1), (7, 1), (1, 2)
+            ].iter()
+            .map(|&s| trees_for_slope(input, s))
+            .product::<u32>()
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+fn get_seat_id(boarding_pass: &str) -> i32 {
+    let row = boarding_pass.chars().take(7).fold(
+        (0, 127),
+        |(low, up), c| {
+            let mid = (low + up) / 2;
+            match c {
+                'F' => (low, mid),
+                'B' => (mid, up),
+                _   => panic!("Invalid row identifier"),
+            }
+        }
+    ).1;
+
+    let col = boarding_pass.chars().skip(7).fold(
+        (0, 7),
+        |(low, up), c| {
+            let mid = (low + up) / 2;
+            match c {
+                'L' => (low, mid),
+                'R' => (mid, up),
+                _   => panic!("Invalid col identifier"),
+            }
+        }
+    ).1;
+
+    row * 8 + col
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 5;
+
+    /// Sorted seat ids
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut input: Vec<_> = input
+            .lines()
+            .map(get_seat_id)
+            .collect();
+        input.sort_unstable();
+
+        input
+    }
+
+    /// Max seat id
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input[input.len() - 1]
+    }
+
+    /// Seat id
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input.windows(2)
+            .filter(|w| w[0] + 2 == w[1])
+            .map(|w| w[0] + 1)
+            .collect::<Vec<_>>()[0]
+    }
+}
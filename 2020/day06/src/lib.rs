@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .split("\n\n")
+            .collect()
+    }
+
+    /// Sum of unique answers per group
+    fn part1(groups: &Self::Input<'_>) -> impl Display {
+        groups.iter()
+            .map(|g| {
+                let person_answers: HashSet<char> = HashSet::from_iter(
+                    g.lines().flat_map(|l| l.chars())
+                );
+
+                person_answers.len()
+            })
+            .sum::<usize>()
+    }
+
+    /// Sum of answers per group
+    fn part2(groups: &Self::Input<'_>) -> impl Display {
+        groups.iter()
+            .map(|g| {
+                let all_answers: HashSet<char> = HashSet::from_iter(
+                    g.lines().flat_map(|l| l.chars())
+                );
+
+                g.lines().fold(all_answers, |acc, p| {
+                    acc.intersection(&HashSet::from_iter(p.chars()))
+                        .cloned()
+                        .collect::<HashSet<char>>()
+                }).len()
+            })
+            .sum::<usize>()
+    }
+}
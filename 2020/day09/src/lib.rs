@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+const PREAMBLE_SIZE: usize = 25;
+
+fn contains_sum(numbers: &[u64], target: u64) -> bool {
+    for i in 0..numbers.len() {
+        for j in 0..i {
+            if numbers[i] + numbers[j] == target {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn find_contiguous_range(numbers: &[u64], target: u64) -> Option<(usize, usize)> {
+    for i in 0..numbers.len() {
+        let mut sum = numbers[i];
+        let mut offset = 0;
+        while sum < target {
+            offset += 1;
+            sum += numbers[i + offset];
+        }
+
+        if sum == target {
+            return Some((i, i + offset));
+        }
+    }
+
+    None
+}
+
+fn first_non_valid(input: &[u64]) -> u64 {
+    *input.iter().enumerate()
+        .skip(PREAMBLE_SIZE)
+        .find(|(i, n)| !contains_sum(&input[i-PREAMBLE_SIZE..*i], **n))
+        .unwrap().1
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| s.parse::<u64>().unwrap())
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        first_non_valid(input)
+    }
+
+    /// Encryption weakness
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let (min_index, max_index) = find_contiguous_range(input, first_non_valid(input)).unwrap();
+        let min_value = input[min_index..=max_index].iter().min().unwrap();
+        let max_value = input[min_index..=max_index].iter().max().unwrap();
+
+        min_value + max_value
+    }
+}
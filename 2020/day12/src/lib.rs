@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use grid::{Direction, Heading};
+
+fn move_position(pos: &mut (i32, i32), direction: Direction, distance: i32) {
+    let (dx, dy) = direction.offset();
+    pos.0 += dy as i32 * distance;
+    pos.1 += dx as i32 * distance;
+}
+
+fn move_to_waypoint(pos: &mut (i32, i32), (wp_x, wp_y): (i32, i32), n: i32) {
+    pos.0 += n * wp_x;
+    pos.1 += n * wp_y;
+}
+
+fn rotate_waypoint_counter_clockwise(wp: &mut (i32, i32), deg: i32) {
+    for _ in 0..(deg / 90) {
+        let tmp_wp0 = wp.0;
+        wp.0 = -wp.1;
+        wp.1 = tmp_wp0;
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .collect()
+    }
+
+    /// Manhattan distance
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let mut current_direction = Direction::East;
+        let mut current_position = (0, 0);
+        for instruction in input {
+            let (i, n) = instruction.split_at(1);
+            let n = n.parse::<i32>().unwrap();
+            match i {
+                "N" => move_position(&mut current_position, Direction::North, n),
+                "S" => move_position(&mut current_position, Direction::South, n),
+                "E" => move_position(&mut current_position, Direction::East, n),
+                "W" => move_position(&mut current_position, Direction::West, n),
+                "L" => current_direction = current_direction.rotate(-n),
+                "R" => current_direction = current_direction.rotate(n),
+                "F" => move_position(&mut current_position, current_direction, n),
+                _   => panic!("Invalid instruction"),
+            }
+        }
+
+        current_position.0.abs() + current_position.1.abs()
+    }
+
+    /// Manhattan distance
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut waypoint_position = (-1, 10);
+        let mut ship_position = (0, 0);
+        for instruction in input {
+            let (i, n) = instruction.split_at(1);
+            let n = n.parse::<i32>().unwrap();
+            match i {
+                "N" => move_position(&mut waypoint_position, Direction::North, n),
+                "S" => move_position(&mut waypoint_position, Direction::South, n),
+                "E" => move_position(&mut waypoint_position, Direction::East, n),
+                "W" => move_position(&mut waypoint_position, Direction::West, n),
+                "L" => rotate_waypoint_counter_clockwise(&mut waypoint_position, n),
+                "R" => rotate_waypoint_counter_clockwise(&mut waypoint_position, 360 - n),
+                "F" => move_to_waypoint(&mut ship_position, waypoint_position, n),
+                _   => panic!("Invalid instruction"),
+            }
+        }
+
+        ship_position.0.abs() + ship_position.1.abs()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn rotate() {
+        let mut waypoint = (10, 4);
+        rotate_waypoint_counter_clockwise(&mut waypoint, 270);
+        assert_eq!(waypoint, (4, -10));
+
+        let mut position = (0, 0);
+        move_position(&mut position, Direction::North, 3);
+        assert_eq!(position, (-3, 0));
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 13;
+
+    /// Earliest departure, and the bus ids with their offset
+    type Input<'a> = (u64, Vec<(u64, u64)>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input.lines().collect();
+
+        let earliest_bus = input[0].parse::<u64>().unwrap();
+        let bus_ids: Vec<_> = input[1].split(',')
+            .enumerate()
+            .filter_map(|(i, s)| {
+                let bus_id = s.parse::<u64>();
+                if let Ok(bus_id) = bus_id {
+                    Some((i as u64, bus_id))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        (earliest_bus, bus_ids)
+    }
+
+    /// Next bus
+    fn part1((earliest_bus, bus_ids): &Self::Input<'_>) -> impl Display {
+        let next_bus = bus_ids.iter()
+            .map(|(_, bus_id)| (*bus_id, *bus_id * ((earliest_bus / *bus_id) + 1)))
+            .min_by(|(_, a1), (_, a2)| a1.cmp(a2))
+            .unwrap();
+
+        next_bus.0 * (next_bus.1 - earliest_bus)
+    }
+
+    /// Earliest time
+    fn part2((_, bus_ids): &Self::Input<'_>) -> impl Display {
+        // Every bus departs `offset` minutes after the time, so the time is -offset modulo the bus id
+        let congruences = bus_ids.iter().map(|&(offset, id)| (-(offset as i64), id as i64));
+
+        math::crt(congruences).expect("The buses never depart in this order").0
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn earliest_time() {
+        assert_eq!(Day13::part2(&Day13::parse("0\n17,x,13,19")).to_string(), "3417");
+        assert_eq!(Day13::part2(&Day13::parse("0\n67,7,x,59,61")).to_string(), "1261476");
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+/// Plays the memory game and returns the number spoken at the given turn.
+fn spoken_number(input: &[usize], turns: u32) -> usize {
+    // Last turn at which each number was spoken, excluding the most recent number
+    let size = usize::max(turns as usize, input.iter().max().unwrap() + 1);
+    let mut spoken = vec![None; size];
+    for (i, n) in input.iter().enumerate().take(input.len() - 1) {
+        spoken[*n] = Some(i as u32 + 1);
+    }
+
+    let mut last_number = input[input.len() - 1];
+    for turn in (input.len() as u32)..turns {
+        last_number = match spoken[last_number].replace(turn) {
+            Some(last_turn) => (turn - last_turn) as usize,
+            None => 0,
+        };
+    }
+
+    last_number
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim()
+            .split(',')
+            .map(|n| n.parse().unwrap())
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        spoken_number(input, 2020)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        spoken_number(input, 30_000_000)
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::{HashSet, HashMap};
+use std::fmt::Display;
+
+use aoc::Solution;
+
+#[derive(Debug, Clone)]
+pub struct Notes<'a> {
+    my_ticket: Vec<u32>,
+    nearby_tickets: Vec<Vec<u32>>,
+    /// All numbers valid for any field
+    seen_numbers: HashSet<u32>,
+    all_labels: HashSet<&'a str>,
+    valid_numbers: HashMap<&'a str, HashSet<u32>>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Notes<'a>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .split("\n\n")
+            .collect();
+
+        // Parse the tickets
+
+        let my_ticket = input[1].lines()
+            .skip(1)
+            .map(|s| s.split(',').map(|n| n.parse::<u32>().unwrap()).collect::<Vec<_>>())
+            .next().unwrap();
+        let nearby_tickets: Vec<_> = input[2].lines()
+            .skip(1)
+            .map(|s| s.split(',').map(|n| n.parse::<u32>().unwrap()).collect::<Vec<_>>())
+            .collect();
+
+        // Keep track of all the possible numbers
+        let mut seen_numbers = HashSet::with_capacity(1000);
+        let mut all_labels = HashSet::new();
+        let mut valid_numbers = HashMap::new();
+
+        for l in input[0].lines() {
+            let (label, ranges) = l.split_once(": ").unwrap();
+            let (range1, range2) = ranges.split_once(" or ").unwrap();
+
+            all_labels.insert(label);
+
+            let range1: Vec<_> = range1.split('-').map(|n| n.parse::<u32>().unwrap()).collect();
+            let range2: Vec<_> = range2.split('-').map(|n| n.parse::<u32>().unwrap()).collect();
+
+            seen_numbers.extend(range1[0]..=range1[1]);
+            seen_numbers.extend(range2[0]..=range2[1]);
+
+            valid_numbers.insert(label, HashSet::new());
+            valid_numbers.get_mut(label).unwrap().extend(range1[0]..=range1[1]);
+            valid_numbers.get_mut(label).unwrap().extend(range2[0]..=range2[1]);
+        }
+
+        Notes { my_ticket, nearby_tickets, seen_numbers, all_labels, valid_numbers }
+    }
+
+    /// Ticket error rate
+    fn part1(notes: &Self::Input<'_>) -> impl Display {
+        notes.nearby_tickets.iter()
+            .flat_map(|ticket| {
+                ticket.iter()
+                    .filter(|n| !notes.seen_numbers.contains(*n))
+            })
+            .sum::<u32>()
+    }
+
+    /// Product of the fields starting with departure
+    fn part2(notes: &Self::Input<'_>) -> impl Display {
+        // Discard invalid tickets
+        let mut nearby_tickets = notes.nearby_tickets.clone();
+        nearby_tickets.retain(|ticket| {
+            ticket.iter().all(|n| notes.seen_numbers.contains(n))
+        });
+
+        // Calculate all the possible labels for each column
+        let mut possible_labels = vec![notes.all_labels.clone(); notes.my_ticket.len()];
+        for (index, pl) in possible_labels.iter_mut().enumerate() {
+            pl.retain(|label| {
+                nearby_tickets.iter()
+                    .map(|row| row[index])
+                    .all(|n| notes.valid_numbers[*label].contains(&n))
+            });
+        }
+
+        // Try to reduce the possibilities for each column by the process of elimination
+        let mut made_changes = true;
+        while made_changes {
+            let mut to_remove = Vec::new();
+            for (i, labels) in possible_labels.iter().enumerate() {
+                if labels.len() == 1 {
+                    to_remove.push((i, <&str>::clone(labels.iter().next().unwrap())));
+                }
+            }
+
+            made_changes = !to_remove.is_empty() && to_remove.len() != possible_labels.len();
+            for (index, labels) in possible_labels.iter_mut().enumerate() {
+                for (i, l) in &to_remove {
+                    if index != *i {
+                        labels.retain(|label| label != l);
+                    }
+                }
+            }
+        }
+
+        // Multiply values of fields starting with departure
+        notes.my_ticket.iter().enumerate()
+            .fold(1, |acc, (i, v)| {
+                if possible_labels[i].iter().next().unwrap().starts_with("departure") {
+                    acc * (*v as u64)
+                } else {
+                    acc
+                }
+            })
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::Display;
+use std::iter::repeat_n;
+use std::str::FromStr;
+
+use aoc::Solution;
+use hashbrown::HashMap;
+
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Character(char),
+    Single(Vec<i32>),
+    Or(Vec<i32>, Vec<i32>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Rules {
+    map: HashMap<i32, Rule>,
+}
+
+impl Rules {
+    pub fn matches<'a>(&self, rule: i32, input: &'a str) -> Option<&'a str> {
+        if input.is_empty() {
+            return None;
+        }
+
+        match &self.map[&rule] {
+            Rule::Character(c) => {
+                if input.chars().next().unwrap() == *c {
+                    Some(&input[1..])
+                } else {
+                    None
+                }
+            },
+            Rule::Single(rules) => self.match_chain(rules, input),
+            Rule::Or(rule1, rule2) => {
+                let rule1_result = self.match_chain(rule1, input);
+
+                rule1_result.or_else(|| self.match_chain(rule2, input))
+            },
+        }
+    }
+
+    #[inline]
+    fn match_chain<'a>(&self, rules: &[i32], input: &'a str) -> Option<&'a str> {
+        rules.iter().try_fold(input, |acc, r| {
+            self.matches(*r, acc)
+        })
+    }
+}
+
+impl FromStr for Rules {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules: Vec<_> = s.lines().collect();
+        let mut map = HashMap::with_capacity(rules.len());
+
+        for rule in rules {
+            let (id, rule) = rule.split_once(": ")
+                .ok_or("Invalid rule can't find ': '")?;
+            let id = id.parse().map_err(|_| "Can't parse id")?;
+
+            let rule =
+                if rule.starts_with('\"') {
+                    Rule::Character(rule.chars().nth(1).unwrap())
+                } else if rule.contains('|') {
+                    let (rule1, rule2) = rule.split_once(" | ").unwrap();
+
+                    Rule::Or(
+                        rule1.split(' ').map(|n| n.parse().unwrap()).collect(),
+                        rule2.split(' ').map(|n| n.parse().unwrap()).collect()
+                    )
+                } else {
+                    Rule::Single(rule.split(' ').map(|n| n.parse().unwrap()).collect())
+                };
+
+            map.insert(id, rule);
+        }
+
+        Ok(Self { map })
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 19;
+
+    type Input<'a> = (Rules, Vec<&'a str>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let (rules, messages) = input
+            .split_once("\n\n")
+            .unwrap();
+
+        let rules = Rules::from_str(rules).unwrap();
+        let messages: Vec<_> = messages.lines().collect();
+
+        (rules, messages)
+    }
+
+    /// Rules matched
+    fn part1((rules, messages): &Self::Input<'_>) -> impl Display {
+        messages.iter()
+            .filter(|m| {
+                if let Some(rest) = rules.matches(0, m) {
+                    rest.is_empty()
+                } else {
+                    false
+                }
+            })
+            .count()
+    }
+
+    /// Rules matched
+    fn part2((rules, messages): &Self::Input<'_>) -> impl Display {
+        // For part 2, rules 8 and 11 are only used in rule 0.
+        // This means that for a message to be valid, we first need to match rule 42 i times,
+        // then rule 31 has to be matched j times with 1 <= j < i.
+
+        let mut rules = rules.clone();
+        messages.iter()
+            .filter(|m| {
+                for i in 1.. {
+                    // Update rule 8
+                    rules.map.insert(8, Rule::Single(repeat_n(42, i).collect()));
+                    if let Some(rule8_remaining) = rules.matches(8, m) {
+                        for j in 1..i {
+                            // Update rule 11
+                            rules.map.insert(11, Rule::Single(repeat_n(31, j).collect()));
+                            if let Some(rule11_remaining) = rules.matches(11, rule8_remaining) {
+                                if rule11_remaining.is_empty() {
+                                    return true;
+                                }
+                            }
+                        }
+                    } else {
+                        return false;
+                    }
+                }
+
+                unreachable!()
+            })
+            .count()
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+/// Plays the crab game with the given amount of cups,
+/// and returns the list where the index number points to the next number.
+fn play(input: &[u32], total_numbers: u32, iterations: u32) -> Vec<u32> {
+    // Create list where the index number points to the next number
+    let mut next_number = vec![0; total_numbers as usize];
+    for i in input.windows(2) {
+        next_number[i[0] as usize] = i[1];
+    }
+
+    if total_numbers as usize > input.len() {
+        next_number[input[input.len() - 1] as usize] = input.len() as u32;
+        for i in input.len() as u32..(total_numbers - 1) {
+            next_number[i as usize] = i + 1;
+        }
+        next_number[total_numbers as usize - 1] = input[0];
+    } else {
+        // Link last number to the first
+        next_number[input[input.len() - 1] as usize] = input[0];
+    }
+
+
+    let mut current_cup = input[0];
+    for _move in 0..iterations {
+        let next1 = next_number[current_cup as usize];
+        let next2 = next_number[next1 as usize];
+        let next3 = next_number[next2 as usize];
+
+        let target = {
+            let mut target = current_cup - 1;
+            if target > total_numbers {
+                target = total_numbers - 1;
+            }
+            while target == next1 || target == next2 || target == next3 {
+                target -= 1;
+
+                if target > total_numbers {
+                    target = total_numbers - 1;
+                }
+            }
+
+            target
+        };
+
+        next_number[current_cup as usize] = next_number[next3 as usize];
+        next_number[next3 as usize] = next_number[target as usize];
+        next_number[target as usize] = next1;
+
+
+        current_cup = next_number[current_cup as usize];
+    }
+
+    next_number
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 23;
+
+    type Input<'a> = [u32; 9];
+
+    /// Cup labels are made zero based
+    fn parse(input: &str) -> Self::Input<'_> {
+        let cups: Vec<_> = input.trim()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() - 1)
+            .collect();
+
+        cups.try_into().unwrap()
+    }
+
+    /// Labels after cup 1
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        const TOTAL_NUMBERS: u32 = 9;
+        let next_number = play(input, TOTAL_NUMBERS, 100);
+
+        let mut result = Vec::new();
+        let mut current = 0;
+        for _ in 0..(TOTAL_NUMBERS - 1) {
+            result.push(next_number[current]);
+
+            current = next_number[current] as usize;
+        }
+
+        result.iter().map(|i| i +  1).fold(0, |acc, n| acc * 10 + n)
+    }
+
+    /// Product of the two cups after cup 1
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let next_number = play(input, 1_000_000, 10_000_000);
+
+        let next1 = next_number[0] as u64;
+        let next2 = next_number[next1 as usize] as u64;
+
+        (next1 + 1) * (next2 + 1)
+    }
+}
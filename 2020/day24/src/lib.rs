@@ -0,0 +1,133 @@
+use std::array;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use aoc::Solution;
+use grid::{Heading, HexDirection};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    White,
+    Black,
+}
+
+impl Tile {
+    pub fn flip(&mut self) {
+        match self {
+            Tile::White => *self = Tile::Black,
+            Tile::Black => *self = Tile::White,
+        }
+    }
+}
+
+
+fn step((x, y): (i32, i32), direction: HexDirection) -> (i32, i32) {
+    let (dx, dy) = direction.offset();
+    (x + dx as i32, y + dy as i32)
+}
+
+fn get_tile(mut instruction: &str) -> (i32, i32) {
+    let mut current_tile = (0, 0);
+
+    while !instruction.is_empty() {
+        let (direction, rest) = HexDirection::split_first(instruction).expect("Invalid direction");
+        current_tile = step(current_tile, direction);
+        instruction = rest;
+    }
+
+    current_tile
+}
+
+/// Returns the neighbours of a coordinate
+fn get_neighbours(c: (i32, i32)) -> [(i32, i32); 6] {
+    array::from_fn(|i| step(c, HexDirection::ALL[i]))
+}
+
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 24;
+
+    /// Hashmap linking a coordinate with a tile colour
+    type Input<'a> = HashMap<(i32, i32), Tile>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut flipped_tiles = HashMap::new();
+
+        for instruction in input.lines() {
+            let current_tile = get_tile(instruction);
+            flipped_tiles.entry(current_tile).or_insert(Tile::White).flip();
+        }
+
+        flipped_tiles
+    }
+
+    /// Black tiles
+    fn part1(flipped_tiles: &Self::Input<'_>) -> impl Display {
+        flipped_tiles.values().filter(|i| **i == Tile::Black).count()
+    }
+
+    /// Black tiles after day 100
+    fn part2(flipped_tiles: &Self::Input<'_>) -> impl Display {
+        let mut flipped_tiles = flipped_tiles.clone();
+
+        let mut tiles_to_flip = HashSet::new();
+        for _day in 0..100 {
+            // Go through the black tiles to see which tiles need to flipped.
+            for ((x, y), tile) in flipped_tiles.iter() {
+                match tile {
+                    Tile::White => continue,
+                    Tile::Black => {
+                        let neighbours: Vec<_> = get_neighbours((*x, *y))
+                            .into_iter()
+                            .map(|n| (n, flipped_tiles.get(&n).unwrap_or(&Tile::White)))
+                            .collect();
+
+                        let black_neighbours = neighbours.iter().filter(|(_, t)| **t == Tile::Black).count();
+                        if black_neighbours == 0 || black_neighbours > 2 {
+                            tiles_to_flip.insert((*x, *y));
+                        }
+
+                        // Check white neighbours, as white tiles can only be flipped if they have at least one black neighbour.
+                        for c in neighbours.iter()
+                            .filter(|(_, t)| **t == Tile::White)
+                            .map(|(c, _)| c)
+                        {
+                            let black_neighbours = get_neighbours(*c).into_iter()
+                                .filter(|c| *flipped_tiles.get(c).unwrap_or(&Tile::White) == Tile::Black)
+                                .count();
+
+                            if black_neighbours == 2 {
+                                tiles_to_flip.insert(*c);
+                            }
+                        }
+                    },
+                }
+            }
+
+            // Flip all the tiles we marked to be flipped
+            for t in &tiles_to_flip {
+                flipped_tiles.entry(*t).or_insert(Tile::White).flip();
+            }
+            // Clear hash-set for next iteration
+            tiles_to_flip.clear();
+        }
+
+        flipped_tiles.values().filter(|i| **i == Tile::Black).count()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn tiles() {
+        assert_eq!(get_tile("esew"), (1, 1));
+        assert_eq!(get_tile("nwwswee"), (0, 0));
+        assert!(get_neighbours((0, 0)).contains(&(1, 1)));
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+const SUBJECT_NUMBER: i64 = 7;
+const MODULUS: i64 = 20201227;
+
+fn loop_size(public_key: i64) -> u64 {
+    math::discrete_log(SUBJECT_NUMBER, public_key, MODULUS).expect("Public key is never reached")
+}
+
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 25;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| s.parse::<i64>().unwrap())
+            .collect()
+    }
+
+    /// Encryption key
+    fn part1(public_keys: &Self::Input<'_>) -> impl Display {
+        let card_loops = loop_size(public_keys[0]);
+
+        math::mod_pow(public_keys[1], card_loops, MODULUS)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn loop_sizes() {
+        assert_eq!(loop_size(5764801), 8);
+        assert_eq!(loop_size(17807724), 11);
+    }
+}
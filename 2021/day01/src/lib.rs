@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+/// Counts the increases of the sum of a sliding window over the measurements.
+fn increases(input: &[i32], window_size: usize) -> usize {
+    let mut increases = 0;
+    for i in window_size..input.len() {
+        // We can obtain the new measurement by subtracting input[i - window_size]
+        // from the previous sum and adding input[i].
+        // This leads to a measurement increase iff input[i - window_size] < input[i].
+        // Thus, we only need to check this condition, meaning there is no need to keep
+        // track of the previous total.
+        if input[i - window_size] < input[i] {
+            increases += 1;
+        }
+    }
+
+    increases
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|i| i.parse::<i32>().unwrap())
+            .collect()
+    }
+
+    /// Measurement increases
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        increases(input, 1)
+    }
+
+    /// Measurement increases of a three-measurement sliding window
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        increases(input, 3)
+    }
+}
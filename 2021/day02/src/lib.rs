@@ -0,0 +1,78 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+
+
+pub enum Direction {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl FromStr for Direction {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<_> = s.split(' ').collect();
+
+        if let Ok(magnitude) = parts[1].parse::<i32>() {
+            match parts[0] {
+                "forward" => Ok(Direction::Forward(magnitude)),
+                "down"    => Ok(Direction::Down(magnitude)),
+                "up"      => Ok(Direction::Up(magnitude)),
+                _         => Err("Invalid direction")
+            }
+        } else {
+            Err("Can't parse magnitude")
+        }
+    }
+}
+
+
+/// Follows the course, returns the forward position, the aim and the depth.
+/// The aim is the depth when interpreting the commands as in part 1.
+fn follow_course(input: &[Direction]) -> (i32, i32, i32) {
+    let mut forward = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+
+    for direction in input {
+        match direction {
+            Direction::Forward(x) => {
+                forward += x;
+                depth   += aim * x;
+            },
+            Direction::Down(x)    => aim += x,
+            Direction::Up(x)      => aim -= x,
+        }
+    }
+
+    (forward, aim, depth)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| Direction::from_str(s).unwrap())
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let (forward, aim, _) = follow_course(input);
+        forward * aim
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let (forward, _, depth) = follow_course(input);
+        forward * depth
+    }
+}
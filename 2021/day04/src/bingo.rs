@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Bingo {
     marked_fields: [[bool; 5]; 5],
     unmarked_numbers: HashMap<i32, (usize, usize)>,
@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+mod bingo;
+
+use aoc::Solution;
+use bingo::Bingo;
+
+/// Scores of the boards in the order they get a bingo, while marking the numbers.
+fn bingo_scores(numbers: &[i32], mut boards: Vec<Bingo>) -> impl Iterator<Item = i32> + '_ {
+    numbers.iter().flat_map(move |&n| {
+        // Modify the boards to mark the number, and score each board that has a bingo
+        let scores: Vec<_> = boards.iter_mut()
+            .filter_map(|board| board.mark_number(n).then(|| n * board.get_score()))
+            .collect();
+
+        // Retain the boards that don't have a bingo
+        boards.retain(|board| !board.check_bingo());
+
+        scores
+    })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 4;
+
+    /// Bingo numbers and boards
+    type Input<'a> = (Vec<i32>, Vec<Bingo>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .lines()
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        // Parse the bingo numbers
+        let bingo_numbers: Vec<_> = input[0]
+            .split(',')
+            .map(|s| s.parse::<i32>().unwrap())
+            .collect();
+
+        // Get all the bingo boards
+        let mut bingo_boards = Vec::new();
+        for i in (1..input.len()).step_by(5) {
+            bingo_boards.push(Bingo::new(&input[i..i+5]));
+        }
+
+        (bingo_numbers, bingo_boards)
+    }
+
+    /// Best board score
+    fn part1((numbers, boards): &Self::Input<'_>) -> impl Display {
+        bingo_scores(numbers, boards.clone()).next().unwrap()
+    }
+
+    /// Worst board score
+    fn part2((numbers, boards): &Self::Input<'_>) -> impl Display {
+        bingo_scores(numbers, boards.clone()).last().unwrap()
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+use std::mem;
+
+use aoc::Solution;
+
+/// Simulates the lanternfish for the given number of days and counts them.
+fn total_fish(input: &[usize], total_days: i32) -> u64 {
+    let mut current_fish = [0_u64; 9];
+    // Fill current fish array
+    for f in input {
+        current_fish[*f] += 1;
+    }
+    // Create temporary storage for the next iteration
+    let mut next_fish = [0_u64; 9];
+
+    for _current_day in 0..total_days {
+        // Let all fish decrease by 1 day.
+        next_fish[0..8].copy_from_slice(&current_fish[1..9]);
+
+        // Create new fish
+        next_fish[8] = current_fish[0];
+
+        // Reset fishes that reached day 0
+        next_fish[6] += current_fish[0];
+
+        // Push next_fish to current_fish by swapping the memory
+        mem::swap(&mut current_fish, &mut next_fish);
+    }
+
+    current_fish.iter().sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim()
+            .split(',')
+            .map(|s| s.parse::<usize>().unwrap())
+            .collect()
+    }
+
+    /// Total fish after 80 days
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        total_fish(input, 80)
+    }
+
+    /// Total fish after 256 days
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        total_fish(input, 256)
+    }
+}
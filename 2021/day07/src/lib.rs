@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+fn constant_fuel_cost(positions: &[i32], move_to: i32) -> i32 {
+    positions.iter()
+        .map(|p| (p - move_to).abs())
+        .sum()
+}
+
+fn fuel_cost(positions: &[i32], move_to: i32) -> i32 {
+    positions.iter()
+        .map(|p| {
+            let difference = (p - move_to).abs();
+
+            // NOTE: 1 + 2 + ... + n == n(n+1) / 2
+            (difference * (difference + 1)) / 2
+        })
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim()
+            .split(',')
+            .map(|s| s.parse::<i32>().unwrap())
+            .collect()
+    }
+
+    /// Least fuel cost
+    fn part1(positions: &Self::Input<'_>) -> impl Display {
+        let min = *positions.iter().min().unwrap();
+        let max = *positions.iter().max().unwrap();
+
+        (min..max)
+            .map(|p| constant_fuel_cost(positions, p))
+            .min().unwrap()
+    }
+
+    /// Least fuel cost
+    fn part2(positions: &Self::Input<'_>) -> impl Display {
+        let min = *positions.iter().min().unwrap();
+        let max = *positions.iter().max().unwrap();
+
+        (min..max)
+            .map(|p| fuel_cost(positions, p))
+            .min().unwrap()
+    }
+}
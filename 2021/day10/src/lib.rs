@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+fn bracket_matches(opening: char, closing: char) -> bool {
+    match opening {
+        '(' => closing == ')',
+        '[' => closing == ']',
+        '{' => closing == '}',
+        '<' => closing == '>',
+        _   => unreachable!(),
+    }
+}
+
+fn incorrect_bracket_score(bracket: char) -> i32 {
+    match bracket {
+        ')' =>     3,
+        ']' =>    57,
+        '}' =>  1197,
+        '>' => 25137,
+        _   => unreachable!(),
+    }
+}
+
+fn insert_bracket_score(bracket: char) -> u64 {
+    match bracket {
+        '(' => 1,
+        '[' => 2,
+        '{' => 3,
+        '<' => 4,
+        _   => unreachable!(),
+    }
+}
+
+
+fn parse_line(line: &str) -> (i32, Vec<char>) {
+    let mut stack = Vec::with_capacity(line.len());
+
+    for c in line.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' | ']' | '}' | '>' => {
+                let last_item = stack.pop().unwrap();
+                if !bracket_matches(last_item, c) {
+                    return (incorrect_bracket_score(c), stack);
+                }
+            },
+            _ => panic!("Invalid bracket"),
+        }
+    }
+
+    (0, stack)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 10;
+
+    /// Syntax error score and remaining open brackets of each line
+    type Input<'a> = Vec<(i32, Vec<char>)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(parse_line)
+            .collect()
+    }
+
+    /// Invalid syntax error score
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.iter()
+            .map(|(s, _)| s)
+            .sum::<i32>()
+    }
+
+    /// Fix missing brackets score
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut fix_missing_scores: Vec<_> = input.iter()
+            // Only fix incomplete sequences and ignore the invalid ones.
+            .filter(|(s, _)| *s == 0)
+            .map(|(_, remaining)| {
+                // Make sure to go in reverse order through the stack,'
+                // as the last item needs to be closed first.
+                remaining.iter().rev().fold(0, |acc, c| {
+                    acc * 5 + insert_bracket_score(*c)
+                })
+            })
+            .collect();
+        fix_missing_scores.sort_unstable();
+
+        fix_missing_scores[fix_missing_scores.len() / 2]
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use aoc::Solution;
+use grid::{Grid, Point};
+
+
+const STEPS: i32 = 100;
+
+
+fn simulate_step(map: &mut Grid<u8>) -> u32 {
+    // Go through the map to trigger flashes
+    for p in map.points() {
+        flash(map, p);
+    }
+
+    // Count flashes and reset energy levels
+    let mut flashes = 0;
+    for (_, item) in map.iter_mut() {
+        if *item > 9 {
+            flashes += 1;
+            *item = 0;
+        }
+    }
+
+    flashes
+}
+
+fn flash(map: &mut Grid<u8>, p: Point) {
+    let mut queue = VecDeque::from([p]);
+
+    while let Some(current) = queue.pop_front() {
+        map[current] += 1;
+
+        // Check if this triggered the first flash for a octopus,
+        // if so add the neighbours to the queue
+        if map[current] == 10 {
+            queue.extend(map.neighbors8(current));
+        }
+    }
+}
+
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 11;
+
+    /// Energy levels of the octopuses
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    /// Total flashes in the first steps
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let mut map = input.clone();
+
+        (0..STEPS)
+            .map(|_| simulate_step(&mut map))
+            .sum::<u32>()
+    }
+
+    /// Synchronisation step
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut map = input.clone();
+
+        let mut current_step = 0;
+        loop {
+            let flashes = simulate_step(&mut map);
+
+            // Check if the whole grid flashed to see if we have synchronisation
+            if flashes as usize == map.cells().len() {
+                break;
+            }
+            current_step += 1;
+        }
+
+        current_step + 1
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn flashes() {
+        let mut map: Grid<u8> = include_str!("../example.txt").parse().unwrap();
+        let flashes: Vec<_> = (0..10).map(|_| simulate_step(&mut map)).collect();
+
+        assert_eq!(flashes[..2], [0, 35]);
+        assert_eq!(flashes.iter().sum::<u32>(), 204);
+    }
+}
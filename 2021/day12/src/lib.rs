@@ -0,0 +1,80 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+mod graph;
+
+use aoc::Solution;
+use bitset::BitSet;
+use graph::Graph;
+
+
+fn paths(graph: &Graph, allow_visiting_twice: bool) -> i32 {
+    let mut paths = 0;
+    let mut queue = VecDeque::from([(graph.start_vertex(), BitSet::<1>::new(), false)]);
+
+    while let Some((current, visited, visited_twice)) = queue.pop_front() {
+        let mut new_visited = visited;
+        let mut new_visited_twice = visited_twice;
+        // Check if we are allowed to visit this vertex
+        if !graph.big_cave()[current as usize] && new_visited.contains(current as usize) {
+            if allow_visiting_twice && !new_visited_twice {
+                new_visited_twice = true;
+            } else {
+                continue;
+            }
+        }
+        // Visit this vertex
+        new_visited.insert(current as usize);
+
+        if current == graph.end_vertex() {
+            paths += 1;
+            continue;
+        }
+
+        for neighbour in &graph.adjacency_list()[current as usize] {
+            if *neighbour != graph.start_vertex() {
+                queue.push_back((*neighbour, new_visited, new_visited_twice));
+            }
+        }
+    }
+
+    paths
+}
+
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Graph;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Graph::new(input)
+    }
+
+    /// Paths
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        paths(input, false)
+    }
+
+    /// Paths visiting a single small cave twice
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        paths(input, true)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn larger_example() {
+        let graph = Graph::new("dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sj\nkj-HN\nkj-dc");
+
+        assert_eq!(paths(&graph, false), 19);
+        assert_eq!(paths(&graph, true), 103);
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc::{ocr, Solution};
+
+fn fold(dots: &HashSet<(i32, i32)>, axis: char, n: i32) -> HashSet<(i32, i32)> {
+    match axis {
+        'x' => dots.iter()
+            .map(|&(x, y)| if x > n {
+                (n - (x - n), y)
+            } else {
+                (x, y)
+            })
+            .collect(),
+        'y' => dots.iter()
+            .map(|&(x, y)| if y > n {
+                (x, n - (y - n))
+            } else {
+                (x, y)
+            })
+            .collect(),
+        _ => panic!("Invalid axis"),
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 13;
+
+    /// Dots and fold instructions
+    type Input<'a> = (HashSet<(i32, i32)>, Vec<(char, i32)>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .split("\n\n")
+            .collect();
+
+        let dots = input[0].lines()
+            .map(|s| {
+                let nums: Vec<_> = s.split(',')
+                    .map(|n| n.parse::<i32>().unwrap())
+                    .collect();
+
+                (nums[0], nums[1])
+            })
+            .collect();
+
+        let instructions = input[1].lines()
+            .map(|s| {
+                let axis = s.chars().nth(11).unwrap();
+                let n = s.split_at(13).1.parse::<i32>().unwrap();
+
+                (axis, n)
+            })
+            .collect();
+
+        (dots, instructions)
+    }
+
+    /// Dots after the first fold
+    fn part1((dots, instructions): &Self::Input<'_>) -> impl Display {
+        let (axis, n) = instructions[0];
+        fold(dots, axis, n).len()
+    }
+
+    /// Code
+    fn part2((dots, instructions): &Self::Input<'_>) -> impl Display {
+        let dots = instructions.iter()
+            .fold(dots.clone(), |dots, &(axis, n)| fold(&dots, axis, n));
+
+        let max_x = dots.iter().max_by(|(x1, _), (x2, _)| x1.cmp(x2)).unwrap().0 as usize;
+        let max_y = dots.iter().max_by(|(_, y1), (_, y2)| y1.cmp(y2)).unwrap().1 as usize;
+        let mut grid = vec![vec![false; max_x + 1]; max_y + 1];
+        for (x, y) in dots {
+            grid[y as usize][x as usize] = true;
+        }
+
+        ocr::text(&grid)
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+fn dijkstra(map: &[Vec<u32>], start: (usize, usize), end: (usize, usize), width: usize, height: usize) -> u32 {
+    let neighbours = |&(x, y): &(usize, usize)| {
+        [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)].into_iter()
+            .filter(|&(x, y)| x < width && y < height)
+            .map(|(x, y)| ((x, y), map[y][x]))
+    };
+
+    search::dijkstra([start], neighbours, |&p| p == end).unwrap().cost
+}
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| s.chars().map(|c| c.to_digit(10).unwrap()).collect::<Vec<_>>())
+            .collect()
+    }
+
+    /// Path risk
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let height = input.len();
+        let width = input[0].len();
+
+        dijkstra(input, (0, 0), (width - 1, height - 1), width, height)
+    }
+
+    /// Path risk in the extended cave
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let height = input.len();
+        let width = input[0].len();
+
+        let extended_input: Vec<_> = (0..5 * height)
+            .map(|y| {
+                (0..5 * width)
+                    .map(|x| {
+                        let mut risk_level = input[y % height][x % width] + (y / height) as u32 + (x / width) as u32;
+                        if risk_level > 9 {
+                            risk_level -= 9;
+                        }
+
+                        risk_level
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        dijkstra(&extended_input, (0, 0), (5 * width - 1, 5 * height - 1), 5 * width, 5 * height)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn lowest_risk() {
+        let map = Day15::parse("1911\n1119\n9911");
+
+        assert_eq!(dijkstra(&map, (0, 0), (3, 2), 4, 3), 5);
+    }
+}
@@ -0,0 +1,101 @@
+use std::cmp;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Target {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+}
+
+impl FromStr for Target {
+    type Err = &'static str;
+
+    /// Parses `target area: x=20..30, y=-10..-5`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.trim()
+            .strip_prefix("target area: x=")
+            .and_then(|s| s.split_once(", y="))
+            .ok_or("Invalid target area")?;
+
+        let range = |r: &str| -> Result<(i32, i32), Self::Err> {
+            let (min, max) = r.split_once("..").ok_or("Invalid range")?;
+            let min = min.parse().map_err(|_| "Invalid number")?;
+            let max = max.parse().map_err(|_| "Invalid number")?;
+            Ok((min, max))
+        };
+        let (x_min, x_max) = range(x)?;
+        let (y_min, y_max) = range(y)?;
+
+        Ok(Self { x_min, x_max, y_min, y_max })
+    }
+}
+
+fn simulate(target: &Target, start_x: i32, start_y: i32) -> (bool, i32) {
+    let mut current_x = 0;
+    let mut vx = start_x;
+    let mut current_y = 0;
+    let mut vy = start_y;
+
+    let mut max_y = 0;
+    let mut hit_target = false;
+
+    while current_y + vy >= target.y_min && current_x + vx <= target.x_max {
+        current_x += vx;
+        current_y += vy;
+
+        vx = cmp::max(vx - 1, 0);
+        vy -= 1;
+
+        if current_y > max_y {
+            max_y = current_y;
+        }
+
+        if (target.x_min..=target.x_max).contains(&current_x)
+            && (target.y_min..=target.y_max).contains(&current_y)
+        {
+            hit_target = true;
+            break;
+        }
+    }
+
+    (hit_target, max_y)
+}
+
+/// Highest y position of each initial velocity which hits the target
+fn hits(target: &Target) -> impl Iterator<Item = i32> + '_ {
+    (1..(target.x_max + 1))
+        .flat_map(move |x| (target.y_min..(-target.y_min + 1)).map(move |y| (x, y)))
+        .filter_map(|(x, y)| {
+            let (hit_target, max_y) = simulate(target, x, y);
+            hit_target.then_some(max_y)
+        })
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Target;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Target::from_str(input).unwrap()
+    }
+
+    /// Max y
+    fn part1(target: &Self::Input<'_>) -> impl Display {
+        hits(target).max().unwrap()
+    }
+
+    /// Total hits
+    fn part2(target: &Self::Input<'_>) -> impl Display {
+        hits(target).count()
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use snailfish::Snailfish;
+
+mod snailfish;
+
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<Snailfish>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| {
+                let (rem, sf) = Snailfish::parse(s).unwrap();
+                debug_assert!(rem.is_empty());
+                sf
+            })
+            .collect()
+    }
+
+    /// Magnitude
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let mut snailfish = input[0].clone();
+        for sf in input.iter().skip(1).cloned() {
+            snailfish = Snailfish::add(snailfish, sf);
+        }
+
+        snailfish.magnitude()
+    }
+
+    /// Largest magnitude
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut largest_magnitude = 0;
+        for i in 0..input.len() {
+            for j in 0..i {
+                let mag1 = Snailfish::add(input[i].clone(), input[j].clone()).magnitude();
+                let mag2 = Snailfish::add(input[j].clone(), input[i].clone()).magnitude();
+
+                if mag1 > largest_magnitude {
+                    largest_magnitude = mag1;
+                }
+                if mag2 > largest_magnitude {
+                    largest_magnitude = mag2;
+                }
+            }
+        }
+
+        largest_magnitude
+    }
+}
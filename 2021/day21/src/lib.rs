@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use cached::proc_macro::cached;
+
+pub struct DeterministicDie(i32);
+
+impl DeterministicDie {
+    pub fn new() -> Self {
+        Self(1)
+    }
+
+    pub fn roll(&mut self) -> i32 {
+        let value = self.0;
+        self.0 += 1;
+        if self.0 > 100 {
+            self.0 = 1;
+        }
+        value
+    }
+}
+
+impl Default for DeterministicDie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// Positions are zero based, so 0 to 9 instead of 1 to 10.
+fn play_deterministic(starting_positions: [i32; 2]) -> i32 {
+    let mut player_positions = starting_positions;
+    let mut player_scores = [0, 0];
+
+
+    let mut round = 0;
+    let mut die = DeterministicDie::new();
+    while player_scores[0] < 1000 && player_scores[1] < 1000 {
+        let player = round % 2;
+
+        // Update player position
+        player_positions[player] += die.roll() + die.roll() + die.roll();
+        player_positions[player] %= 10;
+        // Update player score
+        player_scores[player] += player_positions[player] + 1;
+
+        round += 1;
+    }
+
+    (round as i32) * 3 * player_scores[0].min(player_scores[1])
+}
+
+#[cached]
+fn play_dirac(positions: [i32; 2], scores: [i32; 2], player1_turn: bool) -> [i64; 2] {
+    if scores[0] >= 21 {
+        return [1, 0];
+    } else if scores[1] >= 21 {
+        return [0, 1];
+    }
+
+    let player =
+        if player1_turn {
+            0
+        } else {
+            1
+        };
+    let mut p1_wins = 0;
+    let mut p2_wins = 0;
+
+    for (die_outcome, amount) in [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)] {
+        let mut new_position = positions;
+        let mut new_score = scores;
+        new_position[player] += die_outcome;
+        new_position[player] %= 10;
+        new_score[player] += new_position[player] + 1;
+
+        let [p1, p2] = play_dirac(new_position, new_score, !player1_turn);
+        p1_wins += p1 * amount;
+        p2_wins += p2 * amount;
+    }
+
+    [p1_wins, p2_wins]
+}
+
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 21;
+
+    /// Zero based starting positions of both players
+    type Input<'a> = [i32; 2];
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let positions: Vec<i32> = input
+            .lines()
+            .map(|l| {
+                let (_, position) = l.split_once(": ").unwrap();
+                position.parse::<i32>().unwrap() - 1
+            })
+            .collect();
+
+        [positions[0], positions[1]]
+    }
+
+    /// Deterministic game score
+    fn part1(starting_positions: &Self::Input<'_>) -> impl Display {
+        play_deterministic(*starting_positions)
+    }
+
+    /// Dirac game most wins
+    fn part2(starting_positions: &Self::Input<'_>) -> impl Display {
+        *play_dirac(*starting_positions, [0, 0], true).iter().max().unwrap()
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use interval::{Cuboid, CuboidSet};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    On, Off
+}
+
+#[derive(Debug, Clone)]
+pub struct Command {
+    command: Status,
+    cuboid: Cuboid<i64, 3>,
+}
+
+fn get_on_cubes(commands: &[Command]) -> i64 {
+    let mut on_cubes = CuboidSet::new();
+    for command in commands {
+        match command.command {
+            Status::On => on_cubes.insert(command.cuboid.clone()),
+            Status::Off => on_cubes.remove(&command.cuboid),
+        }
+    }
+
+    on_cubes.volume()
+}
+
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 22;
+
+    type Input<'a> = Vec<Command>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| {
+                let (command, coords) = s.split_once(' ').unwrap();
+                let command = match command {
+                    "on" => Status::On,
+                    "off" => Status::Off,
+                    _ => panic!("Invalid command"),
+                };
+                let coords: Vec<_> = coords.split(',')
+                    .flat_map(|c| {
+                        c[2..].split("..").map(|n| n.parse::<i64>().unwrap())
+                    })
+                    .collect();
+
+                Command {
+                    command,
+                    cuboid: Cuboid::new([
+                        coords[0]..coords[1] + 1,
+                        coords[2]..coords[3] + 1,
+                        coords[4]..coords[5] + 1,
+                    ]),
+                }
+            })
+            .collect()
+    }
+
+    /// On cubes in the initialization region
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let region = Cuboid::new([-50..51, -50..51, -50..51]);
+        let initialization_input: Vec<_> = input.iter()
+            .map(|c| Command {
+                command: c.command,
+                cuboid: c.cuboid.intersection(&region),
+            })
+            .collect();
+
+        get_on_cubes(&initialization_input)
+    }
+
+    /// On cubes
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        get_on_cubes(input)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn on_cubes() {
+        let commands = Day22::parse(include_str!("../example.txt"));
+
+        assert_eq!(get_on_cubes(&commands[..1]), 27);
+        assert_eq!(get_on_cubes(&commands[..2]), 46);
+        assert_eq!(get_on_cubes(&commands[..3]), 38);
+        assert_eq!(get_on_cubes(&commands), 39);
+    }
+}
@@ -0,0 +1,111 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+use hashbrown::HashSet;
+
+use crate::map::{Map, Map2, Map4};
+
+mod map;
+mod space;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Node<M : Map>(M, u32);
+
+impl<M: Map + Eq> Ord for Node<M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.1.cmp(&self.1)
+    }
+}
+
+impl<M: Map + Eq> PartialOrd for Node<M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn get_min_score<M>(map: M) -> u32
+    where M: Map + Eq + std::hash::Hash + Copy
+{
+    let mut visited = HashSet::new();
+    let mut heap = BinaryHeap::new();
+    heap.push(Node(map, 0));
+
+    while let Some(current_item) = heap.pop() {
+        if current_item.0.is_finished() {
+            return current_item.1;
+        }
+
+        if !visited.insert(current_item.0) {
+            continue;
+        }
+
+        // Add new moves
+        for mut new_move in current_item.0.generate_moves() {
+            new_move.1 += current_item.1;
+            heap.push(new_move);
+        }
+    }
+
+    unreachable!();
+}
+
+/// Lines inserted into the diagram when it is unfolded in part 2
+const UNFOLDED: &str = "  #D#C#B#A#\n  #D#B#A#C#";
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 23;
+
+    /// Folded and unfolded diagram
+    type Input<'a> = (Map2, Map4);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut lines: Vec<_> = input.lines().collect();
+        let folded = Map2::from_str(&lines.join("\n")).unwrap();
+
+        lines.insert(3, UNFOLDED);
+        let unfolded = Map4::from_str(&lines.join("\n")).unwrap();
+
+        (folded, unfolded)
+    }
+
+    /// Min score
+    fn part1((map, _): &Self::Input<'_>) -> impl Display {
+        get_min_score(*map)
+    }
+
+    /// Min score of the unfolded diagram
+    fn part2((_, map): &Self::Input<'_>) -> impl Display {
+        get_min_score(*map)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Diagram without the lines which unfolding inserts
+    fn fold(unfolded: &str) -> String {
+        let lines: Vec<_> = unfolded.lines().enumerate()
+            .filter(|&(i, _)| !(3..5).contains(&i))
+            .map(|(_, l)| l)
+            .collect();
+
+        lines.join("\n")
+    }
+
+    #[test]
+    pub fn unfold() {
+        // The unfolded diagrams which used to be written by hand
+        for unfolded in [include_str!("../test_input_part2.txt"), include_str!("../input_part2.txt")] {
+            let (_, map) = Day23::parse(&fold(unfolded));
+            assert_eq!(map, Map4::from_str(unfolded).unwrap());
+        }
+    }
+}
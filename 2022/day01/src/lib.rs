@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    /// Calories per elf, sorted from most to least
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut calories: Vec<_> = input
+            .split("\n\n")
+            .map(|l| {
+                l.lines()
+                    .map(|s| s.parse::<i32>().unwrap())
+                    .sum::<i32>()
+            })
+            .collect();
+        calories.sort_unstable_by(|a, b| b.cmp(a));
+
+        calories
+    }
+
+    /// Calories
+    fn part1(calories: &Self::Input<'_>) -> impl Display {
+        calories[0]
+    }
+
+    /// Calories of the top three
+    fn part2(calories: &Self::Input<'_>) -> impl Display {
+        calories.iter().take(3).sum::<i32>()
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                l.chars().collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Priority sum
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.iter()
+            .map(|c| {
+                let first_compartment: HashSet<_> = c[..c.len() / 2].iter().cloned().collect();
+                let second_compartment: HashSet<_> = c[c.len() / 2..c.len()].iter().cloned().collect();
+
+                first_compartment.intersection(&second_compartment)
+                    .map(|c| {
+                        if c.is_uppercase() {
+                            *c as i32 - 'A' as i32 + 27
+                        } else {
+                            *c as i32 - 'a' as i32 + 1
+                        }
+                    })
+                    .sum::<i32>()
+            })
+            .sum::<i32>()
+    }
+
+    /// Badge sum
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input.chunks(3)
+            .map(|elves| {
+                let e0: HashSet<_> = elves[0].iter().cloned().collect();
+                let e1: HashSet<_> = elves[1].iter().cloned().collect();
+                let e2: HashSet<_> = elves[2].iter().cloned().collect();
+
+                let common_e0_e1: HashSet<_> = e0.intersection(&e1).cloned().collect::<HashSet<_>>();
+                common_e0_e1.intersection(&e2)
+                    .map(|c| {
+                        if c.is_uppercase() {
+                            *c as i32 - 'A' as i32 + 27
+                        } else {
+                            *c as i32 - 'a' as i32 + 1
+                        }
+                    })
+                    .sum::<i32>()
+            })
+            .sum::<i32>()
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<((i32, i32), (i32, i32))>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                let pairs = l.split_once(',').unwrap();
+                let p1 = pairs.0.split_once('-').unwrap();
+                let p2 = pairs.1.split_once('-').unwrap();
+
+                (
+                    (p1.0.parse::<i32>().unwrap(), p1.1.parse::<i32>().unwrap()),
+                    (p2.0.parse::<i32>().unwrap(), p2.1.parse::<i32>().unwrap())
+                )
+            })
+            .collect()
+    }
+
+    /// Fully overlapping pairs
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.iter()
+            .filter(|(p1, p2)| {
+                (p1.0 <= p2.0 && p2.1 <= p1.1)
+                || (p2.0 <= p1.0 && p1.1 <= p2.1)
+            })
+            .count()
+    }
+
+    /// Overlapping pairs
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input.iter()
+            .filter(|(p1, p2)| {
+                (p2.0 >= p1.0 && p2.0 <= p1.1) || (p1.0 >= p2.0 && p1.0 <= p2.1)
+            })
+            .count()
+    }
+}
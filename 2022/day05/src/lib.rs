@@ -0,0 +1,121 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Move {
+    amount: i32,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    pub fn new(amount: i32, from: usize, to: usize) -> Self {
+        Self { amount, from, to }
+    }
+}
+
+fn parse_stacks(drawing: &str) -> Vec<Vec<char>> {
+    let mut lines: Vec<_> = drawing.lines().collect();
+    let amount = lines.pop().unwrap().split_whitespace().count();
+
+    // Go from the bottom up, so the top crate ends up last
+    let mut stacks = vec![Vec::new(); amount];
+    for line in lines.iter().rev() {
+        for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+            if c != ' ' {
+                stacks[i].push(c);
+            }
+        }
+    }
+
+    stacks
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks.iter()
+        .map(|s| s[s.len() - 1])
+        .collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    /// Starting stacks and the moves
+    type Input<'a> = (Vec<Vec<char>>, Vec<Move>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+
+        let moves = moves
+            .lines()
+            .map(|l| {
+                let splited: Vec<_> = l.split(' ').collect();
+                Move::new(
+                    splited[1].parse().unwrap(),
+                    splited[3].parse::<usize>().unwrap() - 1,
+                    splited[5].parse::<usize>().unwrap() - 1,
+                )
+            })
+            .collect();
+
+        (parse_stacks(drawing), moves)
+    }
+
+    /// Top crates moved one at a time
+    fn part1((stacks, moves): &Self::Input<'_>) -> impl Display {
+        let mut stacks = stacks.clone();
+        for m in moves {
+            for _ in 0..m.amount {
+                if let Some(pop) = stacks[m.from].pop() {
+                    stacks[m.to].push(pop);
+                }
+            }
+        }
+
+        top_crates(&stacks)
+    }
+
+    /// Top crates moved all at once
+    fn part2((stacks, moves): &Self::Input<'_>) -> impl Display {
+        let mut stacks = stacks.clone();
+        for m in moves {
+            let mut crates = vec![char::default(); m.amount as usize];
+            for i in 0..m.amount {
+                if let Some(pop) = stacks[m.from].pop() {
+                    crates[(m.amount - i - 1) as usize] = pop;
+                }
+            }
+            stacks[m.to].extend(crates);
+        }
+
+        top_crates(&stacks)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Starting stacks which used to be hardcoded, for the moves committed next to them
+    const DRAWING: &str = "    [C]         [Q]         [V]
+    [D]         [D] [S]     [M] [Z]
+    [G]     [P] [W] [M]     [C] [G]
+    [F]     [Z] [C] [D] [P] [S] [W]
+[P] [L]     [C] [V] [W] [W] [H] [L]
+[G] [B] [V] [R] [L] [N] [G] [P] [F]
+[R] [T] [S] [S] [S] [T] [D] [L] [P]
+[N] [J] [M] [L] [P] [C] [H] [Z] [R]
+ 1   2   3   4   5   6   7   8   9";
+
+    #[test]
+    pub fn committed_moves() {
+        let input = Day05::parse(&format!("{DRAWING}\n\n{}", include_str!("../moves.txt")));
+
+        assert_eq!(Day05::part1(&input).to_string(), "VPCDMSLWJ");
+        assert_eq!(Day05::part2(&input).to_string(), "TPWCGNCCG");
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<char>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim().chars().collect()
+    }
+
+    /// Processed characters until the start of packet marker
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.windows(4)
+            .position(|w| {
+                w[0] != w[1] && w[0] != w[2] && w[0] != w[3] && w[1] != w[2] && w[1] != w[3] && w[2] != w[3]
+            })
+            .unwrap() + 4
+    }
+
+    /// Processed characters until the start of message marker
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        'outer: for (i, w) in input.windows(14).enumerate() {
+            let mut seen: u32 = 0;
+            for c in w {
+                let c = *c as u32;
+
+                if (seen >> c) & 1 == 1 {
+                    continue 'outer;
+                } else {
+                    seen |= 1 << c;
+                }
+            }
+
+            return i + 14;
+        }
+
+        unreachable!()
+    }
+}
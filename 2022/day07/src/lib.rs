@@ -0,0 +1,84 @@
+use std::collections::{HashMap, hash_map::Entry};
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    /// Total size of each folder
+    type Input<'a> = HashMap<String, usize>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .lines()
+            .collect();
+
+        let mut current_folder = Vec::new();
+        let mut folders = HashMap::new();
+        folders.insert(String::from(""), Vec::new());
+
+        for line in input {
+            if line == "$ cd /" {
+                current_folder.clear();
+            } else if line == "$ cd .." {
+                current_folder.pop();
+            } else if line.starts_with("$ cd") {
+                current_folder.push(line.split(' ').next_back().unwrap());
+                folders.insert(current_folder.join("/"), Vec::new());
+            } else if line == "$ ls" {
+                continue;
+            } else {
+                let (t, name) = line.split_once(' ').unwrap();
+                if let Ok(n) = t.parse::<usize>() {
+                    folders.get_mut(&current_folder.join("/")).unwrap().push((n, name));
+                }
+            }
+        }
+
+        let mut folder_sizes = HashMap::new();
+        for (k, v) in &folders {
+            let size = v.iter().map(|n| n.0).sum::<usize>();
+
+            let splitted: Vec<_> = k.split('/').collect();
+            for i in 0..=splitted.len() {
+                let key = splitted[0..i].join("/");
+                match folder_sizes.entry(key) {
+                    Entry::Occupied(mut e) => {
+                        *e.get_mut() += size;
+                    },
+                    Entry::Vacant(e) => {
+                        e.insert(size);
+                    },
+                };
+            }
+        }
+
+        folder_sizes
+    }
+
+    /// Sum of the small folders
+    fn part1(folder_sizes: &Self::Input<'_>) -> impl Display {
+        folder_sizes.values()
+            .filter(|v| **v <= 100_000)
+            .sum::<usize>()
+    }
+
+    /// Size of the folder to delete
+    fn part2(folder_sizes: &Self::Input<'_>) -> impl Display {
+        let free_space = 70_000_000 - folder_sizes[""];
+        let required_size = 30_000_000 - free_space;
+
+        let mut smallest = usize::MAX;
+        for v in folder_sizes.values() {
+            if *v > required_size && *v < smallest {
+                smallest = *v;
+            }
+        }
+
+        smallest
+    }
+}
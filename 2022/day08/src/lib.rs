@@ -0,0 +1,132 @@
+#![allow(clippy::needless_range_loop)]
+
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect::<Vec<_>>())
+            .collect()
+    }
+
+    /// Visible trees
+    fn part1(heights: &Self::Input<'_>) -> impl Display {
+        let width = heights[0].len();
+        let height = heights.len();
+
+        let mut visible_trees = 0;
+        for y in 0..height {
+            for x in 0..width {
+                let h = heights[y][x];
+
+                // Check left
+                let mut visible_left = true;
+                for i in 0..x {
+                    if heights[y][i] >= h {
+                        visible_left = false;
+                        break;
+                    }
+                }
+
+                // Check right
+                let mut visible_right = true;
+                for i in (x + 1)..width {
+                    if heights[y][i] >= h {
+                        visible_right = false;
+                        break;
+                    }
+                }
+
+                // Check top
+                let mut visible_top = true;
+                for i in 0..y {
+                    if heights[i][x] >= h {
+                        visible_top = false;
+                        break;
+                    }
+                }
+
+                // Check bottom
+                let mut visible_bottom = true;
+                for i in (y + 1)..height {
+                    if heights[i][x] >= h {
+                        visible_bottom = false;
+                        break;
+                    }
+                }
+
+                if visible_left || visible_right || visible_top || visible_bottom {
+                    visible_trees += 1;
+                }
+            }
+        }
+
+        visible_trees
+    }
+
+    /// Scenic score
+    fn part2(heights: &Self::Input<'_>) -> impl Display {
+        let width = heights[0].len();
+        let height = heights.len();
+
+        let mut max_scenic_score = 0;
+        for y in 0..height {
+            for x in 0..width {
+                let h = heights[y][x];
+
+                // Check left
+                let mut distance_left = 0;
+                for i in (0..x).rev() {
+                    distance_left += 1;
+                    if heights[y][i] >= h {
+                        break;
+                    }
+                }
+
+                // Check right
+                let mut distance_right = 0;
+                for i in (x + 1)..width {
+                    distance_right += 1;
+                    if heights[y][i] >= h {
+                        break;
+                    }
+                }
+
+                // Check top
+                let mut distance_top = 0;
+                for i in (0..y).rev() {
+                    distance_top += 1;
+                    if heights[i][x] >= h {
+                        break;
+                    }
+                }
+
+                // Check bottom
+                let mut distance_bottom = 0;
+                for i in (y + 1)..height {
+                    distance_bottom += 1;
+                    if heights[i][x] >= h {
+                        break;
+                    }
+                }
+
+                let scenic_score = distance_left * distance_right * distance_top * distance_bottom;
+                if scenic_score > max_scenic_score {
+                    max_scenic_score = scenic_score;
+                }
+            }
+        }
+
+        max_scenic_score
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc::Solution;
+use grid::{Direction, Heading};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Coord {
+    x: i32, y: i32,
+}
+
+impl Coord {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    fn step(&mut self, direction: Direction) {
+        let (dx, dy) = direction.offset();
+        self.x += dx as i32;
+        self.y += dy as i32;
+    }
+}
+
+pub struct Move {
+    direction: Direction,
+    steps: i32,
+}
+
+impl Move {
+    fn new(direction: Direction, steps: i32) -> Self {
+        Self { direction, steps }
+    }
+}
+
+#[inline]
+fn update_knot(head: Coord, knot: &mut Coord) {
+    if head.x - knot.x >= 2 {
+        knot.x += 1;
+        debug_assert_eq!(head.x, knot.x + 1);
+
+        knot.y += (head.y - knot.y).signum();
+    } else if knot.x - head.x >= 2 {
+        knot.x -= 1;
+        debug_assert_eq!(head.x, knot.x - 1);
+
+        knot.y += (head.y - knot.y).signum();
+    } else if head.y - knot.y >= 2 {
+        knot.y += 1;
+        debug_assert_eq!(head.y, knot.y + 1);
+
+        knot.x += (head.x - knot.x).signum();
+    } else if knot.y - head.y >= 2 {
+        knot.y -= 1;
+        debug_assert_eq!(head.y, knot.y - 1);
+
+        knot.x += (head.x - knot.x).signum();
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Move>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let moves: Vec<_> = input
+            .lines()
+            .map(|l| {
+                let (d, s) = l.split_once(' ').unwrap();
+                Move::new(d.parse().unwrap(), s.parse().unwrap())
+            })
+            .collect();
+
+        moves
+    }
+
+    /// Total visited
+    fn part1(moves: &Self::Input<'_>) -> impl Display {
+        let mut tail_visited = HashSet::new();
+        let mut tail_position = Coord::new(0, 0);
+        let mut head_position = Coord::new(0, 0);
+
+        tail_visited.insert(tail_position);
+        for m in moves {
+            for _ in 0..m.steps {
+                head_position.step(m.direction);
+                match m.direction {
+                    Direction::North => {
+                        if tail_position.y - head_position.y >= 2 {
+                            tail_position.y = head_position.y + 1;
+                            tail_position.x = head_position.x;
+                        }
+                    },
+                    Direction::South => {
+                        if head_position.y - tail_position.y >= 2 {
+                            tail_position.y = head_position.y - 1;
+                            tail_position.x = head_position.x;
+                        }
+                    },
+                    Direction::East => {
+                        if head_position.x - tail_position.x >= 2 {
+                            tail_position.x = head_position.x - 1;
+                            tail_position.y = head_position.y;
+                        }
+                    },
+                    Direction::West => {
+                        if tail_position.x - head_position.x >= 2 {
+                            tail_position.x = head_position.x + 1;
+                            tail_position.y = head_position.y;
+                        }
+                    },
+                }
+
+                tail_visited.insert(tail_position);
+            }
+        }
+
+        tail_visited.len()
+    }
+
+    /// Total visited with ten knots
+    fn part2(moves: &Self::Input<'_>) -> impl Display {
+        let mut visited = HashSet::new();
+        let mut knot_positions = [Coord::new(0, 0); 10];
+        visited.insert(knot_positions[9]);
+
+        for m in moves {
+            for _ in 0..m.steps {
+                knot_positions[0].step(m.direction);
+
+                for i in 1..knot_positions.len() {
+                    update_knot(knot_positions[i - 1], &mut knot_positions[i]);
+                }
+
+                visited.insert(knot_positions[9]);
+            }
+        }
+
+        visited.len()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn knot() {
+        let mut knot = Coord::new(0, 0);
+        update_knot(Coord::new(1, 2), &mut knot);
+        assert_eq!(knot, Coord::new(1, 1));
+
+        update_knot(Coord::new(2, 2), &mut knot);
+        assert_eq!(knot, Coord::new(1, 1));
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use aoc::{ocr, Solution};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+impl Instruction {
+    pub fn get_addx_value(&self) -> i32 {
+        match self {
+            Instruction::Noop => panic!(),
+            Instruction::AddX(v) => *v,
+        }
+    }
+
+    pub fn is_addx(&self) -> bool {
+        match self {
+            Instruction::Noop => false,
+            Instruction::AddX(_) => true,
+        }
+    }
+}
+
+fn draw(screen: &mut String, cycle: i32, sprite_loc: i32, amount: i32) {
+    if cycle % 40 == 0 && cycle != 0 {
+        screen.push('\n');
+    }
+
+    let mut cycle = cycle % 40;
+    for i in 0..amount {
+        if (sprite_loc-1..=sprite_loc+1).contains(&cycle) {
+            screen.push('\u{2588}');
+        } else {
+            screen.push(' ');
+        }
+
+        cycle += 1;
+        if cycle >= 40 && i < amount - 1  {
+            screen.push('\n');
+            cycle = 0;
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                if l == "noop" {
+                    Instruction::Noop
+                } else {
+                    let (_, v) = l.split_once(' ').unwrap();
+                    Instruction::AddX(v.parse().unwrap())
+                }
+            })
+            .collect()
+    }
+
+    /// Sum of signal strengths
+    fn part1(instructions: &Self::Input<'_>) -> impl Display {
+        let mut x = 1;
+        let mut cycle = 1;
+        let mut sum = 0;
+        for instruction in instructions {
+            match instruction {
+                Instruction::Noop => {
+                    cycle += 1;
+                },
+                Instruction::AddX(v) => {
+                    cycle += 2;
+                    x += v;
+                },
+            }
+
+            if (cycle - 20) % 40 == 0 {
+                sum += x * cycle;
+            } else if (cycle - 20) % 40 == 1 && instruction.is_addx() {
+                sum += (x - instruction.get_addx_value()) * (cycle - 1);
+            }
+        }
+
+        sum
+    }
+
+    /// Letters on the screen
+    fn part2(instructions: &Self::Input<'_>) -> impl Display {
+        let mut x = 1;
+        let mut cycle = 0;
+        let mut screen = String::new();
+        for instruction in instructions {
+            match instruction {
+                Instruction::Noop => {
+                    draw(&mut screen, cycle, x, 1);
+
+                    cycle += 1;
+                },
+                Instruction::AddX(v) => {
+                    draw(&mut screen, cycle, x, 2);
+
+                    cycle += 2;
+                    x += v;
+                },
+            }
+        }
+
+        ocr::text(&ocr::pixels(&screen))
+    }
+}
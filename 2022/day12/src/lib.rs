@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+/// Fewest steps from any of the start locations to the end location
+pub fn shortest_path_length(start_locations: impl IntoIterator<Item = (usize, usize)>, end_location: (usize, usize), elevation: &[Vec<u32>]) -> u32 {
+    let width = elevation[0].len();
+    let height = elevation.len();
+
+    let moves = |&(y, x): &(usize, usize)| {
+        let current_elevation = elevation[y][x];
+        [(y.wrapping_sub(1), x), (y + 1, x), (y, x.wrapping_sub(1)), (y, x + 1)].into_iter()
+            .filter(move |&(y, x)| y < height && x < width && elevation[y][x] <= current_elevation + 1)
+    };
+
+    search::bfs(start_locations, moves, |&p| p == end_location)
+        .map_or(u32::MAX, |reached| reached.cost as u32)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+
+    /// Start, end and elevation of each location
+    type Input<'a> = ((usize, usize), (usize, usize), Vec<Vec<u32>>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut start_location = (0, 0);
+        let mut end_location = (0, 0);
+        let elevation: Vec<_> = input
+            .lines()
+            .enumerate()
+            .map(|(y, l)| l.chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    if c == 'S' {
+                        start_location = (y, x);
+                        0
+                    } else if c == 'E' {
+                        end_location = (y, x);
+                        25
+                    } else {
+                        c as u32 - 'a' as u32
+                    }
+                })
+                .collect::<Vec<_>>()
+            )
+            .collect();
+
+        (start_location, end_location, elevation)
+    }
+
+    /// Steps to reach E
+    fn part1((start_location, end_location, elevation): &Self::Input<'_>) -> impl Display {
+        shortest_path_length([*start_location], *end_location, elevation)
+    }
+
+    /// Minimum number of steps from any lowest location
+    fn part2((_, end_location, elevation): &Self::Input<'_>) -> impl Display {
+        let lowest_locations = elevation.iter().enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, e)| **e == 0).map(move |(x, _)| (y, x)));
+
+        shortest_path_length(lowest_locations, *end_location, elevation)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn climbing() {
+        let elevation = vec![vec![0, 1, 2], vec![9, 9, 3]];
+
+        assert_eq!(shortest_path_length([(0, 0)], (1, 2), &elevation), 3);
+        assert_eq!(shortest_path_length([(0, 0)], (1, 0), &elevation), u32::MAX);
+    }
+}
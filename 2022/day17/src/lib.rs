@@ -0,0 +1,298 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left, Right
+}
+
+impl Direction {
+    fn from_char(c: char) -> Self {
+        match c {
+            '<' => Direction::Left,
+            '>' => Direction::Right,
+            _ => panic!("Unsupported char"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Space {
+    Rock, Air
+}
+
+const WIDTH: usize = 7;
+
+fn rock(index: usize) -> Vec<[Space; WIDTH]> {
+    match index % 5 {
+        0 => {
+            vec![[Space::Air, Space::Air, Space::Rock, Space::Rock, Space::Rock, Space::Rock, Space::Air]]
+        },
+        1 => {
+            vec![
+                [Space::Air, Space::Air, Space::Air,  Space::Rock, Space::Air,  Space::Air, Space::Air],
+                [Space::Air, Space::Air, Space::Rock, Space::Rock, Space::Rock, Space::Air, Space::Air],
+                [Space::Air, Space::Air, Space::Air,  Space::Rock, Space::Air,  Space::Air, Space::Air],
+            ]
+        },
+        2 => {
+            vec![
+                [Space::Air, Space::Air, Space::Rock, Space::Rock, Space::Rock, Space::Air, Space::Air],
+                [Space::Air, Space::Air, Space::Air,  Space::Air,  Space::Rock, Space::Air, Space::Air],
+                [Space::Air, Space::Air, Space::Air,  Space::Air,  Space::Rock, Space::Air, Space::Air],
+            ]
+        },
+        3 => {
+            vec![
+                [Space::Air, Space::Air, Space::Rock, Space::Air, Space::Air, Space::Air, Space::Air],
+                [Space::Air, Space::Air, Space::Rock, Space::Air, Space::Air, Space::Air, Space::Air],
+                [Space::Air, Space::Air, Space::Rock, Space::Air, Space::Air, Space::Air, Space::Air],
+                [Space::Air, Space::Air, Space::Rock, Space::Air, Space::Air, Space::Air, Space::Air],
+            ]
+        },
+        4 => {
+            vec![
+                [Space::Air, Space::Air, Space::Rock, Space::Rock, Space::Air, Space::Air, Space::Air],
+                [Space::Air, Space::Air, Space::Rock, Space::Rock, Space::Air, Space::Air, Space::Air],
+            ]
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Chamber<'a> {
+    jets: &'a [Direction],
+    /// Index of the next jet
+    jet: usize,
+    /// Number of rocks which came to rest
+    rocks: usize,
+    /// Rows above the last completely filled row, from the bottom
+    grid: VecDeque<[Space; WIDTH]>,
+    /// Height of the rows below the grid
+    removed: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Direction]) -> Self {
+        Self { jets, jet: 0, rocks: 0, grid: VecDeque::new(), removed: 0 }
+    }
+
+    fn height(&self) -> usize {
+        self.removed + self.grid.len()
+    }
+
+    /// Next rock, next jet and the air which rocks can still reach, which decide how the tower grows
+    fn key(&self) -> (usize, usize, Vec<u8>) {
+        (self.rocks % 5, self.jet, self.reachable_air())
+    }
+
+    /// Air which a falling rock can reach, as a mask of the columns of each row from the top down.
+    ///
+    /// Rocks only move sideways and down, so a row is reached from the air above it and spreads sideways.
+    fn reachable_air(&self) -> Vec<u8> {
+        let mut reachable = Vec::new();
+        let mut above = (1 << WIDTH) - 1;
+        for row in self.grid.iter().rev() {
+            let air = row.iter().enumerate()
+                .filter(|(_, s)| **s == Space::Air)
+                .fold(0u8, |mask, (i, _)| mask | 1 << i);
+
+            let mut mask = above & air;
+            loop {
+                let spread = (mask | mask << 1 | mask >> 1) & air;
+                if spread == mask {
+                    break;
+                }
+                mask = spread;
+            }
+
+            if mask == 0 {
+                break;
+            }
+            reachable.push(mask);
+            above = mask;
+        }
+
+        reachable
+    }
+
+    fn drop_rock(&mut self) {
+        let mut rock = rock(self.rocks);
+
+        let mut height = self.grid.len() + 3;
+        'moving_down: loop {
+            let jet = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            match jet {
+                Direction::Left  => {
+                    // Check if possible
+                    let mut possible = true;
+                    'left_search: for r in &rock {
+                        if r[0] == Space::Rock {
+                            possible = false;
+                            break;
+                        }
+
+                        if height < self.grid.len() {
+                            let max = (self.grid.len() - height).min(rock.len());
+                            for (i, r) in rock[0..max].iter().enumerate() {
+                                for s in 1..r.len() {
+                                    if r[s - 1] == Space::Air && r[s] == Space::Rock {
+                                        if self.grid[height + i][s - 1] == Space::Rock {
+                                            possible = false;
+                                            break 'left_search;
+                                        }
+                                        break;
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if possible {
+                        for row in rock.iter_mut() {
+                            row.rotate_left(1);
+                        }
+                    }
+                },
+                Direction::Right => {
+                    // Check if possible
+                    let mut possible = true;
+                    'right_search: for r in &rock {
+                        if r[6] == Space::Rock {
+                            possible = false;
+                            break 'right_search;
+                        }
+
+                        if height < self.grid.len() {
+                            let max = (self.grid.len() - height).min(rock.len());
+                            for (i, r) in rock[0..max].iter().enumerate() {
+                                for s in (0..(r.len() - 1)).rev() {
+                                    if r[s + 1] == Space::Air && r[s] == Space::Rock {
+                                        if self.grid[height + i][s + 1] == Space::Rock {
+                                            possible = false;
+                                            break 'right_search;
+                                        }
+                                        break;
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if possible {
+                        for row in rock.iter_mut() {
+                            row.rotate_right(1);
+                        }
+                    }
+                },
+            }
+
+            // Move down if possible
+            if height == 0 {
+                break 'moving_down;
+            }
+
+            // Check if we can move down
+            for (i, r) in rock.iter().enumerate() {
+                if height + i > self.grid.len() {
+                    break;
+                }
+
+                for (j, s) in self.grid[height + i - 1].iter().enumerate() {
+                    if r[j] == Space::Rock && *s == Space::Rock {
+                        break 'moving_down;
+                    }
+                }
+            }
+
+            height -= 1;
+        }
+
+        for (i, row) in rock.iter().enumerate() {
+            if height + i >= self.grid.len() {
+                self.grid.push_back([Space::Air; WIDTH]);
+            }
+
+            for (j, s) in self.grid[height + i].iter_mut().enumerate() {
+                if row[j] == Space::Rock {
+                    debug_assert_ne!(*s, Space::Rock);
+                    *s = Space::Rock;
+                }
+            }
+        }
+
+        // Check for each line if it is filled completely (tetris-like)
+        for i in (0..rock.len()).rev() {
+            if self.grid[height + i].iter().all(|s| *s == Space::Rock) {
+                self.removed += height + i + 1;
+                for _ in 0..(height + i + 1) {
+                    self.grid.pop_front();
+                }
+                break;
+            }
+        }
+
+        self.rocks += 1;
+    }
+}
+
+fn tower_height(jets: &[Direction], rocks: usize) -> usize {
+    cycle::extrapolate(Chamber::new(jets), Chamber::drop_rock, Chamber::key, Chamber::height, rocks)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim()
+            .chars()
+            .map(Direction::from_char)
+            .collect()
+    }
+
+    /// Height after 2022 rocks
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        tower_height(input, 2022)
+    }
+
+    /// Height after a trillion rocks
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        tower_height(input, 1_000_000_000_000)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn reachable_air() {
+        // The plus lands on the flat rock, the air under its arms is still reached from the sides
+        let jets = Day17::parse(include_str!("../example.txt"));
+        let mut chamber = Chamber::new(&jets);
+        chamber.drop_rock();
+        assert_eq!(chamber.reachable_air(), [0b1000011]);
+
+        chamber.drop_rock();
+        assert_eq!(chamber.reachable_air(), [0b1110111, 0b1100011, 0b1110111, 0b1000011]);
+    }
+
+    #[test]
+    pub fn example() {
+        let jets = Day17::parse(include_str!("../example.txt"));
+
+        assert_eq!(tower_height(&jets, 2022), 3068);
+        assert_eq!(tower_height(&jets, 1_000_000_000_000), 1514285714288);
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+/// Exposed sides of the cubes, and the air cubes next to them
+fn surface_area(cubes: &HashSet<(i32, i32, i32)>) -> (usize, HashSet<(i32, i32, i32)>) {
+    let mut air_cubes = HashSet::new();
+    let mut exposed_sides = 0;
+    for &(x, y, z) in cubes {
+        let sides = [
+            (x - 1, y, z), (x + 1, y, z),
+            (x, y - 1, z), (x, y + 1, z),
+            (x, y, z - 1), (x, y, z + 1),
+        ];
+
+        let iter = sides.into_iter()
+            .filter(|s| !cubes.contains(s));
+
+        exposed_sides += iter.clone().count();
+        air_cubes.extend(iter);
+    }
+
+    (exposed_sides, air_cubes)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 18;
+
+    type Input<'a> = HashSet<(i32, i32, i32)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                let split: Vec<_> = l.split(',').map(|p| p.parse::<i32>().unwrap()).collect();
+                (split[0], split[1], split[2])
+            })
+            .collect()
+    }
+
+    /// Surface area
+    fn part1(cubes: &Self::Input<'_>) -> impl Display {
+        surface_area(cubes).0
+    }
+
+    /// Exterior surface area
+    fn part2(cubes: &Self::Input<'_>) -> impl Display {
+        let (mut exposed_sides, air_cubes) = surface_area(cubes);
+
+        // Get boundaries of the cube
+        let x_min = cubes.iter().map(|(x, _y, _z)| *x).min().unwrap();
+        let x_max = cubes.iter().map(|(x, _y, _z)| *x).max().unwrap();
+        let y_min = cubes.iter().map(|(_x, y, _z)| *y).min().unwrap();
+        let y_max = cubes.iter().map(|(_x, y, _z)| *y).max().unwrap();
+        let z_min = cubes.iter().map(|(_x, _y, z)| *z).min().unwrap();
+        let z_max = cubes.iter().map(|(_x, _y, z)| *z).max().unwrap();
+
+        'air_cubes: for &(x, y, z) in &air_cubes {
+            let mut queue = VecDeque::new();
+            queue.push_back((x, y, z));
+
+            let mut visited = HashSet::new();
+            while let Some((x, y, z)) = queue.pop_front() {
+                if !(x_min..=x_max).contains(&x) || !(y_min..=y_max).contains(&y) || !(z_min..=z_max).contains(&z) {
+                    // Exposed to the steam
+                    continue 'air_cubes;
+                }
+
+                if !visited.insert((x, y, z)) {
+                    continue;
+                }
+
+                let sides = [
+                    (x - 1, y, z), (x + 1, y, z),
+                    (x, y - 1, z), (x, y + 1, z),
+                    (x, y, z - 1), (x, y, z + 1),
+                ];
+
+                for side in sides.into_iter().filter(|s| !cubes.contains(s)) {
+                    queue.push_back(side);
+                }
+            }
+
+            // Count exposed sides of this cube
+            let sides = [
+                (x - 1, y, z), (x + 1, y, z),
+                (x, y - 1, z), (x, y + 1, z),
+                (x, y, z - 1), (x, y, z + 1),
+            ];
+            exposed_sides -= sides.into_iter()
+                .filter(|s| cubes.contains(s))
+                .count();
+        }
+
+        exposed_sides
+    }
+}
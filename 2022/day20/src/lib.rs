@@ -0,0 +1,66 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use aoc::Solution;
+
+fn mix(numbers: &[i64], iterations: u32) -> i64 {
+    let mut positions: VecDeque<_> = (0..numbers.len()).collect();
+
+    for _ in 0..iterations {
+        for (i, &n) in numbers.iter().enumerate() {
+            if n == 0 {
+                continue;
+            }
+
+            while positions[0] != i {
+                positions.rotate_left(1);
+            }
+
+            let wrap = numbers.len() - 1;
+            let tmp = positions.pop_front().unwrap();
+            debug_assert_eq!(tmp, i);
+            if n < 0 {
+                positions.rotate_right(-n as usize % wrap);
+            } else {
+                positions.rotate_left(n as usize % wrap);
+            }
+            positions.push_front(tmp);
+        }
+    }
+
+    let zero_pos = numbers.iter().position(|i| *i == 0).unwrap();
+    let zero_pos = positions.iter().position(|i| *i == zero_pos).unwrap();
+    let n1000 = (zero_pos + 1000) % positions.len();
+    let n2000 = (zero_pos + 2000) % positions.len();
+    let n3000 = (zero_pos + 3000) % positions.len();
+
+    numbers[positions[n1000]] + numbers[positions[n2000]] + numbers[positions[n3000]]
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 20;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| l.parse::<i64>().unwrap())
+            .collect()
+    }
+
+    /// Sum of grove coordinate
+    fn part1(numbers: &Self::Input<'_>) -> impl Display {
+        mix(numbers, 1)
+    }
+
+    /// Sum of grove coordinate with the decryption key
+    fn part2(numbers: &Self::Input<'_>) -> impl Display {
+        let numbers: Vec<_> = numbers.iter().map(|n| n * 811589153).collect();
+        mix(&numbers, 10)
+    }
+}
+
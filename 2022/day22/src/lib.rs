@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc::Solution;
+use grid::{Direction, Heading};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Open, Wall, Wrap,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Left, Right, Forward(u32),
+}
+
+/// The map is surrounded by wrapping tiles, so the next coordinate never goes below zero.
+fn next_coord(facing: Direction, (x, y): (usize, usize)) -> (usize, usize) {
+    let (dx, dy) = facing.offset();
+    (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy))
+}
+
+fn facing_value(facing: Direction) -> usize {
+    match facing {
+        Direction::North => 3,
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 22;
+
+    /// Map, path and start location
+    type Input<'a> = (Vec<Vec<Tile>>, Vec<Instruction>, (usize, usize));
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut grid = Vec::new();
+
+        let (map, directions) = input.split_once("\n\n").unwrap();
+        let directions = directions.trim();
+        let map: Vec<_> = map.lines().collect();
+        let direction_chars: Vec<_> = directions.chars().collect();
+
+        let width = map.iter().map(|r| r.len()).max().unwrap();
+
+        grid.push(vec![Tile::Wrap; width + 2]);
+        for r in map {
+            grid.push(vec![Tile::Wrap; width + 2]);
+            let y = grid.len() - 1;
+            for (x, t) in r.chars().enumerate() {
+                match t {
+                    '.' => grid[y][x + 1] = Tile::Open,
+                    '#' => grid[y][x + 1] = Tile::Wall,
+                    ' ' => (),
+                    _ => unimplemented!(),
+                }
+            }
+        }
+        grid.push(vec![Tile::Wrap; width + 2]);
+
+        let mut i = 0;
+        let mut parsed_directions = Vec::new();
+        while i < directions.len() {
+            let mut j = i;
+            while j < directions.len() && direction_chars[j].is_ascii_digit() {
+                j += 1;
+            }
+
+            if j != i {
+                parsed_directions.push(Instruction::Forward(directions[i..j].parse().unwrap()));
+                i = j;
+            } else if direction_chars[i] == 'R' {
+                parsed_directions.push(Instruction::Right);
+                i = j + 1;
+            } else if direction_chars[i] == 'L' {
+                parsed_directions.push(Instruction::Left);
+                i = j + 1;
+            }
+        }
+        let directions = parsed_directions;
+
+        let mut start_location = (0, 1);
+        #[allow(clippy::needless_range_loop)]
+        for x in 0..(width+2) {
+            if grid[start_location.1][x] == Tile::Open {
+                start_location.0 = x;
+                break;
+            }
+        }
+        let start_location = start_location;
+
+        (grid, directions, start_location)
+    }
+
+    /// Password
+    fn part1((grid, directions, start_location): &Self::Input<'_>) -> impl Display {
+        let width = grid[0].len() - 2;
+
+        let mut current_location = *start_location;
+        let mut current_facing = Direction::East;
+        for d in directions {
+            match d {
+                Instruction::Left => current_facing = current_facing.turn_left(),
+                Instruction::Right => current_facing = current_facing.turn_right(),
+                Instruction::Forward(n) => {
+                    'forward: for _ in 0..*n {
+                        let next = next_coord(current_facing, current_location);
+                        // Check wrapping
+                        match grid[next.1][next.0] {
+                            Tile::Open => current_location = next,
+                            Tile::Wall => break 'forward,
+                            Tile::Wrap => {
+                                match current_facing {
+                                    Direction::North => {
+                                        for y in (0..grid.len()).rev() {
+                                            match grid[y][next.0] {
+                                                Tile::Open => {
+                                                    current_location = (next.0, y);
+                                                    continue 'forward;
+                                                },
+                                                Tile::Wall => {
+                                                    break 'forward;
+                                                },
+                                                Tile::Wrap => continue,
+                                            }
+                                        }
+                                    },
+                                    Direction::East => {
+                                        #[allow(clippy::needless_range_loop)]
+                                    for x in 0..(width + 2) {
+                                            match grid[next.1][x] {
+                                                Tile::Open => {
+                                                    current_location = (x, next.1);
+                                                    continue 'forward;
+                                                },
+                                                Tile::Wall => {
+                                                    break 'forward;
+                                                },
+                                                Tile::Wrap => continue,
+                                            }
+                                        }
+                                    },
+                                    Direction::South => {
+                                        #[allow(clippy::needless_range_loop)]
+                                        for y in 0..grid.len() {
+                                            match grid[y][next.0] {
+                                                Tile::Open => {
+                                                    current_location = (next.0, y);
+                                                    continue 'forward;
+                                                },
+                                                Tile::Wall => {
+                                                    break 'forward;
+                                                },
+                                                Tile::Wrap => continue,
+                                            }
+                                        }
+                                    },
+                                    Direction::West => {
+                                        for x in (0..(width + 2)).rev() {
+                                            match grid[next.1][x] {
+                                                Tile::Open => {
+                                                    current_location = (x, next.1);
+                                                    continue 'forward;
+                                                },
+                                                Tile::Wall => {
+                                                    break 'forward;
+                                                },
+                                                Tile::Wrap => continue,
+                                            }
+                                        }
+                                    },
+                                }
+                            },
+                        }
+                    }
+                },
+            }
+        }
+
+        current_location.1 * 1000 + current_location.0 * 4 + facing_value(current_facing)
+    }
+
+    /// Password on the cube
+    fn part2((grid, directions, start_location): &Self::Input<'_>) -> impl Display {
+        // Create wrapping rules...
+        let mut wrapping_rules = HashMap::new();
+        for y in 1..51 {
+            // 1 -> 4
+            wrapping_rules.insert((50, y, Direction::West), (1, 151 - y, Direction::East));
+            // 4 -> 1
+            wrapping_rules.insert((0, 151 - y, Direction::West), (51, y, Direction::East));
+
+            // 2 -> 5
+            wrapping_rules.insert((151, y, Direction::East), (100, 151 - y, Direction::West));
+            // 5 -> 2
+            wrapping_rules.insert((101, 151 - y, Direction::East), (150, y, Direction::West));
+        }
+        for x in 51..101 {
+            // 1 -> 6
+            wrapping_rules.insert((x, 0, Direction::North), (1, 100 + x, Direction::East));
+            // 6 -> 1
+            wrapping_rules.insert((0, 100 + x, Direction::West), (x, 1, Direction::South));
+
+            // 5 -> 6
+            wrapping_rules.insert((x, 151, Direction::South), (50, 100 + x, Direction::West));
+            // 6 -> 5
+            wrapping_rules.insert((51, 100 + x, Direction::East), (x, 150, Direction::North));
+        }
+        for x in 101..151 {
+            // 2 -> 3
+            wrapping_rules.insert((x, 51, Direction::South), (100, x - 50, Direction::West));
+            // 3 -> 2
+            wrapping_rules.insert((101, x - 50, Direction::East), (x, 50, Direction::North));
+
+            // 2 -> 6
+            wrapping_rules.insert((x, 0, Direction::North), (x - 100, 200, Direction::North));
+            // 6 -> 2
+            wrapping_rules.insert((x - 100, 201, Direction::South), (x, 1, Direction::South));
+        }
+        for y in 51..101 {
+            // 3 -> 4
+            wrapping_rules.insert((50, y, Direction::West), (y - 50, 101, Direction::South));
+            // 4 -> 3
+            wrapping_rules.insert((y - 50, 100, Direction::North), (51, y, Direction::East));
+        }
+
+        debug_assert!(wrapping_rules.iter().all(|(k, _v)| grid[k.1][k.0] == Tile::Wrap));
+        debug_assert!(wrapping_rules.iter().all(|(_k, v)| grid[v.1][v.0] != Tile::Wrap));
+
+
+        let mut current_location = *start_location;
+        let mut current_facing = Direction::East;
+        for d in directions {
+            match d {
+                Instruction::Left => current_facing = current_facing.turn_left(),
+                Instruction::Right => current_facing = current_facing.turn_right(),
+                Instruction::Forward(n) => {
+                    'forward: for _ in 0..*n {
+                        let next = next_coord(current_facing, current_location);
+                        // Check wrapping
+                        match grid[next.1][next.0] {
+                            Tile::Open => current_location = next,
+                            Tile::Wall => break 'forward,
+                            Tile::Wrap => {
+                                let new_location = wrapping_rules[&(next.0, next.1, current_facing)];
+                                match grid[new_location.1][new_location.0] {
+                                    Tile::Open => {
+                                        current_location = (new_location.0, new_location.1);
+                                        current_facing = new_location.2;
+                                        continue 'forward;
+                                    },
+                                    Tile::Wall => {
+                                        break 'forward;
+                                    },
+                                    Tile::Wrap => unreachable!(),
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+
+        current_location.1 * 1000 + current_location.0 * 4 + facing_value(current_facing)
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::{HashMap, hash_map::Entry, HashSet};
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+/// Performs a single round, returns whether any elf moved.
+fn simulate_round(elves: &mut HashSet<(i32, i32)>, round: usize) -> bool {
+    let mut proposals: HashMap<(i32, i32), Vec<(i32, i32)>> = HashMap::new();
+
+    // Create proposals
+    for &(y, x) in elves.iter() {
+        let positions = [
+            (y - 1, x - 1), (y - 1, x) , (y - 1, x + 1),
+            (y, x - 1), (y, x + 1),
+            (y + 1, x - 1), (y + 1, x) , (y + 1, x + 1),
+        ];
+        if positions.iter().all(|p| !elves.contains(p)) {
+            continue;
+        }
+
+        // Propose
+        let mut elf_proposal = [None; 4];
+        // Check north
+        if !elves.contains(&(y - 1, x - 1)) && !elves.contains(&(y - 1, x)) && !elves.contains(&(y - 1, x + 1)) {
+            elf_proposal[0] = Some((y - 1, x));
+        }
+        // Check south
+        if !elves.contains(&(y+ 1, x - 1)) && !elves.contains(&(y+ 1, x)) && !elves.contains(&(y+ 1, x + 1)) {
+            elf_proposal[1] = Some((y + 1, x));
+        }
+        // Check west
+        if !elves.contains(&(y - 1, x - 1)) && !elves.contains(&(y, x - 1)) && !elves.contains(&(y + 1, x - 1)) {
+            elf_proposal[2] = Some((y, x - 1));
+        }
+        // Check east
+        if !elves.contains(&(y - 1, x + 1)) && !elves.contains(&(y, x + 1)) && !elves.contains(&(y + 1, x + 1)) {
+            elf_proposal[3] = Some((y, x + 1));
+        }
+
+        elf_proposal.rotate_left(round % 4);
+        if let Some(p) = elf_proposal.iter().find(|p| p.is_some()) {
+            let p = p.unwrap();
+            match proposals.entry(p) {
+                Entry::Occupied(mut e) => {
+                    e.get_mut().push((y, x));
+                },
+                Entry::Vacant(e) => {
+                    e.insert(vec![(y, x)]);
+                },
+            }
+        }
+    }
+
+    if proposals.is_empty() {
+        return false;
+    }
+
+    for (to, from) in proposals {
+        if from.len() != 1 {
+            continue;
+        }
+
+        // Move
+        elves.remove(&from[0]);
+        elves.insert(to);
+    }
+
+    true
+}
+
+/// Empty ground tiles in the smallest rectangle containing all elves
+fn empty_squares(elves: &HashSet<(i32, i32)>) -> i32 {
+    let min_x = elves.iter().map(|(_y, x)| x).min().unwrap();
+    let max_x = elves.iter().map(|(_y, x)| x).max().unwrap();
+    let min_y = elves.iter().map(|(y, _x)| y).min().unwrap();
+    let max_y = elves.iter().map(|(y, _x)| y).max().unwrap();
+
+    (max_x - min_x + 1) * (max_y - min_y + 1) - elves.len() as i32
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 23;
+
+    type Input<'a> = HashSet<(i32, i32)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, l)| {
+                l.chars().enumerate()
+                    .filter_map(|(x, c)| {
+                        if c == '#' {
+                            Some((y as i32, x as i32))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Empty squares after 10 rounds
+    fn part1(elves: &Self::Input<'_>) -> impl Display {
+        let mut elves = elves.clone();
+        for i in 0..10 {
+            simulate_round(&mut elves, i);
+        }
+
+        empty_squares(&elves)
+    }
+
+    /// First round in which no elf moves
+    fn part2(elves: &Self::Input<'_>) -> impl Display {
+        let mut elves = elves.clone();
+        let mut i = 0;
+        while simulate_round(&mut elves, i) {
+            i += 1;
+        }
+
+        i + 1
+    }
+}
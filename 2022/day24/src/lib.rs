@@ -0,0 +1,159 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+#[allow(clippy::ptr_arg)]
+fn has_blizzard(
+    (y, x): (i32, i32), t: u32,
+    (width, height): (i32, i32),
+    ups: &Vec<Vec<bool>>, downs: &Vec<Vec<bool>>,
+    lefts: &Vec<Vec<bool>>, rights: &Vec<Vec<bool>>
+) -> bool {
+    if y < 0 || y >= height {
+        return false;
+    }
+
+    lefts[y as usize][(x as usize + t as usize) % width as usize]
+        || rights[y as usize][(x - t as i32).rem_euclid(width) as usize]
+        || downs[x as usize][(y - t as i32).rem_euclid(height) as usize]
+        || ups[x as usize][(y as usize + t as usize) % height as usize]
+}
+
+/// Time at which the goal is reached, waiting or moving one square every minute
+#[allow(clippy::too_many_arguments, clippy::ptr_arg)]
+fn shortest_time(
+    start: (i32, i32), goal: (i32, i32), start_time: u32,
+    (width, height): (i32, i32),
+    ups: &Vec<Vec<bool>>, downs: &Vec<Vec<bool>>,
+    lefts: &Vec<Vec<bool>>, rights: &Vec<Vec<bool>>
+) -> u32 {
+    let moves = |&((y, x), t): &((i32, i32), u32)| {
+        [(1, 0), (0, 1), (0, -1), (-1, 0), (0, 0)].into_iter()
+            .map(move |(dy, dx)| (y + dy, x + dx))
+            // Don't move outside the grid except for the start and goal squares
+            .filter(move |&(new_y, new_x)| {
+                !(new_x < 0 || new_x >= width || (new_y < 0 && !(new_y == -1 && new_x == 0)) || (new_y >= height && !(new_y == height && new_x == width - 1)))
+            })
+            // Can't move into a blizzard
+            .filter(move |&position| !has_blizzard(position, t + 1, (width, height), ups, downs, lefts, rights))
+            .map(move |position| (position, t + 1))
+    };
+
+    search::bfs([(start, start_time)], moves, |&(position, _)| position == goal)
+        .unwrap()
+        .goal.1
+}
+
+/// Blizzards moving in each direction, per column for the vertical ones and per row for the horizontal ones
+pub struct Valley {
+    size: (i32, i32),
+    ups: Vec<Vec<bool>>,
+    downs: Vec<Vec<bool>>,
+    lefts: Vec<Vec<bool>>,
+    rights: Vec<Vec<bool>>,
+}
+
+impl Valley {
+    fn start(&self) -> (i32, i32) {
+        (-1, 0)
+    }
+
+    fn goal(&self) -> (i32, i32) {
+        (self.size.1, self.size.0 - 1)
+    }
+
+    /// Time at which the goal is reached, when starting at the given time
+    fn cross(&self, start: (i32, i32), goal: (i32, i32), start_time: u32) -> u32 {
+        shortest_time(start, goal, start_time, self.size, &self.ups, &self.downs, &self.lefts, &self.rights)
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 24;
+
+    type Input<'a> = Valley;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .lines()
+            .map(|l| l.chars().collect::<Vec<_>>())
+            .collect();
+
+        let width = input[0].len() as i32 - 2;
+        let height = input.len() as i32 - 2;
+
+        let mut lefts = Vec::new();
+        let mut rights = Vec::new();
+        #[allow(clippy::needless_range_loop)]
+        for y in 1..(height as usize + 1) {
+            let mut left_row = Vec::new();
+            let mut right_row = Vec::new();
+
+            #[allow(clippy::needless_range_loop)]
+            for x in 1..(width as usize + 1) {
+                match input[y][x] {
+                    '<' => {
+                        left_row.push(true);
+                        right_row.push(false);
+                    },
+                    '>' => {
+                        left_row.push(false);
+                        right_row.push(true);
+                    },
+                    _ => {
+                        left_row.push(false);
+                        right_row.push(false);
+                    }
+                }
+            }
+
+            lefts.push(left_row);
+            rights.push(right_row);
+        }
+        let mut ups = Vec::new();
+        let mut downs = Vec::new();
+        #[allow(clippy::needless_range_loop)]
+        for x in 1..(width as usize + 1) {
+            let mut up_col = Vec::new();
+            let mut down_col = Vec::new();
+
+            #[allow(clippy::needless_range_loop)]
+            for y in 1..(height as usize + 1) {
+                match input[y][x] {
+                    '^' => {
+                        up_col.push(true);
+                        down_col.push(false);
+                    },
+                    'v' => {
+                        up_col.push(false);
+                        down_col.push(true);
+                    },
+                    _ => {
+                        up_col.push(false);
+                        down_col.push(false);
+                    }
+                }
+            }
+
+            ups.push(up_col);
+            downs.push(down_col);
+        }
+
+        Valley { size: (width, height), ups, downs, lefts, rights }
+    }
+
+    /// Minutes to reach the goal
+    fn part1(valley: &Self::Input<'_>) -> impl Display {
+        valley.cross(valley.start(), valley.goal(), 0)
+    }
+
+    /// Minutes to reach the goal, go back for the snacks and reach it again
+    fn part2(valley: &Self::Input<'_>) -> impl Display {
+        let there = valley.cross(valley.start(), valley.goal(), 0);
+        let back = valley.cross(valley.goal(), valley.start(), there);
+        valley.cross(valley.start(), valley.goal(), back)
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .collect()
+    }
+
+    /// Sum of calibration
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.iter()
+            .map(|s| {
+                let chars: Vec<_> = s.chars()
+                    .filter_map(|c| c.to_digit(10))
+                    .collect();
+
+                10 * chars[0] + chars[chars.len() - 1]
+            })
+            .sum::<u32>()
+    }
+
+    /// Sum of calibration with spelled out digits
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let matches = [
+            ("one", '1'), ("two", '2'), ("three", '3'), ("four", '4'),
+            ("five", '5'), ("six", '6'), ("seven", '7'), ("eight", '8'), ("nine", '9')
+        ];
+        input.iter()
+            .map(|s| {
+                let mut occurrences: Vec<_> = matches
+                    .iter()
+                    .flat_map(|m| {
+                        s.match_indices(m.0).map(|x| (x.0, m.1)).collect::<Vec<_>>()
+                    })
+                    .collect();
+                occurrences.sort_unstable_by_key(|a| a.0);
+
+                let mut chars: Vec<_> = s.chars().collect();
+                if !occurrences.is_empty() {
+                    let first_match = occurrences[0];
+                    let last_match = occurrences[occurrences.len() - 1];
+
+                    chars[first_match.0] = first_match.1;
+                    chars[last_match.0] = last_match.1;
+                }
+
+                let chars: Vec<_> = chars.into_iter()
+                    .filter_map(|c| c.to_digit(10))
+                    .collect();
+
+                10 * chars[0] + chars[chars.len() - 1]
+            })
+            .sum::<u32>()
+    }
+}
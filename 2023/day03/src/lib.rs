@@ -0,0 +1,115 @@
+use std::collections::{HashMap, hash_map::Entry};
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+/// Number in the schematic, at the position of its first digit
+#[derive(Debug, Clone, Copy)]
+pub struct Number {
+    value: u32,
+    x: usize,
+    y: usize,
+    length: usize,
+}
+
+/// Positions of the symbols beside the number, the first one of each row
+fn symbols(map: &[Vec<char>], number: &Number) -> Vec<(usize, usize)> {
+    let Number { x, y, length, .. } = *number;
+
+    let mut symbols = Vec::new();
+    for y in [y.overflowing_sub(1).0, y, y + 1] {
+        for x in (x as isize - 1)..=(x as isize + length as isize) {
+            if y < map.len() && x > 0 && (x as usize) < map[y].len()
+                && map[y][x as usize] != '.' && !map[y][x as usize].is_ascii_digit()
+            {
+                symbols.push((y, x as usize));
+                break;
+            }
+        }
+    }
+
+    symbols
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    /// Map of the schematic, and the numbers on it
+    type Input<'a> = (Vec<Vec<char>>, Vec<Number>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let map: Vec<_> = input
+            .lines()
+            .map(|l| l.chars().collect::<Vec<_>>())
+            .collect();
+
+        let mut numbers = Vec::new();
+        for (y, row) in map.iter().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                if !row[x].is_ascii_digit() {
+                    x += 1;
+                    continue;
+                }
+
+                let mut value = 0;
+                let mut length = 0;
+                for c in &row[x..] {
+                    if let Some(digit) = c.to_digit(10) {
+                        value = 10 * value + digit;
+                        length += 1;
+                    } else {
+                        break;
+                    }
+                }
+
+                numbers.push(Number { value, x, y, length });
+                x += length;
+            }
+        }
+
+        (map, numbers)
+    }
+
+    /// Sum of part numbers
+    fn part1((map, numbers): &Self::Input<'_>) -> impl Display {
+        numbers.iter()
+            .filter(|number| !symbols(map, number).is_empty())
+            .map(|number| number.value)
+            .sum::<u32>()
+    }
+
+    /// Sum of gear ratios
+    fn part2((map, numbers): &Self::Input<'_>) -> impl Display {
+        // Numbers next to each gear
+        let mut gear_map: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+        for number in numbers {
+            for (y, x) in symbols(map, number) {
+                if map[y][x] == '*' {
+                    match gear_map.entry((y, x)) {
+                        Entry::Occupied(mut e) => {
+                            e.get_mut().push(number.value);
+                        },
+                        Entry::Vacant(e) => {
+                            e.insert(vec![number.value]);
+                        },
+                    }
+                }
+            }
+        }
+
+        gear_map.values()
+            .filter_map(|v| {
+                if v.len() != 2 {
+                    None
+                } else {
+                    Some(v[0] * v[1])
+                }
+            })
+            .sum::<u32>()
+    }
+}
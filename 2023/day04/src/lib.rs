@@ -0,0 +1,96 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+
+#[derive(Debug)]
+pub struct ScratchCard {
+    _id: u32,
+    winning_numbers: HashSet<u32>,
+    my_numbers: Vec<u32>,
+}
+
+impl ScratchCard {
+    fn points(&self) -> u32 {
+        let mut points = 0;
+        for n in &self.my_numbers {
+            if self.winning_numbers.contains(n) {
+                if points == 0 {
+                    points = 1;
+                } else {
+                    points *= 2;
+                }
+            }
+        }
+        points
+    }
+
+    fn matching_numbers(&self) -> usize {
+        self.my_numbers.iter()
+            .filter(|n| self.winning_numbers.contains(*n))
+            .count()
+    }
+}
+
+impl FromStr for ScratchCard {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [card_id, numbers] = s.split(':').collect::<Vec<_>>().try_into()
+            .map_err(|_| "Invalid card format")?;
+        let card_id: u32 = card_id[5..].trim().parse()
+            .map_err(|_| "Unable to parse card id")?;
+
+        let [winning, selected] = numbers.split(" | ").collect::<Vec<_>>().try_into()
+            .map_err(|_| "Unable to parse numbers")?;
+        let winning: HashSet<u32> = winning.split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect();
+        let selected: Vec<u32> = selected.split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect();
+
+        Ok(ScratchCard { _id: card_id, winning_numbers: winning, my_numbers: selected })
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<ScratchCard>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| ScratchCard::from_str(l).unwrap())
+            .collect()
+    }
+
+    /// Total point worth
+    fn part1(cards: &Self::Input<'_>) -> impl Display {
+        cards.iter()
+            .map(|c| c.points())
+            .sum::<u32>()
+    }
+
+    /// Total cards
+    fn part2(cards: &Self::Input<'_>) -> impl Display {
+        let mut card_count = vec![1; cards.len()];
+        for i in 0..cards.len() {
+            let card = &cards[i];
+            let total_cards = card_count[i];
+
+            let matches = card.matching_numbers();
+            for count in card_count.iter_mut().skip(i+1).take(matches) {
+                *count += total_cards;
+            }
+        }
+
+        card_count.iter().sum::<u32>()
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use interval::{IntervalSet, RangeMap};
+
+
+/// Parses the lines of a map, which are the destination start, source start and length
+fn parse_mapping(s: &str) -> RangeMap<u64> {
+    s.lines().skip(1)
+        .map(|l| {
+            let [to, from, length] = l.split_whitespace()
+                .map(|i| i.parse().unwrap())
+                .collect::<Vec<_>>().try_into()
+                .expect("Could not convert mapping");
+
+            (from..from + length, to)
+        })
+        .collect()
+}
+
+/// Seed ranges, which are pairs of a start and a length
+fn seed_ranges(seeds: &[u64]) -> IntervalSet<u64> {
+    seeds.chunks(2)
+        .map(|c| c[0]..c[0] + c[1])
+        .collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    /// Seeds and the mappings of each category
+    type Input<'a> = (Vec<u64>, Vec<RangeMap<u64>>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .split("\n\n")
+            .collect();
+
+        let seeds: Vec<u64> = input[0][7..].split_whitespace()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let mappings: Vec<_> = input.into_iter().skip(1)
+            .map(parse_mapping)
+            .collect();
+
+        (seeds, mappings)
+    }
+
+    /// Lowest location number
+    fn part1((seeds, mappings): &Self::Input<'_>) -> impl Display {
+        seeds.iter()
+            .map(|&seed| mappings.iter().fold(seed, |value, m| m.get(value)))
+            .min()
+            .unwrap()
+    }
+
+    /// Lowest location number of the seed ranges
+    fn part2((seeds, mappings): &Self::Input<'_>) -> impl Display {
+        let locations = mappings.iter()
+            .fold(seed_ranges(seeds), |ranges, m| m.map(&ranges));
+
+        locations.ranges()[0].start
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    pub fn propagate() {
+        let (seeds, mappings) = Day05::parse(EXAMPLE);
+        let seeds = seed_ranges(&seeds);
+        assert_eq!(seeds.ranges(), [55..68, 79..93]);
+
+        // Both seed ranges fall inside the second line of the seed-to-soil map
+        let soil = mappings[0].map(&seeds);
+        assert_eq!(soil.ranges(), [57..70, 81..95]);
+
+        // Splits where the offset of the soil-to-fertilizer map changes, and keeps the values past it
+        let fertilizer = mappings[1].map(&IntervalSet::from(50..55));
+        assert_eq!(fertilizer.ranges(), [35..39, 54..55]);
+    }
+
+    #[test]
+    pub fn example() {
+        let input = Day05::parse(EXAMPLE);
+        assert_eq!(Day05::part1(&input).to_string(), "35");
+        assert_eq!(Day05::part2(&input).to_string(), "46");
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn new(time: u64, distance: u64) -> Self {
+        Self { time, distance }
+    }
+
+    fn number_of_ways_to_beat(&self) -> u32 {
+        // let t is time holding the button,
+        // then distance = t*(T - t) = -t^2 + T*t
+        // Thus we need to solve -t^2 + T*t - D > 0
+        // Where T is time and D is the record distance
+
+        let a = -1.0;
+        let b = self.time as f64;
+        let c = -(self.distance as f64);
+        let d = b * b - 4.0 * a * c;
+
+        let t1 = (-b + d.sqrt()) / (2.0 * a);
+        let t2 = (-b - d.sqrt()) / (2.0 * a);
+
+        // Use a small epsilon to move away from the edges where
+        // the equality holds.
+        // We need to be strictly greater than the record distance.
+        let epsilon = 0.001;
+        ((t2 - epsilon).floor() - (t1 + epsilon).ceil() + 1.0) as u32
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    /// Races, and the single race when ignoring the spaces
+    type Input<'a> = (Vec<Race>, Race);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let lines: Vec<_> = input
+            .lines()
+            .map(|l| l.split_once(':').unwrap().1)
+            .collect();
+
+        let numbers = |l: &str| -> Vec<u64> {
+            l.split_whitespace()
+                .map(|n| n.parse().unwrap())
+                .collect()
+        };
+        let races = numbers(lines[0]).into_iter()
+            .zip(numbers(lines[1]))
+            .map(|(time, distance)| Race::new(time, distance))
+            .collect();
+
+        let single = |l: &str| -> u64 {
+            l.replace(' ', "").parse().unwrap()
+        };
+        (races, Race::new(single(lines[0]), single(lines[1])))
+    }
+
+    /// Product of the number of ways to beat each race
+    fn part1((races, _): &Self::Input<'_>) -> impl Display {
+        races.iter()
+            .map(|r| r.number_of_ways_to_beat())
+            .product::<u32>()
+    }
+
+    /// Number of ways to beat
+    fn part2((_, race): &Self::Input<'_>) -> impl Display {
+        race.number_of_ways_to_beat()
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::Entry;
+
+use aoc::Solution;
+use cycle::Cycle;
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Left, Right
+}
+
+impl Instruction {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Steps at which a ghost is on a goal
+#[derive(Debug)]
+struct GhostGoals {
+    /// Steps before the ghost got into its cycle
+    before_cycle: Vec<u64>,
+    /// Remainders of the later steps, modulo the length of the cycle
+    remainders: Vec<u64>,
+    cycle: Cycle,
+}
+
+impl GhostGoals {
+    fn contains(&self, steps: u64) -> bool {
+        if steps < self.cycle.start as u64 {
+            self.before_cycle.contains(&steps)
+        } else {
+            self.remainders.contains(&(steps % self.cycle.length as u64))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    instructions: Vec<Instruction>,
+    nodes: Vec<(u32, u32)>,
+    node_lookup: HashMap<String, u32>,
+}
+
+impl Map {
+    fn next(&self, node: u32, instruction: Instruction) -> u32 {
+        match instruction {
+            Instruction::Left => self.nodes[node as usize].0,
+            Instruction::Right => self.nodes[node as usize].1,
+        }
+    }
+
+    fn steps(&self) -> u32 {
+        let mut steps = 0;
+
+        let mut current = self.node_lookup["AAA"];
+        let goal = self.node_lookup["ZZZ"];
+
+        for instruction in self.instructions.iter().cycle() {
+            if current == goal {
+                break;
+            }
+
+            current = self.next(current, *instruction);
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Follows the ghost until it repeats its node and position in the instructions
+    fn ghost_goals(&self, start: u32, goals: &HashSet<u32>) -> GhostGoals {
+        let step = |(node, i): &mut (u32, usize)| {
+            *node = self.next(*node, self.instructions[*i]);
+            *i = (*i + 1) % self.instructions.len();
+        };
+        let cycle = Cycle::hashed(&(start, 0), step, |&state| state);
+
+        let mut before_cycle = Vec::new();
+        let mut remainders = Vec::new();
+        let mut state = (start, 0);
+        for steps in 0..cycle.start + cycle.length {
+            if goals.contains(&state.0) {
+                if steps < cycle.start {
+                    before_cycle.push(steps as u64);
+                } else {
+                    remainders.push((steps % cycle.length) as u64);
+                }
+            }
+
+            step(&mut state);
+        }
+
+        GhostGoals { before_cycle, remainders, cycle }
+    }
+
+    /// Steps till all ghosts are on a goal at once, or `None` when that never happens
+    fn ghost_steps(&self) -> Option<u64> {
+        let goals: HashSet<_> = self.node_lookup.iter()
+            .filter_map(|(k, v)| {
+                if k.ends_with('Z') {
+                    Some(*v)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let ghosts: Vec<_> = self.node_lookup.iter()
+            .filter_map(|(k, v)| {
+                if k.ends_with('A') {
+                    Some(self.ghost_goals(*v, &goals))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Check every step until all ghosts are in their cycle
+        let all_in_cycle = ghosts.iter().map(|g| g.cycle.start as u64).max()?;
+        if let Some(steps) = (0..all_in_cycle).find(|&steps| ghosts.iter().all(|g| g.contains(steps))) {
+            return Some(steps);
+        }
+
+        // Afterwards combine the remainders of every ghost
+        let mut solutions = vec![(0, 1)];
+        for g in &ghosts {
+            let length = g.cycle.length as i64;
+            solutions = solutions.into_iter()
+                .flat_map(|solution| {
+                    g.remainders.iter().filter_map(move |&r| math::crt([solution, (r as i64, length)]))
+                })
+                .collect();
+
+            // Solutions are reduced modulo the combined cycle length, so combinations which agree are kept once
+            solutions.sort_unstable();
+            solutions.dedup();
+        }
+
+        solutions.into_iter()
+            .map(|(x, m)| {
+                let (x, m) = (x as u64, m as u64);
+                if x >= all_in_cycle { x } else { x + (all_in_cycle - x).div_ceil(m) * m }
+            })
+            .min()
+    }
+}
+
+impl FromStr for Map {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [instructions, str_nodes] = s.split("\n\n")
+            .collect::<Vec<_>>()
+            .try_into().map_err(|_| "Invalid format")?;
+
+        let instructions = instructions.chars()
+            .map(|c| Instruction::from_char(c).unwrap())
+            .collect();
+
+        let mut node_lookup_len = 0;
+        let mut node_lookup = HashMap::new();
+        let mut nodes = vec![(0, 0); str_nodes.lines().count()];
+        for l in str_nodes.lines() {
+            let chars: Vec<_> = l.chars().collect();
+            let current: String = chars[..3].iter().collect();
+            let left: String = chars[7..10].iter().collect();
+            let right: String = chars[12..15].iter().collect();
+
+            let current_id = match node_lookup.entry(current) {
+                Entry::Occupied(e) => *e.get(),
+                Entry::Vacant(e) => {
+                    let id = node_lookup_len as u32;
+                    e.insert(id);
+                    node_lookup_len += 1;
+
+                    id
+                },
+            };
+            let left_id = match node_lookup.entry(left) {
+                Entry::Occupied(e) => *e.get(),
+                Entry::Vacant(e) => {
+                    let id = node_lookup_len as u32;
+                    e.insert(id);
+                    node_lookup_len += 1;
+
+                    id
+                },
+            };
+            let right_id = match node_lookup.entry(right) {
+                Entry::Occupied(e) => *e.get(),
+                Entry::Vacant(e) => {
+                    let id = node_lookup_len as u32;
+                    e.insert(id);
+                    node_lookup_len += 1;
+
+                    id
+                },
+            };
+
+            nodes[current_id as usize] = (left_id, right_id);
+        }
+
+        Ok(Self { instructions, nodes, node_lookup })
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Map::from_str(input).unwrap()
+    }
+
+    /// Steps till the end
+    fn part1(map: &Self::Input<'_>) -> impl Display {
+        map.steps()
+    }
+
+    /// Steps till all ghosts are at the end
+    fn part2(map: &Self::Input<'_>) -> impl Display {
+        map.ghost_steps().expect("Ghosts are never all at the end")
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GHOSTS: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+44A = (44Z, 44Z)
+44Z = (44B, 44B)
+44B = (44C, 44C)
+44C = (44Z, 44Z)
+XXX = (XXX, XXX)";
+
+    fn ghosts(names: &[&str]) -> Map {
+        let lines: Vec<_> = GHOSTS.lines()
+            .filter(|l| !l.ends_with(')') || !l[..3].ends_with('A') || names.contains(&&l[..3]))
+            .collect();
+
+        Map::from_str(&lines.join("\n")).unwrap()
+    }
+
+    #[test]
+    pub fn example() {
+        assert_eq!(ghosts(&["11A", "22A"]).ghost_steps(), Some(6));
+    }
+
+    #[test]
+    pub fn offset_cycles() {
+        // The 44A ghost is on a goal after 1, 4, 7, ... steps, which does not start at zero
+        assert_eq!(ghosts(&["11A", "44A"]).ghost_steps(), Some(4));
+        assert_eq!(ghosts(&["22A", "44A"]).ghost_steps(), None);
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt::Display;
+use std::mem;
+
+use aoc::Solution;
+
+
+fn predict(history: Vec<i32>) -> (i32, i32) {
+    let mut current = history;
+    let mut differences = Vec::new();
+
+    let mut first_values = vec![current[0]];
+    let mut last_values = vec![current[current.len() - 1]];
+
+    loop {
+        let mut all_zero = true;
+        for w in current.windows(2) {
+            let diff = w[1] - w[0];
+            differences.push(diff);
+            all_zero &= diff == 0;
+        }
+
+        if all_zero {
+            break;
+        } else {
+            first_values.push(differences[0]);
+            last_values.push(differences[differences.len() - 1]);
+
+            // Set the differences on current, and clear to start anew.
+            mem::swap(&mut current, &mut differences);
+            differences.clear();
+        }
+    }
+
+    // Find new first
+    for i in (0..(first_values.len() - 1)).rev() {
+        first_values[i] -= first_values[i + 1];
+    }
+
+    (first_values[0], last_values.into_iter().sum())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    /// Previous and next prediction of each history
+    type Input<'a> = Vec<(i32, i32)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let histories = input
+            .lines()
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|n| n.parse::<i32>().unwrap())
+                    .collect::<Vec<_>>()
+            });
+        histories
+            .map(predict)
+            .collect()
+    }
+
+    /// Sum of predictions
+    fn part1(predictions: &Self::Input<'_>) -> impl Display {
+        predictions.iter()
+            .map(|h| h.1)
+            .sum::<i32>()
+    }
+
+    /// Sum of backwards predictions
+    fn part2(predictions: &Self::Input<'_>) -> impl Display {
+        predictions.iter()
+            .map(|h| h.0)
+            .sum::<i32>()
+    }
+}
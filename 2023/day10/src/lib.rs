@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+use geometry::Polygon;
+use grid::{Cell, Grid, Point};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pipe {
+    Horizontal, Vertical,
+    NorthEast, NorthWest,
+    SouthEast, SouthWest,
+    Start, Ground,
+}
+
+impl Cell for Pipe {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '-' => Some(Self::Horizontal),
+            '|' => Some(Self::Vertical),
+            'L' => Some(Self::NorthEast),
+            'J' => Some(Self::NorthWest),
+            'F' => Some(Self::SouthEast),
+            '7' => Some(Self::SouthWest),
+            'S' => Some(Self::Start),
+            '.' => Some(Self::Ground),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pipe::Horizontal => write!(f, "-"),
+            Pipe::Vertical => write!(f, "|"),
+            Pipe::NorthEast => write!(f, "L"),
+            Pipe::NorthWest => write!(f, "J"),
+            Pipe::SouthEast => write!(f, "F"),
+            Pipe::SouthWest => write!(f, "7"),
+            Pipe::Start => write!(f, "S"),
+            Pipe::Ground => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    map: Grid<Pipe>,
+    starting_point: Point,
+    /// Tiles of the loop, in order
+    main_pipeline: Vec<Point>,
+}
+
+impl Map {
+    pub fn loop_length(&mut self) -> u32 {
+        let mut prev = self.starting_point;
+        let mut current = self.starting_point;
+        let mut steps = 0;
+        loop {
+            if steps != 0 && current == self.starting_point {
+                break;
+            }
+            steps += 1;
+            self.main_pipeline.push(current);
+
+            let Point { x, y } = current;
+            let next = match self.map[current] {
+                Pipe::Horizontal => Point::new(2 * x - prev.x, y),
+                Pipe::Vertical => Point::new(x, 2 * y - prev.y),
+                Pipe::NorthEast => {
+                    if prev == Point::new(x, y - 1) {
+                        // Coming from the north, going east
+                        Point::new(x + 1, y)
+                    } else {
+                        // Coming from east, going north
+                        Point::new(x, y - 1)
+                    }
+                },
+                Pipe::NorthWest => {
+                    if prev == Point::new(x, y - 1) {
+                        // Coming from the north, going west
+                        Point::new(x - 1, y)
+                    } else {
+                        // Coming from west, going north
+                        Point::new(x, y - 1)
+                    }
+                },
+                Pipe::SouthEast => {
+                    if prev == Point::new(x, y + 1) {
+                        // Coming from the south, going east
+                        Point::new(x + 1, y)
+                    } else {
+                        // Coming from east, going south
+                        Point::new(x, y + 1)
+                    }
+                },
+                Pipe::SouthWest => {
+                    if prev == Point::new(x, y + 1) {
+                        // Coming from the south, going west
+                        Point::new(x - 1, y)
+                    } else {
+                        // Coming from west, going south
+                        Point::new(x, y + 1)
+                    }
+                },
+                Pipe::Start => {
+                    let connects = |offset, pipes: [Pipe; 3]| {
+                        self.map.step(current, offset).filter(|&p| pipes.contains(&self.map[p]))
+                    };
+
+                    connects((0, -1), [Pipe::Vertical, Pipe::SouthEast, Pipe::SouthWest])
+                        .or_else(|| connects((0, 1), [Pipe::Vertical, Pipe::NorthEast, Pipe::NorthWest]))
+                        .or_else(|| connects((1, 0), [Pipe::Horizontal, Pipe::NorthWest, Pipe::SouthWest]))
+                        .or_else(|| connects((-1, 0), [Pipe::Horizontal, Pipe::NorthEast, Pipe::SouthEast]))
+                        .expect("Start is not connected")
+                },
+                Pipe::Ground => unreachable!(),
+            };
+
+            (prev, current) = (current, next);
+        }
+
+        steps
+    }
+
+    /// Tiles enclosed by the loop, which are the points strictly inside it as a polygon.
+    pub fn enclosed_tiles(&self) -> i64 {
+        let pipeline: Polygon = self.main_pipeline.iter()
+            .map(|p| geometry::Point::new(p.x as i64, p.y as i64))
+            .collect();
+
+        pipeline.interior_points()
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let main_pipeline: HashSet<_> = self.main_pipeline.iter().collect();
+        for (p, t) in self.map.iter() {
+            if main_pipeline.contains(&p) {
+                write!(f, "{}", t)?;
+            } else {
+                write!(f, ".")?;
+            }
+
+            if p.x == self.map.width() - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Map {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Grid<Pipe> = s.parse().map_err(|_| "Invalid map")?;
+        let starting_point = map.position(|&p| p == Pipe::Start).ok_or("No starting point")?;
+
+        Ok(Self { map, starting_point, main_pipeline: Vec::new() })
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    /// Map with its main pipeline, and the length of that loop
+    type Input<'a> = (Map, u32);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut map = Map::from_str(input).unwrap();
+        let length = map.loop_length();
+
+        (map, length)
+    }
+
+    /// Furthest away
+    fn part1((_, length): &Self::Input<'_>) -> impl Display {
+        length / 2
+    }
+
+    /// Inside size
+    fn part2((map, _): &Self::Input<'_>) -> impl Display {
+        map.enclosed_tiles()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn enclosed() {
+        let mut map = Map::from_str(include_str!("../example.txt")).unwrap();
+        assert_eq!(map.loop_length(), 16);
+
+        // Tiles between two pipes next to each other are outside the loop
+        let mut map = Map::from_str("..........\n.S------7.\n.|F----7|.\n.||....||.\n.||....||.\n.|L-7F-J|.\n.|..||..|.\n.L--JL--J.\n..........").unwrap();
+        map.loop_length();
+        assert_eq!(map.enclosed_tiles(), 4);
+    }
+}
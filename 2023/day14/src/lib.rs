@@ -0,0 +1,211 @@
+use std::fmt::{Display, self};
+use std::str::FromStr;
+
+use aoc::Solution;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Empty, Rock, RoundRock,
+}
+
+impl Field {
+    fn from_char(c: char) -> Self {
+        match c {
+            '.' => Self::Empty,
+            '#' => Self::Rock,
+            'O' => Self::RoundRock,
+            _ => panic!("Invalid field: {c}"),
+        }
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Field::Empty => write!(f, "."),
+            Field::Rock => write!(f, "#"),
+            Field::RoundRock => write!(f, "O"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Map {
+    map: Vec<Vec<Field>>,
+}
+
+impl Map {
+    fn tilt_north(&mut self) {
+        for y in 1..self.map.len() {
+            for x in 0..self.map[y].len() {
+                if self.map[y][x] != Field::RoundRock {
+                    continue;
+                }
+
+                let mut new_y = y;
+                while new_y > 0 {
+                    if self.map[new_y - 1][x] == Field::Empty {
+                        new_y -= 1;
+                        continue;
+                    } else {
+                        break;
+                    }
+                }
+
+                self.map[y][x] = Field::Empty;
+                self.map[new_y][x] = Field::RoundRock;
+            }
+        }
+    }
+
+    fn tilt_east(&mut self) {
+        for x in (0..self.map[0].len()-1).rev() {
+            for y in 0..self.map.len() {
+                if self.map[y][x] != Field::RoundRock {
+                    continue;
+                }
+
+                let mut new_x = x;
+                while new_x < self.map[y].len() - 1 {
+                    if self.map[y][new_x + 1] == Field::Empty {
+                        new_x += 1;
+                        continue;
+                    } else {
+                        break;
+                    }
+                }
+
+                self.map[y][x] = Field::Empty;
+                self.map[y][new_x] = Field::RoundRock;
+            }
+        }
+    }
+
+    fn tilt_south(&mut self) {
+        for y in (0..self.map.len()-1).rev() {
+            for x in 0..self.map[y].len() {
+                if self.map[y][x] != Field::RoundRock {
+                    continue;
+                }
+
+                let mut new_y = y;
+                while new_y < self.map.len() - 1 {
+                    if self.map[new_y + 1][x] == Field::Empty {
+                        new_y += 1;
+                        continue;
+                    } else {
+                        break;
+                    }
+                }
+
+                self.map[y][x] = Field::Empty;
+                self.map[new_y][x] = Field::RoundRock;
+            }
+        }
+    }
+
+    fn tilt_west(&mut self) {
+        for x in 1..self.map[0].len() {
+            for y in 0..self.map.len() {
+                if self.map[y][x] != Field::RoundRock {
+                    continue;
+                }
+
+                let mut new_x = x;
+                while new_x > 0 {
+                    if self.map[y][new_x - 1] == Field::Empty {
+                        new_x -= 1;
+                        continue;
+                    } else {
+                        break;
+                    }
+                }
+
+                self.map[y][x] = Field::Empty;
+                self.map[y][new_x] = Field::RoundRock;
+            }
+        }
+    }
+
+    fn beam_load(&self) -> usize {
+        self.map.iter().enumerate()
+            .map(|(y, row)| (self.map.len() - y) * row.iter().filter(|f| **f == Field::RoundRock).count())
+            .sum()
+    }
+
+    fn spin(&mut self) {
+        self.tilt_north();
+        self.tilt_west();
+        self.tilt_south();
+        self.tilt_east();
+    }
+}
+
+impl FromStr for Map {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = s.lines()
+            .map(|l| l.chars().map(Field::from_char).collect())
+            .collect();
+
+        Ok(Self { map })
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.map {
+            for field in row {
+                write!(f, "{}", field)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Map::from_str(input).unwrap()
+    }
+
+    /// Beam load
+    fn part1(map: &Self::Input<'_>) -> impl Display {
+        let mut map = map.clone();
+        map.tilt_north();
+
+        map.beam_load()
+    }
+
+    /// Beam load after a billion cycles
+    fn part2(map: &Self::Input<'_>) -> impl Display {
+        cycle::state_at(map.clone(), Map::spin, 1_000_000_000).beam_load()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn spin() {
+        let mut map = Map::from_str(include_str!("../example.txt")).unwrap();
+        map.spin();
+
+        let after = ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n.O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....\n";
+        assert_eq!(map.to_string(), after);
+
+        let map = Map::from_str(include_str!("../example.txt")).unwrap();
+        assert_eq!(cycle::state_at(map, Map::spin, 1_000_000_000).beam_load(), 64);
+    }
+}
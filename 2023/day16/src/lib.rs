@@ -0,0 +1,229 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+use grid::Direction;
+use hashbrown::HashSet;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    MirrorSWNE,
+    MirrorSENW,
+    SplitVertical,
+    SplitHorizontal,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Self {
+        match c {
+            '.'  => Self::Empty,
+            '/'  => Self::MirrorSWNE,
+            '\\' => Self::MirrorSENW,
+            '|'  => Self::SplitVertical,
+            '-'  => Self::SplitHorizontal,
+            _    => panic!("Invalid tile: {c}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Facility {
+    map: Vec<Vec<Tile>>,
+}
+
+impl Facility {
+    pub fn find_max_energized_tiles(&self) -> usize {
+        let mut max_energized_tiles = 0;
+
+        let height = self.map.len() as i32;
+        let width = self.map[0].len() as i32;
+
+        for x in 0..width {
+            let point_south = self.energized_tiles(((x, 0), Direction::South)).len();
+            max_energized_tiles = max_energized_tiles.max(point_south);
+
+            let point_north = self.energized_tiles(((x, height - 1), Direction::North)).len();
+            max_energized_tiles = max_energized_tiles.max(point_north);
+        }
+
+        for y in 0..height {
+            let point_east = self.energized_tiles(((0, y), Direction::East)).len();
+            max_energized_tiles = max_energized_tiles.max(point_east);
+
+            let point_west = self.energized_tiles(((width - 1, y), Direction::West)).len();
+            max_energized_tiles = max_energized_tiles.max(point_west);
+        }
+
+        max_energized_tiles
+    }
+
+    pub fn energized_tiles(&self, starting_beam: ((i32, i32), Direction)) -> HashSet<(i32, i32)> {
+        let mut energized_tiles = HashSet::new();
+        let mut visited = HashSet::new();
+
+        let mut beams = vec![starting_beam];
+        while let Some(((x, y), heading)) = beams.pop() {
+            // Check if we've already visited this tile with this heading
+            // In other to avoid infinite loops
+            if !visited.insert(((x, y), heading)) {
+                continue;
+            }
+
+            // Energize the current tile
+            energized_tiles.insert((x, y));
+
+            match (self.map[y as usize][x as usize], heading) {
+                (Tile::Empty, h) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), h) {
+                    beams.push(new_loc);
+                },
+                (Tile::MirrorSWNE, Direction::North) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::East) {
+                    beams.push(new_loc);
+                },
+                (Tile::MirrorSWNE, Direction::East) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::North) {
+                    beams.push(new_loc);
+                },
+                (Tile::MirrorSWNE, Direction::South) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::West) {
+                    beams.push(new_loc);
+                },
+                (Tile::MirrorSWNE, Direction::West) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::South) {
+                    beams.push(new_loc);
+                }
+                (Tile::MirrorSENW, Direction::North) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::West) {
+                    beams.push(new_loc);
+                },
+                (Tile::MirrorSENW, Direction::East) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::South) {
+                    beams.push(new_loc);
+                },
+                (Tile::MirrorSENW, Direction::South) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::East) {
+                    beams.push(new_loc);
+                },
+                (Tile::MirrorSENW, Direction::West) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::North) {
+                    beams.push(new_loc);
+                },
+                (Tile::SplitVertical, Direction::East|Direction::West) => {
+                    if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::North) {
+                        beams.push(new_loc);
+                    }
+                    if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::South) {
+                        beams.push(new_loc);
+                    }
+                },
+                (Tile::SplitVertical, h) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), h) {
+                    beams.push(new_loc);
+                },
+                (Tile::SplitHorizontal, Direction::North|Direction::South) => {
+                    if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::East) {
+                        beams.push(new_loc);
+                    }
+                    if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), Direction::West) {
+                        beams.push(new_loc);
+                    }
+                },
+                (Tile::SplitHorizontal, h) => if let Some(new_loc) = self.get_new_location(&mut energized_tiles, (x, y), h) {
+                    beams.push(new_loc);
+                },
+            }
+        }
+
+        energized_tiles
+    }
+
+    /// Find the next location where the beam encounters a non-empty tile energizing all the empty tiles in between
+    /// Returns None if the beam goes out of the map
+    fn get_new_location(&self, energized_tiles: &mut HashSet<(i32, i32)>, (x, y): (i32, i32), new_heading: Direction) -> Option<((i32, i32), Direction)> {
+        match new_heading {
+            Direction::North => {
+                for y in (0..y).rev() {
+                    if self.map[y as usize][x as usize] != Tile::Empty {
+                        return Some(((x, y), Direction::North));
+                    }
+
+                    energized_tiles.insert((x, y));
+                }
+                None
+            },
+            Direction::East => {
+                for x in x+1..self.map[0].len() as i32 {
+                    if self.map[y as usize][x as usize] != Tile::Empty {
+                        return Some(((x, y), Direction::East));
+                    }
+
+                    energized_tiles.insert((x, y));
+                }
+                None
+            },
+            Direction::South => {
+                for y in y+1..self.map.len() as i32 {
+                    if self.map[y as usize][x as usize] != Tile::Empty {
+                        return Some(((x, y), Direction::South));
+                    }
+
+                    energized_tiles.insert((x, y));
+                }
+                None
+            },
+            Direction::West => {
+                for x in (0..x).rev() {
+                    if self.map[y as usize][x as usize] != Tile::Empty {
+                        return Some(((x, y), Direction::West));
+                    }
+
+                    energized_tiles.insert((x, y));
+                }
+                None
+            },
+        }
+    }
+}
+
+impl FromStr for Facility {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = s.lines()
+            .map(|l| l.chars().map(Tile::from_char).collect())
+            .collect();
+
+        Ok(Facility { map })
+    }
+}
+
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Facility;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Facility::from_str(input).unwrap()
+    }
+
+    /// Number of energized tiles
+    fn part1(facility: &Self::Input<'_>) -> impl Display {
+        facility.energized_tiles(((0, 0), Direction::East)).len()
+    }
+
+    /// Maximum number of energized tiles
+    fn part2(facility: &Self::Input<'_>) -> impl Display {
+        facility.find_max_energized_tiles()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn example() {
+        let facility = Facility::from_str(include_str!("../example.txt")).unwrap();
+
+        assert_eq!(facility.energized_tiles(((0, 0), Direction::East)).len(), 46);
+        assert_eq!(facility.find_max_energized_tiles(), 51);
+    }
+}
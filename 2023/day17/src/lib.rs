@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use grid::{Direction, Grid, Heading, Point};
+
+
+/// Position, heading and the number of steps in the same direction
+type Crucible = (Point, Direction, u32);
+
+fn heat_loss<const MAX_IN_SAME_DIRECTION: u32, const TURN_RADIUS: u32>(map: &Grid<u32>) -> u32 {
+    let goal = Point::new(map.width() - 1, map.height() - 1);
+
+    let moves = |&(position, heading, in_same_direction): &Crucible| {
+        let mut moves = Vec::with_capacity(3);
+        for &direction in Direction::ALL {
+            let turning = heading != direction;
+            if (!turning && in_same_direction == MAX_IN_SAME_DIRECTION)
+                || (turning && in_same_direction > MAX_IN_SAME_DIRECTION - TURN_RADIUS)
+                || direction == heading.reverse()
+            {
+                continue;
+            }
+
+            if turning {
+                if let Some((h, position)) = turn::<TURN_RADIUS>(map, position, heading, direction) {
+                    moves.push(((position, direction, 1), h));
+                }
+            } else if let Some(next) = map.step(position, direction.offset()) {
+                moves.push(((next, direction, in_same_direction + 1), map[next]));
+            }
+        }
+        moves
+    };
+
+    let is_goal = |&(position, _, in_same_direction): &Crucible| {
+        position == goal && in_same_direction > TURN_RADIUS
+    };
+
+    search::dijkstra([(Point::new(0, 0), Direction::East, 0)], moves, is_goal).unwrap().cost
+}
+
+fn turn<const TURN_RADIUS: u32>(map: &Grid<u32>, mut position: Point, heading: Direction, new_heading: Direction) -> Option<(u32, Point)> {
+    let mut heat_loss = 0;
+
+    for _ in 0..TURN_RADIUS {
+        position = map.step(position, heading.offset())?;
+        heat_loss += map[position];
+    }
+
+    position = map.step(position, new_heading.offset())?;
+    heat_loss += map[position];
+
+    Some((heat_loss, position))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse::<Grid<u8>>().unwrap().map(|&d| u32::from(d))
+    }
+
+    /// Heat loss
+    fn part1(map: &Self::Input<'_>) -> impl Display {
+        heat_loss::< 3, 0>(map)
+    }
+
+    /// Heat loss with ultra crucibles
+    fn part2(map: &Self::Input<'_>) -> impl Display {
+        heat_loss::<10, 3>(map)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn example() {
+        let map = Day17::parse(include_str!("../example.txt"));
+
+        assert_eq!(heat_loss::<3, 0>(&map), 102);
+        assert_eq!(heat_loss::<10, 3>(&map), 94);
+    }
+
+    #[test]
+    pub fn turn_radius() {
+        let map = Day17::parse("111111111111\n999999999991\n999999999991\n999999999991\n999999999991\n");
+
+        assert_eq!(heat_loss::<10, 3>(&map), 71);
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+use geometry::{Point, Polygon};
+use grid::{Direction, Heading};
+
+
+#[derive(Debug)]
+pub struct Instruction {
+    direction: Direction,
+    distance: i64,
+}
+
+impl Instruction {
+    fn parse_from_color(s: &str) -> Self {
+        let parts: Vec<_> = s.split_whitespace().collect();
+
+        let distance = parts[2][2..7].chars()
+            .map(|c| c.to_digit(16).unwrap() as i64)
+            .fold(0, |acc, c| 16 * acc + c);
+
+        let direction = match parts[2][7..].chars().next().and_then(Direction::from_char) {
+            Some(d) => d,
+            None => panic!("Invalid direction: {}", &parts[2][7..]),
+        };
+
+        Self { direction, distance }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<_> = s.split_whitespace().collect();
+        let direction = parts[0].parse().map_err(|_| "Invalid direction")?;
+
+        let distance = parts[1].parse().unwrap();
+
+        Ok(Self { direction, distance })
+    }
+}
+
+/// Points on the edges of the lagoon and inside it
+fn calculate_volume(instructions: &[Instruction]) -> i64 {
+    let mut current = Point::new(0, 0);
+    let lagoon: Polygon = instructions.iter()
+        .map(|Instruction { direction, distance }| {
+            let (dx, dy) = direction.offset();
+            current = current + Point::new(dx as i64, dy as i64) * *distance;
+            current
+        })
+        .collect();
+
+    lagoon.lattice_points()
+}
+
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    /// Instructions, and the ones hidden in the colors
+    type Input<'a> = (Vec<Instruction>, Vec<Instruction>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let part1_instructions: Vec<_> = input
+            .lines()
+            .map(|l| Instruction::from_str(l).unwrap())
+            .collect();
+        let part2_instructions: Vec<_> = input
+            .lines()
+            .map(Instruction::parse_from_color)
+            .collect();
+
+        (part1_instructions, part2_instructions)
+    }
+
+    /// Volume
+    fn part1((part1_instructions, _): &Self::Input<'_>) -> impl Display {
+        calculate_volume(part1_instructions)
+    }
+
+    /// Volume using the colors
+    fn part2((_, part2_instructions): &Self::Input<'_>) -> impl Display {
+        calculate_volume(part2_instructions)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn colors() {
+        let instructions: Vec<_> = ["R 6 (#70c710)", "D 5 (#0dc571)", "L 2 (#5713f2)", "U 2 (#caa173)"]
+            .into_iter()
+            .map(Instruction::parse_from_color)
+            .collect();
+
+        let directions: Vec<_> = instructions.iter().map(|i| i.direction).collect();
+        assert_eq!(directions, [Direction::East, Direction::South, Direction::West, Direction::North]);
+        assert_eq!(instructions[0].distance, 461937);
+    }
+
+    #[test]
+    pub fn volume() {
+        let (instructions, color_instructions) = Day18::parse(include_str!("../example.txt"));
+
+        assert_eq!(calculate_volume(&instructions), 62);
+        assert_eq!(calculate_volume(&color_instructions), 952408144115);
+    }
+}
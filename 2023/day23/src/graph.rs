@@ -1,33 +1,9 @@
 use std::collections::{HashMap, HashSet, VecDeque, hash_map::Entry};
 
-use crate::map::{Map, Direction};
+use bitset::GrowableBitSet;
+use grid::{Direction, Heading};
 
-
-/// A visited set that can hold up to 64 elements
-#[derive(Debug, Clone, Copy)]
-struct Visited(u64);
-
-impl Visited {
-    #[inline(always)]
-    const fn new() -> Self {
-        Self(0)
-    }
-
-    #[inline(always)]
-    fn insert(&mut self, n: usize) -> bool {
-        let mask = 1 << n;
-
-        let already_visited = (self.0 & mask) != 0;
-        self.0 |= mask;
-
-        !already_visited
-    }
-
-    #[inline(always)]
-    const fn contains(&self, n: &usize) -> bool {
-        self.0 & (1 << *n) != 0
-    }
-}
+use crate::map::Map;
 
 
 #[derive(Debug)]
@@ -39,33 +15,24 @@ pub(crate) struct Graph {
 
 impl Graph {
     pub(crate) fn longest_path(&self) -> u32 {
-        // Check if we have at most 64 nodes, because our visited set can't handle more.
-        assert!(self.outgoing_arcs.len() <= 64);
+        let mut visited = GrowableBitSet::with_capacity(self.outgoing_arcs.len());
+        self.longest_path_from(self.start, 1, &mut visited)
+    }
 
-        let mut queue = Vec::new();
-        queue.push((1, self.start, Visited::new()));
+    /// Longest path to the goal which does not go through the visited nodes, or 0 when there is none
+    fn longest_path_from(&self, n: usize, length: u32, visited: &mut GrowableBitSet) -> u32 {
+        if n == self.goal {
+            return length;
+        }
 
+        visited.insert(n);
         let mut longest_path_length = 0;
-        while let Some((l, n, mut visited)) = queue.pop() {
-            if n == self.goal {
-                longest_path_length = u32::max(longest_path_length, l);
-                continue;
-            }
-
-            // Check if visited
-            if !visited.insert(n) {
-                continue;
-            }
-
-            // Generate next step
-            for (next, steps) in &self.outgoing_arcs[n] {
-                if visited.contains(next) {
-                    continue;
-                }
-
-                queue.push((l + *steps, *next, visited));
+        for &(next, steps) in &self.outgoing_arcs[n] {
+            if !visited.contains(next) {
+                longest_path_length = u32::max(longest_path_length, self.longest_path_from(next, length + steps, visited));
             }
         }
+        visited.remove(n);
 
         longest_path_length
     }
@@ -88,16 +55,16 @@ impl Graph {
 
         let mut visited = HashSet::new();
 
-        while let Some((x, y)) = queue.pop_front() {
-            if !visited.insert((x, y)) {
+        while let Some(position) = queue.pop_front() {
+            if !visited.insert(position) {
                 continue;
             }
 
-            if (x, y) == map.goal() {
+            if position == map.goal() {
                 continue;
             }
 
-            let node_index = match node_lookup.entry((x, y)) {
+            let node_index = match node_lookup.entry(position) {
                 Entry::Occupied(e) => *e.get(),
                 Entry::Vacant(e) => {
                     let i = outgoing_arcs.len();
@@ -110,15 +77,15 @@ impl Graph {
             };
 
             // Generate next step
-            for d in [Direction::North, Direction::East, Direction::South, Direction::West] {
-                let (steps, (new_x, new_y)) = map.next_crossing::<WITH_SLOPES>((x, y), d);
+            for &d in Direction::ALL {
+                let (steps, new_position) = map.next_crossing::<WITH_SLOPES>(position, d);
                 if steps == 0 {
                     continue;
                 }
 
-                queue.push_back((new_x, new_y));
+                queue.push_back(new_position);
 
-                let new_node_index = match node_lookup.entry((new_x, new_y)) {
+                let new_node_index = match node_lookup.entry(new_position) {
                     Entry::Occupied(e) => *e.get(),
                     Entry::Vacant(e) => {
                         let i = outgoing_arcs.len();
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+
+use crate::map::Map;
+use crate::graph::Graph;
+
+mod map;
+mod graph;
+
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Map::from_str(input).unwrap()
+    }
+
+    /// Scenic path length
+    fn part1(map: &Self::Input<'_>) -> impl Display {
+        Graph::from_map::<true>(map).longest_path()
+    }
+
+    /// Scenic path length when climbing slopes
+    fn part2(map: &Self::Input<'_>) -> impl Display {
+        Graph::from_map::<false>(map).longest_path()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn example() {
+        let map = Map::from_str(include_str!("../example.txt")).unwrap();
+
+        assert_eq!(Graph::from_map::<true>(&map).longest_path(), 94);
+        assert_eq!(Graph::from_map::<false>(&map).longest_path(), 154);
+    }
+}
@@ -1,5 +1,8 @@
 use std::str::FromStr;
 
+use grid::{Cell, Direction, Grid, Heading, Point};
+
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Trail {
     Path, Forest,
@@ -7,97 +10,69 @@ enum Trail {
     SlopeWest, SlopeEast,
 }
 
-impl Trail {
-    pub fn from_char(c: char) -> Self {
+impl Cell for Trail {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '.' => Self::Path,
-            '#' => Self::Forest,
-            '^' => Self::SlopeNorth,
-            'v' => Self::SlopeSouth,
-            '<' => Self::SlopeWest,
-            '>' => Self::SlopeEast,
-            _ => panic!("Invalid trail char: {c}"),
+            '.' => Some(Self::Path),
+            '#' => Some(Self::Forest),
+            '^' => Some(Self::SlopeNorth),
+            'v' => Some(Self::SlopeSouth),
+            '<' => Some(Self::SlopeWest),
+            '>' => Some(Self::SlopeEast),
+            _ => None,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum Direction {
-    North, East, South, West,
-}
-
-impl Direction {
-    const fn step(&self, (x, y): (usize, usize)) -> (usize, usize) {
-        match self {
-            Self::North => (x, y - 1),
-            Self::East  => (x + 1, y),
-            Self::South => (x, y + 1),
-            Self::West  => (x - 1, y),
-        }
-    }
-
-    const fn continue_in(&self) -> [Direction; 3] {
-        match self {
-            Self::North => [Self::North, Self::East, Self::West],
-            Self::East  => [Self::North, Self::East, Self::South],
-            Self::South => [Self::East, Self::South, Self::West],
-            Self::West  => [Self::North, Self::South, Self::West],
-        }
-    }
-}
-
-
 #[derive(Debug, Clone)]
-pub(crate) struct Map {
-    map: Vec<Vec<Trail>>,
-    start: (usize, usize),
-    goal: (usize, usize),
+pub struct Map {
+    map: Grid<Trail>,
+    start: Point,
+    goal: Point,
 }
 
 impl Map {
     #[inline(always)]
-    pub(crate) const fn start(&self) -> (usize, usize) {
+    pub(crate) const fn start(&self) -> Point {
         self.start
     }
 
     #[inline(always)]
-    pub(crate) const fn goal(&self) -> (usize, usize) {
+    pub(crate) const fn goal(&self) -> Point {
         self.goal
     }
 
-    pub(crate) fn next_crossing<const WITH_SLOPES: bool>(&self, (mut x, mut y): (usize, usize), mut direction: Direction) -> (u32, (usize, usize)) {
+    pub(crate) fn next_crossing<const WITH_SLOPES: bool>(&self, mut position: Point, mut direction: Direction) -> (u32, Point) {
         // Check if we can even walk in this direction
-        let (next_x, next_y) = direction.step((x, y));
-        if !self.can_continue::<WITH_SLOPES>((next_x, next_y), direction) {
-            return (0, (x, y));
+        // The map is surrounded by forest, except for the goal
+        let next = direction.step(position).unwrap();
+        if !self.can_continue::<WITH_SLOPES>(next, direction) {
+            return (0, position);
         }
-        (x, y) = (next_x, next_y);
+        position = next;
 
         let mut steps = 1;
         loop {
-            if (x, y) == self.goal {
-                return (steps, (x, y));
+            if position == self.goal {
+                return (steps, position);
             }
 
-            let can_continue_in: Vec<_> = direction.continue_in().into_iter()
-                .filter(|d| {
-                    let (sx, sy) = d.step((x, y));
-                    self.can_continue::<WITH_SLOPES>((sx, sy), *d)
-                })
+            let can_continue_in: Vec<_> = [direction.turn_left(), direction, direction.turn_right()].into_iter()
+                .filter(|d| self.can_continue::<WITH_SLOPES>(d.step(position).unwrap(), *d))
                 .collect();
 
             if can_continue_in.len() > 1 || can_continue_in.is_empty() {
-                return (steps, (x, y));
+                return (steps, position);
             }
             direction = can_continue_in[0];
 
-            (x, y) = direction.step((x, y));
+            position = direction.step(position).unwrap();
             steps += 1;
         }
     }
 
-    fn can_continue<const WITH_SLOPES: bool>(&self, (x, y): (usize, usize), direction: Direction) -> bool {
-        match self.map[y][x] {
+    fn can_continue<const WITH_SLOPES: bool>(&self, position: Point, direction: Direction) -> bool {
+        match self.map[position] {
             Trail::Path   => true,
             Trail::Forest => false,
             Trail::SlopeNorth => {
@@ -120,13 +95,11 @@ impl FromStr for Map {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut map: Vec<Vec<_>> = s.lines()
-            .map(|l| l.chars().map(Trail::from_char).collect())
-            .collect();
+        let mut map: Grid<Trail> = s.parse().map_err(|_| "Invalid map")?;
 
-        map[0][1] = Trail::Forest;
-        let start = (1, 1);
-        let goal = (map[0].len() - 2, map.len() - 1);
+        map[Point::new(1, 0)] = Trail::Forest;
+        let start = Point::new(1, 1);
+        let goal = Point::new(map.width() - 2, map.height() - 1);
 
         Ok(Self { map, start, goal })
     }
@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    /// Both lists, sorted
+    type Input<'a> = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let (mut left_list, mut right_list): (Vec<_>, Vec<_>) = input
+            .lines()
+            .map(|l| {
+                let (l1, l2) = l.split_once("   ").unwrap();
+                let l1: u32 = l1.parse().unwrap();
+                let l2: u32 = l2.parse().unwrap();
+
+                (l1, l2)
+            })
+            .unzip();
+
+        left_list.sort_unstable();
+        right_list.sort_unstable();
+
+        (left_list, right_list)
+    }
+
+    /// Total distance
+    fn part1((left_list, right_list): &Self::Input<'_>) -> impl Display {
+        left_list.iter()
+            .zip(right_list.iter())
+            .map(|(l, r)| u32::abs_diff(*l,  *r))
+            .sum::<u32>()
+    }
+
+    /// Similarity
+    fn part2((left_list, right_list): &Self::Input<'_>) -> impl Display {
+        left_list.iter()
+            .map(|l| {
+                let right_list_count = right_list.iter()
+                    .filter(|r| *r == l)
+                    .count() as u32;
+
+                l * right_list_count
+            })
+            .sum::<u32>()
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+fn is_safe(report: &[u32]) -> bool {
+    let is_increasing = report[0] < report[report.len() - 1];
+
+    for w in report.windows(2) {
+        if u32::abs_diff(w[0], w[1]) > 3
+            || w[0] == w[1]
+            || (is_increasing && w[0] > w[1])
+            || (!is_increasing && w[0] < w[1])
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                l.split(' ')
+                    .map(|p| p.parse::<u32>().unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Safe reports
+    fn part1(reports: &Self::Input<'_>) -> impl Display {
+        reports.iter()
+            .filter(|r| is_safe(r))
+            .count()
+    }
+
+    /// Safe reports with the problem dampener
+    fn part2(reports: &Self::Input<'_>) -> impl Display {
+        reports.iter()
+            .filter(|r| {
+                if is_safe(r) {
+                    return true;
+                }
+
+                for i in 0..r.len() {
+                    let mut new_report = (*r).clone();
+                    new_report.remove(i);
+
+                    if is_safe(&new_report) {
+                        return true;
+                    }
+                }
+
+                false
+            })
+            .count()
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use regex::Regex;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    /// Corrupted memory
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    /// Mul sum
+    fn part1(corrupt_instruction: &Self::Input<'_>) -> impl Display {
+        let part1_re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+        part1_re.captures_iter(corrupt_instruction)
+            .map(|c| c.extract())
+            .map(|(_, [x, y])| {
+                x.parse::<i32>().unwrap() * y.parse::<i32>().unwrap()
+            })
+            .sum::<i32>()
+    }
+
+    /// Mul sum with enabled instructions only
+    fn part2(corrupt_instruction: &Self::Input<'_>) -> impl Display {
+        let mut enabled = true;
+        let mut part2_mult_sum = 0;
+
+        let part2_re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
+        for c in part2_re.captures_iter(corrupt_instruction) {
+            let m = c.get(0).map_or("", |m| m.as_str());
+            if m == "do()" {
+                enabled = true;
+                continue;
+            }
+            if m == "don't()" {
+                enabled = false;
+                continue;
+            }
+
+            if enabled {
+                let x = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                let y = c.get(2).map_or(0, |m| m.as_str().parse().unwrap());
+
+                part2_mult_sum += x * y;
+            }
+        }
+
+        part2_mult_sum
+    }
+}
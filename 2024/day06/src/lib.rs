@@ -0,0 +1,152 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+use grid::{Direction, Grid, Heading, Point};
+use hashbrown::HashSet;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Empty, Obstruction
+}
+
+impl State {
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '#' => Some(Self::Obstruction),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    map: Grid<State>,
+    guard: (Point, Direction),
+}
+
+impl Map {
+    pub fn guard_positions(&self) -> HashSet<Point> {
+        let (mut position, mut heading) = self.guard;
+
+        let mut positions = HashSet::new();
+        loop {
+            positions.insert(position);
+            debug_assert!(self.map[position] == State::Empty);
+
+            let Some(next) = self.map.step(position, heading.offset()) else {
+                break;
+            };
+
+            match self.map[next] {
+                State::Empty => {
+                    position = next;
+                },
+                State::Obstruction => {
+                    heading = heading.turn_right();
+                },
+            }
+        }
+        positions
+    }
+
+    pub fn is_guard_loop(&self) -> bool {
+        let (mut position, mut heading) = self.guard;
+
+        let mut position_heading = HashSet::new();
+        loop {
+            debug_assert!(self.map[position] == State::Empty);
+            let Some(next) = self.map.step(position, heading.offset()) else {
+                break;
+            };
+
+            match self.map[next] {
+                State::Empty => {
+                    position = next;
+                },
+                State::Obstruction => {
+                    heading = heading.turn_right();
+
+                    if !position_heading.insert((position, heading)) {
+                        return true;
+                    }
+                },
+            }
+        }
+        false
+    }
+
+    fn obstacle_locations(&mut self) -> usize {
+        let mut locations = 0;
+        let positions = self.guard_positions();
+
+        for position in positions.into_iter() {
+            if position == self.guard.0 {
+                continue;
+            }
+
+            self.map[position] = State::Obstruction;
+            let is_loop = self.is_guard_loop();
+            self.map[position] = State::Empty;
+
+            if is_loop {
+                locations += 1;
+            }
+        }
+        locations
+    }
+}
+
+impl FromStr for Map {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Grid<char> = s.parse().map_err(|_| "Invalid map")?;
+
+        let position = map.position(|&c| State::from_char(c).is_none()).ok_or("No guard found")?;
+        let guard = (position, Direction::from_char(map[position]).ok_or("Invalid guard")?);
+        let map = map.map(|&c| State::from_char(c).unwrap_or(State::Empty));
+
+        Ok(Map{ map, guard })
+    }
+}
+
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Map::from_str(input).unwrap()
+    }
+
+    /// Visited positions
+    fn part1(map: &Self::Input<'_>) -> impl Display {
+        map.guard_positions().len()
+    }
+
+    /// Obstacle locations
+    fn part2(map: &Self::Input<'_>) -> impl Display {
+        map.clone().obstacle_locations()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn example() {
+        let mut map = Map::from_str(include_str!("../example.txt")).unwrap();
+
+        assert_eq!(map.guard_positions().len(), 41);
+        assert_eq!(map.obstacle_locations(), 6);
+    }
+}
@@ -0,0 +1,101 @@
+use std::str::FromStr;
+use std::fmt::Display;
+
+use aoc::Solution;
+use grid::Direction;
+use map::Map;
+
+mod map;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Space {
+    Empty,
+    Wall,
+    Box, BoxOpen, BoxClose,
+}
+
+impl Space {
+    pub fn from_char(c: char) -> Self {
+        match c {
+            '.' => Space::Empty,
+            '#' => Space::Wall,
+            'O' => Space::Box,
+            _ => panic!("Invalid space character: {}", c),
+        }
+    }
+}
+
+impl Display for Space {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Space::Empty => write!(f, "."),
+            Space::Wall => write!(f, "#"),
+            Space::Box => write!(f, "O"),
+            Space::BoxOpen => write!(f, "["),
+            Space::BoxClose => write!(f, "]"),
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    /// Warehouse map and the moves of the robot
+    type Input<'a> = (Map, Vec<Direction>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .split("\n\n")
+            .collect();
+
+        let map = Map::from_str(input[0]).unwrap();
+        let moves: Vec<_> = input[1].chars()
+            .filter(|&c| c != '\n')
+            .map(|c| Direction::from_char(c).unwrap())
+            .collect();
+
+        (map, moves)
+    }
+
+    /// Box GPS sum
+    fn part1((map, moves): &Self::Input<'_>) -> impl Display {
+        let mut part1_map = map.clone();
+        for &m in moves {
+            part1_map.move_robot(m);
+        }
+
+        part1_map.box_gps_sum()
+    }
+
+    /// Box GPS sum in the enlarged warehouse
+    fn part2((map, moves): &Self::Input<'_>) -> impl Display {
+        let mut part2_map = map.clone();
+        part2_map.enlarge();
+
+        for &m in moves {
+            part2_map.move_robot(m);
+        }
+
+        part2_map.box_gps_sum()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn small_example() {
+        let (mut map, moves) = Day15::parse(include_str!("../example.txt"));
+        for m in moves {
+            map.move_robot(m);
+        }
+
+        assert_eq!(map.box_gps_sum(), 2028);
+    }
+}
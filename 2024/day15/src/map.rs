@@ -1,150 +1,94 @@
 use std::str::FromStr;
 use std::fmt::{Display, Formatter};
 
-use crate::{Move, Space};
+use grid::{Direction, Grid, Heading, Point};
+
+use crate::Space;
 
 
 #[derive(Debug, Clone)]
 pub struct Map {
-    map: Vec<Vec<Space>>,
-    robot_position: (usize, usize),
+    map: Grid<Space>,
+    robot_position: Point,
 }
 
 impl Map {
-    pub fn move_robot(&mut self, m: Move) {
-        let (x, y) = self.robot_position;
-        let (dx, dy) = m.direction();
-
-        let new_x = x as isize + dx;
-        let new_y = y as isize + dy;
-
-        if new_x < 0 || new_x >= self.map[0].len() as isize {
-            return;
-        }
-        if new_y < 0 || new_y >= self.map.len() as isize {
+    pub fn move_robot(&mut self, m: Direction) {
+        let Some(new) = self.map.step(self.robot_position, m.offset()) else {
             return;
-        }
-
-        let new_x = new_x as usize;
-        let new_y = new_y as usize;
+        };
 
-        match self.map[new_y][new_x] {
+        match self.map[new] {
             Space::Wall => return,
             Space::Box => {
-                let mut new_box_x = new_x as isize;
-                let mut new_box_y = new_y as isize;
-                while self.map[new_box_y as usize][new_box_x as usize] == Space::Box {
-                    new_box_x += dx;
-                    new_box_y += dy;
+                let mut new_box = new;
+                while self.map[new_box] == Space::Box {
+                    let Some(next) = self.map.step(new_box, m.offset()) else {
+                        return;
+                    };
+                    new_box = next;
                 }
 
-                if new_box_x < 0 || new_box_x >= self.map[0].len() as isize {
-                    return;
-                }
-
-                if new_box_y < 0 || new_box_y >= self.map.len() as isize {
-                    return;
-                }
-
-                let new_box_x = new_box_x as usize;
-                let new_box_y = new_box_y as usize;
-
-                if self.map[new_box_y][new_box_x] == Space::Wall {
+                if self.map[new_box] == Space::Wall {
                     return;
                 }
 
-                self.map[new_y][new_x] = Space::Empty;
-                self.map[new_box_y][new_box_x] = Space::Box;
+                self.map[new] = Space::Empty;
+                self.map[new_box] = Space::Box;
             }
             Space::BoxOpen => {
-                if !self.move_big_box(m, (new_x, new_y)) {
+                if !self.move_big_box(m, new) {
                     return;
                 }
             },
             Space::BoxClose => {
-                if !self.move_big_box(m, (new_x - 1, new_y)) {
+                if !self.move_big_box(m, Point::new(new.x - 1, new.y)) {
                     return;
                 }
             }
             Space::Empty => {},
         }
 
-        self.robot_position = (new_x, new_y);
-        debug_assert!(self.map[new_y][new_x] == Space::Empty);
+        self.robot_position = new;
+        debug_assert!(self.map[new] == Space::Empty);
     }
 
     pub fn box_gps_sum(&self) -> usize {
-        self.map.iter().enumerate()
-            .flat_map(|(y, row)| {
-                row.iter().enumerate()
-                    .filter(|(_, space)| matches!(**space, Space::Box | Space::BoxOpen))
-                    .map(move |(x, _)| (x, y))
-            })
-            .map(|(x, y)| x + 100 * y)
+        self.map.iter()
+            .filter(|(_, space)| matches!(**space, Space::Box | Space::BoxOpen))
+            .map(|(p, _)| p.x + 100 * p.y)
             .sum()
     }
 
     pub fn enlarge(&mut self) {
-        let width = self.map[0].len();
-        let height = self.map.len();
-
-        let mut new_map = vec![vec![Space::Empty; width * 2]; height];
-
-        #[allow(clippy::needless_range_loop)]
-        for y in 0..height {
-            for x in 0..width {
-                if self.map[y][x] == Space::Box {
-                    new_map[y][2*x] = Space::BoxOpen;
-                    new_map[y][2*x+1] = Space::BoxClose;
-                } else {
-                    new_map[y][2*x] = self.map[y][x];
-                    new_map[y][2*x+1] = self.map[y][x];
-                }
-            }
-        }
-
-        self.map = new_map;
-        self.robot_position = (2 * self.robot_position.0, self.robot_position.1);
-    }
-
-    #[allow(dead_code)]
-    pub fn print(&self) {
-        for (y, row) in self.map.iter().enumerate() {
-            for (x, &space) in row.iter().enumerate() {
-                if (x, y) == self.robot_position {
-                    print!("@");
-                } else {
-                    match space {
-                        Space::Empty => print!("."),
-                        Space::Wall => print!("#"),
-                        Space::Box => print!("O"),
-                        Space::BoxOpen => print!("["),
-                        Space::BoxClose => print!("]"),
-                    }
-                }
+        self.map = Grid::from_fn(self.map.width() * 2, self.map.height(), |p| {
+            match (self.map[Point::new(p.x / 2, p.y)], p.x % 2) {
+                (Space::Box, 0) => Space::BoxOpen,
+                (Space::Box, _) => Space::BoxClose,
+                (space, _) => space,
             }
-            println!();
-        }
+        });
+        self.robot_position = Point::new(2 * self.robot_position.x, self.robot_position.y);
     }
 
-    fn can_move_big_box(&self, m: Move, (box_x, box_y): (usize, usize)) -> bool {
-        debug_assert!(self.map[box_y][box_x] == Space::BoxOpen);
+    fn can_move_big_box(&self, m: Direction, box_position: Point) -> bool {
+        debug_assert!(self.map[box_position] == Space::BoxOpen);
 
-        let (dx, dy) = m.direction();
-        let new_box_x = (box_x as isize + dx) as usize;
-        let new_box_y = (box_y as isize + dy) as usize;
+        // Boxes are surrounded by walls
+        let new_box = m.step(box_position).unwrap();
+        let right = Point::new(new_box.x + 1, new_box.y);
 
-        let left_side = m == Move::Right || match self.map[new_box_y][new_box_x] {
+        let left_side = m == Direction::East || match self.map[new_box] {
             Space::Wall => false,
             Space::Box => false,
-            Space::BoxOpen => self.can_move_big_box(m, (new_box_x, new_box_y)),
-            Space::BoxClose => self.can_move_big_box(m, (new_box_x - 1, new_box_y)),
+            Space::BoxOpen => self.can_move_big_box(m, new_box),
+            Space::BoxClose => self.can_move_big_box(m, Point::new(new_box.x - 1, new_box.y)),
             Space::Empty => true,
         };
-        let right_side = m == Move::Left || match self.map[new_box_y][new_box_x + 1] {
+        let right_side = m == Direction::West || match self.map[right] {
             Space::Wall => false,
             Space::Box => false,
-            Space::BoxOpen => self.can_move_big_box(m, (new_box_x + 1, new_box_y)),
+            Space::BoxOpen => self.can_move_big_box(m, right),
             Space::BoxClose => true, // Checked on the left side
             Space::Empty => true,
         };
@@ -152,44 +96,43 @@ impl Map {
         left_side && right_side
     }
 
-    fn move_big_box(&mut self, m: Move, (box_x, box_y): (usize, usize)) -> bool {
-        if !self.can_move_big_box(m, (box_x, box_y)) {
+    fn move_big_box(&mut self, m: Direction, box_position: Point) -> bool {
+        if !self.can_move_big_box(m, box_position) {
             return false;
         }
 
-        let (dx, dy) = m.direction();
-        let new_box_x = (box_x as isize + dx) as usize;
-        let new_box_y = (box_y as isize + dy) as usize;
+        let new_box = m.step(box_position).unwrap();
+        let right = Point::new(new_box.x + 1, new_box.y);
 
         // Clear the current box
-        self.map[box_y][box_x] = Space::Empty;
-        self.map[box_y][box_x + 1] = Space::Empty;
+        self.map[box_position] = Space::Empty;
+        self.map[Point::new(box_position.x + 1, box_position.y)] = Space::Empty;
 
         // Move left side
-        match self.map[new_box_y][new_box_x] {
+        match self.map[new_box] {
             Space::Wall => unreachable!(),
             Space::Box => unreachable!(),
             Space::BoxOpen => {
-                self.move_big_box(m, (new_box_x, new_box_y));
+                self.move_big_box(m, new_box);
             },
             Space::BoxClose => {
-                self.move_big_box(m, (new_box_x - 1, new_box_y));
+                self.move_big_box(m, Point::new(new_box.x - 1, new_box.y));
             },
             Space::Empty => {},
         }
-        self.map[new_box_y][new_box_x] = Space::BoxOpen;
+        self.map[new_box] = Space::BoxOpen;
 
         // Move right side
-        match self.map[new_box_y][new_box_x + 1] {
+        match self.map[right] {
             Space::Wall => unreachable!(),
             Space::Box => unreachable!(),
             Space::BoxOpen => {
-                self.move_big_box(m, (new_box_x + 1, new_box_y));
+                self.move_big_box(m, right);
             },
             Space::BoxClose => {}, // Checked on the left side
             Space::Empty => {},
         }
-        self.map[new_box_y][new_box_x + 1] = Space::BoxClose;
+        self.map[right] = Space::BoxClose;
 
         true
     }
@@ -199,24 +142,10 @@ impl FromStr for Map {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut map = Vec::new();
-        let mut robot_position = None;
-
-        for (y, line) in s.lines().enumerate() {
-            let mut row = Vec::new();
-            for (x, c) in line.chars().enumerate() {
-                if c == '@' {
-                    robot_position = Some((x, y));
-                    row.push(Space::Empty);
-                } else {
-                    let space = Space::from_char(c);
-                    row.push(space);
-                }
-            }
-            map.push(row);
-        }
+        let map: Grid<char> = s.parse().map_err(|_| "Invalid map")?;
 
-        let robot_position = robot_position.ok_or("No robot position found")?;
+        let robot_position = map.position(|&c| c == '@').ok_or("No robot position found")?;
+        let map = map.map(|&c| if c == '@' { Space::Empty } else { Space::from_char(c) });
 
         Ok(Map { map, robot_position })
     }
@@ -224,15 +153,16 @@ impl FromStr for Map {
 
 impl Display for Map {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for (y, row) in self.map.iter().enumerate() {
-            for (x, &space) in row.iter().enumerate() {
-                if (x, y) == self.robot_position {
-                    write!(f, "@")?;
-                } else {
-                    write!(f, "{}", space)?;
-                }
+        for (p, space) in self.map.iter() {
+            if p == self.robot_position {
+                write!(f, "@")?;
+            } else {
+                write!(f, "{}", space)?;
+            }
+
+            if p.x == self.map.width() - 1 {
+                writeln!(f)?;
             }
-            writeln!(f)?;
         }
 
         Ok(())
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::collections::HashSet;
+
+use aoc::Solution;
+use grid::{Direction, Heading};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Space {
+    Empty, Wall,
+}
+
+impl Space {
+    fn from_char(c: char) -> Self {
+        match c {
+            '.' => Space::Empty,
+            '#' => Space::Wall,
+            _ => panic!("Invalid space character: {}", c),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    map: Vec<Vec<Space>>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Map {
+    /// Reindeer steps from a position and heading, with the cost of each.
+    fn moves(&self, &((px, py), heading): &((usize, usize), Direction)) -> impl Iterator<Item = (((usize, usize), Direction), u32)> + '_ {
+        [
+            (heading, 0),
+            (heading.turn_left(), 1_000),
+            (heading.turn_right(), 1_000),
+        ].into_iter().filter_map(move |(h, heading_cost)| {
+            let (dx, dy) = h.offset();
+            let (nx, ny) = (px.checked_add_signed(dx)?, py.checked_add_signed(dy)?);
+            if nx >= self.map[0].len() || ny >= self.map.len() || self.map[ny][nx] == Space::Wall {
+                return None;
+            }
+
+            Some((((nx, ny), h), 1 + heading_cost))
+        })
+    }
+
+    fn best_score(&self) -> u32 {
+        search::dijkstra([(self.start, Direction::East)], |n| self.moves(n), |&(p, _)| p == self.end).unwrap().cost
+    }
+
+    fn best_path_tiles(&self) -> HashSet<(usize, usize)> {
+        let paths = search::dijkstra_all([(self.start, Direction::East)], |n| self.moves(n), |&(p, _)| p == self.end).unwrap();
+        paths.nodes().into_iter().map(|(p, _)| p).collect()
+    }
+}
+
+impl FromStr for Map {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Vec::new();
+        let mut start = None;
+        let mut end = None;
+
+        for (y, line) in s.lines().enumerate() {
+            let mut row = Vec::new();
+            for (x, c) in line.chars().enumerate() {
+                if c == 'S' {
+                    start = Some((x, y));
+                    row.push(Space::Empty);
+                } else if c == 'E' {
+                    end = Some((x, y));
+                    row.push(Space::Empty);
+                } else {
+                    let space = Space::from_char(c);
+                    row.push(space);
+                }
+            }
+            map.push(row);
+        }
+
+        Ok(Map {
+            map,
+            start: start.ok_or("No start found")?,
+            end: end.ok_or("No end found")?,
+        })
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Map::from_str(input).unwrap()
+    }
+
+    /// Best score
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.best_score()
+    }
+
+    /// Tiles on any of the best paths
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input.best_path_tiles().len()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn example() {
+        let map = Map::from_str(include_str!("../example.txt")).unwrap();
+
+        assert_eq!(map.best_score(), 7036);
+        assert_eq!(map.best_path_tiles().len(), 45);
+    }
+}
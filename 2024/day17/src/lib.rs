@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use computer::Computer;
+
+mod computer;
+
+
+fn find_a(program: &[u8], index: usize, a: i64) -> Option<i64> {
+    for a_bit in 0..8 {
+        let test_a = a | (a_bit << (3*index));
+        let output = Computer::new([test_a, 0, 0], program.to_vec()).run();
+
+        if output.len() != program.len() {
+            continue;
+        }
+        if output[index] == program[index] as i64 {
+            if index == 0 {
+                return Some(test_a);
+            } else if let Some(answer) = find_a(program, index - 1, test_a) {
+                return Some(answer);
+            }
+        }
+    }
+
+    None
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
+
+    /// Initial registers and the program
+    type Input<'a> = ([i64; 3], Vec<u8>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let (registers, program) = input
+            .split_once("\n\n")
+            .unwrap();
+
+        let registers: Vec<i64> = registers
+            .lines()
+            .map(|l| {
+                let (_, value) = l.split_once(": ").unwrap();
+                value.parse().unwrap()
+            })
+            .collect();
+        let program = program.trim()
+            .trim_start_matches("Program: ")
+            .split(',')
+            .map(|n| n.parse().unwrap())
+            .collect();
+
+        ([registers[0], registers[1], registers[2]], program)
+    }
+
+    /// Program output
+    fn part1((registers, program): &Self::Input<'_>) -> impl Display {
+        let mut computer = Computer::new(*registers, program.clone());
+
+        computer.run()
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    /// Minimal 'a' value to output the program itself
+    fn part2((_, program): &Self::Input<'_>) -> impl Display {
+        find_a(program, program.len() - 1, 0).unwrap()
+    }
+}
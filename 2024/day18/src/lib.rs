@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc::Solution;
+
+
+const WIDTH: u32 = 71;
+const HEIGHT: u32 = 71;
+const PART1_BYTES: usize = 1024;
+
+
+fn min_steps(fallen_bytes: &HashSet<(u32, u32)>) -> Option<u32> {
+    let moves = |&(x, y): &(u32, u32)| {
+        [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)].into_iter()
+            .filter(|&(x, y)| x < WIDTH && y < HEIGHT && !fallen_bytes.contains(&(x, y)))
+    };
+
+    search::bfs([(0, 0)], moves, |&p| p == (WIDTH - 1, HEIGHT - 1))
+        .map(|reached| reached.cost as u32)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<(u32, u32)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|line| {
+                let (x, y) = line.split_once(',').unwrap();
+                let x: u32 = x.parse().unwrap();
+                let y: u32 = y.parse().unwrap();
+
+                (x, y)
+            })
+            .collect()
+    }
+
+    /// Steps to exit
+    fn part1(falling_bytes: &Self::Input<'_>) -> impl Display {
+        let part1_bytes: HashSet<_> = falling_bytes
+            .iter().take(PART1_BYTES)
+            .cloned()
+            .collect();
+
+        min_steps(&part1_bytes).unwrap()
+    }
+
+    /// Byte causing the cutoff
+    fn part2(falling_bytes: &Self::Input<'_>) -> impl Display {
+        // Perform a binary search to find which byte will cut off the path
+        let mut min_bytes = PART1_BYTES;
+        let mut max_bytes = falling_bytes.len();
+        while min_bytes < max_bytes {
+            let mid_bytes = (max_bytes + min_bytes) / 2;
+            let selected_bytes: HashSet<_> = falling_bytes
+                .iter().take(mid_bytes)
+                .cloned()
+                .collect();
+
+            if min_steps(&selected_bytes).is_some() {
+                min_bytes = mid_bytes + 1;
+            } else {
+                max_bytes = mid_bytes;
+            }
+        }
+
+        // Get the byte that causes the cutoff
+        let (x, y) = falling_bytes[min_bytes - 1];
+
+        format!("{x},{y}")
+    }
+}
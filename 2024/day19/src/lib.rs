@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc::Solution;
+use regex::Regex;
+
+
+/// Count the total number of arrangements of a towel
+fn total_arrangements<'a>(towel: &'a str, rules: &HashMap<char, Vec<&str>>, cache: &mut HashMap<&'a str, u64>) -> u64 {
+    if towel.is_empty() {
+        return 1;
+    }
+    if let Some(&cached) = cache.get(towel) {
+        return cached;
+    }
+
+    let Some(patterns) = rules.get(&towel.chars().next().unwrap()) else {
+        return 0;
+    };
+    let mut arrangements = 0;
+
+    for &pattern in patterns {
+        if let Some(remaining_towel) = towel.strip_prefix(pattern) {
+            arrangements += total_arrangements(remaining_towel, rules, cache);
+        }
+    }
+
+    cache.insert(towel, arrangements);
+    arrangements
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+
+    /// Towel patterns and the designs
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let (patterns, designs) = input
+            .split_once("\n\n")
+            .unwrap();
+        let patterns: Vec<_> = patterns.split(", ").collect();
+        let designs: Vec<_> = designs.lines().collect();
+
+        (patterns, designs)
+    }
+
+    /// Possible designs
+    fn part1((patterns, designs): &Self::Input<'_>) -> impl Display {
+        // Build regex
+        let pattern_string = patterns.join("|");
+        let regex = Regex::new(&format!("^({})*$", pattern_string)).unwrap();
+
+        // Count possible designs (number of matches)
+        designs
+            .iter()
+            .filter(|design| regex.is_match(design))
+            .count()
+    }
+
+    /// Total arrangements
+    fn part2((patterns, designs): &Self::Input<'_>) -> impl Display {
+        // Build rules hashmap
+        let mut rules: HashMap<char, Vec<_>> = HashMap::new();
+        for &pattern in patterns {
+            let first_char = pattern.chars().next().unwrap();
+            rules.entry(first_char).or_default().push(pattern);
+        }
+
+        // Count total arrangements of each design
+        let mut memoized = HashMap::new();
+        designs.iter()
+            .map(|design| total_arrangements(design, &rules, &mut memoized))
+            .sum::<u64>()
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc::Solution;
+use keypad::{DirectionalKeypad, Keypad, NumericKeypad};
+
+mod keypad;
+
+
+fn shortest_path_length(
+    cache: &mut HashMap<(Vec<char>, u32, u32), u64>,
+    sequence: &[char], depth: u32, max_depth: u32
+) -> u64 {
+    let key = (sequence.to_vec(), depth, max_depth);
+    if let Some(&length) = cache.get(&key) {
+        return length;
+    }
+
+    let mut current = 'A';
+
+    let mut length = 0;
+    for next in sequence {
+        let move_sequences =
+            if depth == 0 {
+                NumericKeypad::move_sequences(current, *next)
+            } else {
+                DirectionalKeypad::move_sequences(current, *next)
+            };
+
+        if depth >= max_depth {
+            // At the limit, so count the sequence length
+            length += move_sequences.iter()
+                .min_by(|a, b| a.len().cmp(&b.len()))
+                .unwrap()
+                .len() as u64;
+        } else {
+            // Recurse to the next keypad
+            length += move_sequences.iter()
+                .map(|sequence| shortest_path_length(cache, sequence, depth + 1, max_depth))
+                .min().unwrap();
+        }
+        current = *next;
+    }
+
+    cache.insert(key, length);
+    length
+}
+
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 21;
+
+    /// Numeric part and the sequence of every code
+    type Input<'a> = Vec<(u64, Vec<char>)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|line| {
+                let multiplier: u64 = line[..3].parse().unwrap();
+                let sequence: Vec<_> = line.chars().collect();
+                (multiplier, sequence)
+            })
+            .collect()
+    }
+
+    /// Complexity with 2 directional keypads
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let mut cache = HashMap::new();
+        input.iter()
+            .map(|(m, sequence)| *m * shortest_path_length(&mut cache, sequence, 0, 2))
+            .sum::<u64>()
+    }
+
+    /// Complexity with 25 directional keypads
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut cache = HashMap::new();
+        input.iter()
+            .map(|(m, sequence)| *m * shortest_path_length(&mut cache, sequence, 0, 25))
+            .sum::<u64>()
+    }
+}
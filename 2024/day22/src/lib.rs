@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+const SECRET_NUMBERS: u32 = 2000;
+const MAX_KEYS: usize = 19_usize.pow(4);
+
+
+const fn mix(a: u64, b: u64) -> u64 {
+    a ^ b
+}
+
+const fn prune(a: u64) -> u64 {
+    a % 16777216
+}
+
+const fn next_number(secret_number: u64) -> u64 {
+    let mut s = mix(secret_number, secret_number * 64);
+    s = prune(s);
+
+    s = mix(s, s / 32);
+    s = prune(s);
+
+    s = mix(s, s * 2048);
+    s = prune(s);
+
+    s
+}
+
+
+#[inline(always)]
+fn to_int(digits: &[i32]) -> usize {
+    digits.iter()
+        .fold(0, |acc, &digit| acc * 19 + (digit + 9) as usize)
+}
+
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+
+    /// Initial secret numbers of the buyers
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|line| line.parse::<u64>().unwrap())
+            .collect()
+    }
+
+    /// Sum of 2000th secret numbers
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.iter()
+            .map(|&secret_number| (0..SECRET_NUMBERS).fold(secret_number, |s, _| next_number(s)))
+            .sum::<u64>()
+    }
+
+    /// Maximal profit
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        // Keep track of total profit per sequence
+        let mut sequence_profit_map = vec![0; MAX_KEYS];
+        // Keep track of which sequences we have seen in an iteration (as only the first occurrence counts)
+        let mut seen = vec![false; MAX_KEYS];
+        let mut history = Vec::with_capacity(SECRET_NUMBERS as usize);
+
+        for secret_number in input {
+            let mut result = *secret_number;
+            history.clear();
+
+            for _ in 0..SECRET_NUMBERS {
+                let next_result = next_number(result);
+                let price = next_result % 10;
+                let price_diff = price as i32 - (result % 10) as i32;
+
+                history.push(price_diff);
+                if history.len() >= 4 {
+                    let key = to_int(&history[history.len() - 4..]);
+                    let is_seen = unsafe { seen.get_unchecked_mut(key) };
+                    if !*is_seen {
+                        sequence_profit_map[key] += price;
+                        *is_seen = true;
+                    }
+                }
+                result = next_result;
+            }
+
+            // Reset seen array (this way we don't need to create it for every number)
+            for s in seen.iter_mut() {
+                *s = false;
+            }
+        }
+
+        sequence_profit_map.into_iter()
+            .max()
+            .unwrap()
+    }
+}
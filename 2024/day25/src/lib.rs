@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use itertools::iproduct;
+
+
+fn key_fits(key: &[usize; 5], lock: &[usize; 5]) -> bool {
+    key.iter().zip(lock.iter())
+        .all(|(key_column, lock_column)| key_column + lock_column <= 5)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+
+    /// Column heights of the locks and keys
+    type Input<'a> = (Vec<[usize; 5]>, Vec<[usize; 5]>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .split("\n\n")
+            .map(|x| {
+                let grid: Vec<_> = x.lines()
+                    .map(|x| x.chars().collect::<Vec<_>>())
+                    .collect();
+                let is_lock = grid[0][0] == '#';
+                let mut column_heights = [0; 5];
+
+                for row in grid.iter().skip(1).take(5) {
+                    for (x, &cell) in row.iter().enumerate() {
+                        if cell == '#' {
+                            column_heights[x] += 1;
+                        }
+                    }
+                }
+
+                (is_lock, column_heights)
+            })
+            .collect();
+        let locks: Vec<_> = input.iter()
+            .filter(|(is_lock, _)| *is_lock)
+            .map(|(_, column_heights)| *column_heights)
+            .collect();
+        let keys: Vec<_> = input.iter()
+            .filter(|(is_lock, _)| !is_lock)
+            .map(|(_, column_heights)| *column_heights)
+            .collect();
+
+        (locks, keys)
+    }
+
+    /// Key/locks that fit together
+    fn part1((locks, keys): &Self::Input<'_>) -> impl Display {
+        iproduct!(locks.iter(), keys.iter())
+            .filter(|(lock, key)| key_fits(key, lock))
+            .count()
+    }
+}
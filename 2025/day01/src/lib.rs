@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    /// Rotations, to the left being negative
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|s| {
+                let mut num = s[1..].parse::<i32>().unwrap();
+                if &s[..1] == "L" {
+                    num = -num;
+                }
+                num
+            })
+            .collect()
+    }
+
+    /// Password
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let mut position = 50;
+        let mut password = 0;
+        for r in input {
+            position = (position + r).rem_euclid(100);
+
+            if position == 0 {
+                password += 1;
+            }
+        }
+
+        password
+    }
+
+    /// Password using method 0x434C49434B
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut position = 50;
+        let mut password = 0;
+        for r in input {
+            let prev_position = position;
+            position += r % 100;
+
+            let before_mod = position;
+            position = position.rem_euclid(100);
+
+            if prev_position != 0 && (before_mod != position || position == 0) {
+                password += 1;
+            }
+            password += r.abs() / 100;
+        }
+
+        password
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+fn is_invalid_part1_id(id: u64) -> bool {
+    let string_id = id.to_string();
+    if !string_id.len().is_multiple_of(2) {
+        return false;
+    }
+    let m = string_id.len() / 2;
+
+    string_id[..m] == string_id[m..]
+}
+
+fn is_invalid_part2_id(id: u64) -> bool {
+    let string_id = id.to_string();
+    for m in 1..=(string_id.len() / 2) {
+        if !string_id.len().is_multiple_of(m) {
+            continue;
+        }
+
+        // Slow check
+        // if string_id == string_id[..m].repeat(string_id.len() / m) {
+        //     return true;
+        // }
+
+        if (m..string_id.len())
+            .step_by(m)
+            .all(|i| string_id[..m] == string_id[i..i + m])
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    /// Ranges of IDs, inclusive
+    type Input<'a> = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .trim_end()
+            .split(',')
+            .map(|s| {
+                let t = s.split_once('-').unwrap();
+
+                (t.0.parse::<u64>().unwrap(), t.1.parse::<u64>().unwrap())
+            })
+            .collect()
+    }
+
+    /// Invalid ID sum
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter(|&id| is_invalid_part1_id(id))
+            .sum::<u64>()
+    }
+
+    /// Invalid ID sum with any repetitions
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter(|&id| is_invalid_part2_id(id))
+            .sum::<u64>()
+    }
+}
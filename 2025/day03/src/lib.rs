@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+
+fn max_joltage(bank: &[u32], left: u32) -> u64 {
+    if left == 0 {
+        return 0;
+    }
+
+    let mut max = bank[0];
+    let mut max_index = 0;
+    for (i, battery) in bank
+        .iter()
+        .enumerate()
+        .take(bank.len() - (left as usize - 1))
+        .skip(1)
+    {
+        if *battery > max {
+            max = *battery;
+            max_index = i;
+        }
+    }
+    let max = max as u64;
+
+    max * 10_u64.pow(left - 1) + max_joltage(&bank[max_index + 1..], left - 1)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .map(|c| c.to_digit(10).unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Joltage sum
+    fn part1(banks: &Self::Input<'_>) -> impl Display {
+        banks
+            .iter()
+            .map(|b| max_joltage(b, 2))
+            .sum::<u64>()
+    }
+
+    /// Joltage sum with 12 batteries
+    fn part2(banks: &Self::Input<'_>) -> impl Display {
+        banks
+            .iter()
+            .map(|b| max_joltage(b, 12))
+            .sum::<u64>()
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use grid::{Cell, Grid, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Empty,
+    Paper,
+}
+
+impl Cell for Field {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '@' => Some(Self::Paper),
+            _ => None,
+        }
+    }
+}
+
+fn forklift_accessible(grid: &Grid<Field>) -> impl Iterator<Item = Point> + '_ {
+    grid.points().filter(|&p| is_forklift_accessible(grid, p))
+}
+
+fn is_forklift_accessible(grid: &Grid<Field>, p: Point) -> bool {
+    if grid[p] == Field::Empty {
+        return false;
+    }
+
+    let paper_count = grid
+        .neighbors8(p)
+        .filter(|&n| grid[n] == Field::Paper)
+        .count();
+
+    paper_count < 4
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Grid<Field>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    /// Rolls accessible
+    fn part1(grid: &Self::Input<'_>) -> impl Display {
+        forklift_accessible(grid).count()
+    }
+
+    /// Rolls removed
+    fn part2(grid: &Self::Input<'_>) -> impl Display {
+        let mut grid = grid.clone();
+
+        let mut removed = 0;
+        loop {
+            // Find accessible paper rolls, then remove them
+            let accessible: Vec<_> = forklift_accessible(&grid).collect();
+            for &p in &accessible {
+                grid[p] = Field::Empty;
+            }
+
+            removed += accessible.len();
+            if accessible.is_empty() {
+                break;
+            }
+        }
+
+        removed
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn accessible() {
+        let grid: Grid<Field> = include_str!("../example.txt").parse().unwrap();
+        let accessible: Vec<_> = forklift_accessible(&grid).collect();
+
+        assert_eq!(accessible.len(), 13);
+        assert!(accessible.contains(&Point::new(2, 0)));
+    }
+}
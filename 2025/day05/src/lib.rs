@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use interval::IntervalSet;
+
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    /// Fresh ranges and the ingredients
+    type Input<'a> = (IntervalSet<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let (fresh_ranges, ingredients) = input
+            .split_once("\n\n")
+            .unwrap();
+
+        // Parse the fresh ranges, which are inclusive
+        let fresh_ranges = fresh_ranges
+            .lines()
+            .map(|l| {
+                let (start, end) = l.split_once('-').unwrap();
+                start.parse::<u64>().unwrap()..end.parse::<u64>().unwrap() + 1
+            })
+            .collect();
+
+        // Parse ingredient list
+        let ingredients: Vec<_> = ingredients
+            .lines()
+            .map(|l| l.parse::<u64>().unwrap())
+            .collect();
+
+        (fresh_ranges, ingredients)
+    }
+
+    /// Fresh ingredients
+    fn part1((fresh_ranges, ingredients): &Self::Input<'_>) -> impl Display {
+        ingredients
+            .iter()
+            .filter(|i| fresh_ranges.contains(**i))
+            .count()
+    }
+
+    /// Total fresh ids
+    fn part2((fresh_ranges, _): &Self::Input<'_>) -> impl Display {
+        fresh_ranges.total_length()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn inclusive_ranges() {
+        let (fresh_ranges, _) = Day05::parse(include_str!("../example.txt"));
+
+        assert!(fresh_ranges.contains(20));
+        assert!(!fresh_ranges.contains(21));
+        assert_eq!(fresh_ranges.total_length(), 14);
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use itertools::Itertools;
+use union_find::{QuickFindUf, UnionBySize, UnionFind};
+
+const PART1_CONNECTIONS: usize = 1000;
+
+type Position = (i64, i64, i64);
+
+fn distance((x1, y1, z1): Position, (x2, y2, z2): Position) -> f64 {
+    f64::sqrt(((x1 - x2).pow(2) + (y1 - y2).pow(2) + (z1 - z2).pow(2)) as f64)
+}
+
+/// All pairs of boxes by their index, sorted by distance
+fn sorted_pairs(boxes: &[Position]) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<_> = (0..boxes.len()).tuple_combinations().collect();
+    pairs.sort_unstable_by(|&(a1, a2), &(b1, b2)| {
+        distance(boxes[a1], boxes[a2])
+            .partial_cmp(&distance(boxes[b1], boxes[b2]))
+            .unwrap()
+    });
+
+    pairs
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    /// Positions of the junction boxes
+    type Input<'a> = Vec<Position>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                l.split(',')
+                    .map(|n| n.parse().unwrap())
+                    .collect_tuple()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    /// Size product
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        // Create union-find for quick merging
+        let mut uf = QuickFindUf::<UnionBySize>::new(input.len());
+
+        for (a, b) in sorted_pairs(input).into_iter().take(PART1_CONNECTIONS) {
+            uf.union(a, b);
+        }
+
+        // Collect connected components by counting parent occurences
+        let mut cc = vec![0; input.len()];
+        for i in 0..input.len() {
+            let root = uf.find(i);
+            cc[root] += 1;
+        }
+        // Sort descending
+        cc.sort_unstable_by(|a, b| b.cmp(a));
+
+        cc[0] * cc[1] * cc[2]
+    }
+
+    /// Cable length required
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut uf = QuickFindUf::<UnionBySize>::new(input.len());
+
+        // Pairs will create a single fully connected component,
+        // so program is finished before iterator is consumed.
+        let (a, b) = sorted_pairs(input)
+            .into_iter()
+            .find(|&(a, b)| {
+                uf.union(a, b);
+
+                // Check if we have a single connected component
+                let key = uf.find(0);
+                (1..input.len()).all(|i| uf.find(i) == key)
+            })
+            .unwrap();
+
+        input[a].0 * input[b].0
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use geometry::{Point, Polygon, Rectangle};
+use itertools::Itertools;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+
+    /// Red tiles, the vertices of the polygon of red and green tiles
+    type Input<'a> = Polygon;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                let (x, y) = l.split_once(',').unwrap();
+
+                Point::new(x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect()
+    }
+
+    /// Largest rectangle
+    fn part1(red_tiles: &Self::Input<'_>) -> impl Display {
+        red_tiles
+            .vertices()
+            .iter()
+            .tuple_combinations()
+            .map(|(t1, t2)| Rectangle::from_corners(*t1, *t2).lattice_points())
+            .max()
+            .unwrap()
+    }
+
+    /// Largest rectangle inside the polygon
+    fn part2(red_tiles: &Self::Input<'_>) -> impl Display {
+        // Only the red and green tiles, on the edges of the polygon and inside it
+        red_tiles.largest_rectangle().unwrap().lattice_points()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn example() {
+        let red_tiles = Day09::parse(include_str!("../example.txt"));
+
+        assert_eq!(Day09::part1(&red_tiles).to_string(), "50");
+        assert_eq!(Day09::part2(&red_tiles).to_string(), "24");
+    }
+}
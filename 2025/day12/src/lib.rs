@@ -0,0 +1,45 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::Solution;
+
+use crate::{present::Present, region::Region};
+
+mod present;
+mod region;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+
+    /// Shapes of the presents and the regions under the trees
+    type Input<'a> = (Vec<Present>, Vec<Region>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let input: Vec<_> = input
+            .split("\n\n")
+            .collect();
+
+        let presents: Vec<_> = input
+            .iter()
+            .take(input.len() - 1)
+            .map(|i| Present::from_str(i).unwrap())
+            .collect();
+        let regions: Vec<_> = input[input.len() - 1]
+            .lines()
+            .map(|l| Region::from_str(l).unwrap())
+            .collect();
+
+        (presents, regions)
+    }
+
+    /// Regions that fit
+    fn part1((presents, regions): &Self::Input<'_>) -> impl Display {
+        regions
+            .iter()
+            .filter(|r| r.fits_presents(presents))
+            .count()
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, fs, process};
+
+pub use table::Table;
+
+pub mod ocr;
+mod table;
+
+
+/// Solution of a single day, split into parsing the input and solving both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Parsed input, which may borrow from the input text.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    fn part1(input: &Self::Input<'_>) -> impl Display;
+
+    /// Day 25 only has a single part.
+    fn part2(_input: &Self::Input<'_>) -> impl Display {
+        "-"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: String,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub parse: Duration,
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+impl Report {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.time + self.part2.time
+    }
+}
+
+/// Parses the input and solves both parts, timing every step.
+pub fn solve<S: Solution>(input: &str) -> Report {
+    let start = Instant::now();
+    let input = S::parse(input);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let value = S::part1(&input).to_string();
+    let part1 = Answer { value, time: start.elapsed() };
+
+    let start = Instant::now();
+    let value = S::part2(&input).to_string();
+    let part2 = Answer { value, time: start.elapsed() };
+
+    Report { parse, part1, part2 }
+}
+
+/// Type erased [`Solution`], so all days can be listed together.
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str) -> Report,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str) -> Report {
+        (self.solve)(input)
+    }
+
+    /// Default input of the puzzle, `<year>/day<day>/input.txt` in the repository.
+    pub fn input_path(&self) -> PathBuf {
+        root()
+            .join(self.year.to_string())
+            .join(format!("day{:02}", self.day))
+            .join("input.txt")
+    }
+}
+
+/// Root of the repository, containing the directories of each year.
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+/// Reads an input file, or stdin when the path is `-`.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Reads the input of a single day from the path given as first argument, or stdin when it is `-`.
+/// Without an argument, the default input of the day is used.
+/// Arguments starting with `--` are flags of the day itself, and are skipped.
+pub fn input<S: Solution>() -> String {
+    let path = env::args()
+        .skip(1)
+        .find(|a| !a.starts_with("--"))
+        .map_or_else(|| Puzzle::new::<S>().input_path(), PathBuf::from);
+
+    match read_input(&path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not read input {}: {e}", path.display());
+            process::exit(1);
+        },
+    }
+}
+
+/// Entry point of the binary of a single day, see [`input`] for where the input is read from.
+pub fn main<S: Solution>() {
+    run::<S>(&input::<S>());
+}
+
+/// Solves a single day and prints the answers as a table.
+pub fn run<S: Solution>(input: &str) {
+    let puzzle = Puzzle::new::<S>();
+
+    let mut table = Table::new();
+    table.header();
+    table.report(&puzzle, &puzzle.solve(input));
+}
+
+/// Formats a duration with a unit fitting its size.
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+    if seconds < 1e-3 {
+        format!("{:.1} µs", seconds * 1e6)
+    } else if seconds < 1.0 {
+        format!("{:.2} ms", seconds * 1e3)
+    } else {
+        format!("{seconds:.3} s")
+    }
+}
@@ -0,0 +1,156 @@
+/// Letters of the 4x6 font, some letters do not use all columns.
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the 6x10 font.
+const LARGE: &[(char, &str)] = &[
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+/// Reads the pixels of a drawing, in which `#` and `█` are lit.
+pub fn pixels(drawing: &str) -> Vec<Vec<bool>> {
+    drawing.lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '\u{2588}').collect())
+        .collect()
+}
+
+/// Letters drawn in the pixels, or the drawing itself when they can not be recognized.
+pub fn text<R: AsRef<[bool]>>(pixels: &[R]) -> String {
+    recognize(pixels).unwrap_or_else(|| draw(pixels))
+}
+
+/// Draws the pixels with `█` for lit pixels.
+pub fn draw<R: AsRef<[bool]>>(pixels: &[R]) -> String {
+    pixels.iter()
+        .map(|row| row.as_ref().iter().map(|&p| if p { '\u{2588}' } else { ' ' }).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Recognizes the letters drawn with the 4x6 or 6x10 font of advent of code.
+///
+/// Empty rows and columns around the letters are ignored. Letters are cut at a fixed pitch of 5 or 8 columns,
+/// as the letters of the small font are separated by an empty column, except for the 5 columns wide `Y`.
+/// Returns `None` when the height does not match a font, or a letter is not known.
+pub fn recognize<R: AsRef<[bool]>>(pixels: &[R]) -> Option<String> {
+    let lit = |x: isize, y: usize| usize::try_from(x).is_ok_and(|x| pixels[y].as_ref().get(x).copied().unwrap_or(false));
+    let width = pixels.iter().map(|row| row.as_ref().len()).max()? as isize;
+
+    let rows: Vec<_> = (0..pixels.len())
+        .filter(|&y| pixels[y].as_ref().contains(&true))
+        .collect();
+    let (top, bottom) = (*rows.first()?, *rows.last()?);
+    let (font, pitch) = match bottom - top + 1 {
+        6 => (SMALL, 5),
+        10 => (LARGE, 8),
+        _ => return None,
+    };
+
+    let columns: Vec<_> = (0..width).filter(|&x| (top..=bottom).any(|y| lit(x, y))).collect();
+    let (first, last) = (*columns.first()?, *columns.last()?);
+
+    // Letter in the columns from `start`, in the format of the font
+    let letter = |start: isize| {
+        let glyph = (top..=bottom)
+            .map(|y| (start..start + pitch as isize).map(|x| if lit(x, y) { '#' } else { '.' }).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        font.iter()
+            .find(|(_, pattern)| padded(pattern, pitch) == glyph)
+            .map(|&(c, _)| c)
+    };
+
+    // The first letter can start with empty columns, like `I`
+    (0..pitch as isize).find_map(|offset| {
+        (first - offset..=last)
+            .step_by(pitch)
+            .map(letter)
+            .collect()
+    })
+}
+
+/// Pads a letter of the font with empty columns on the right, up to the pitch.
+fn padded(pattern: &str, pitch: usize) -> String {
+    pattern.lines()
+        .map(|l| format!("{l:.<pitch$}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn small() {
+        let drawing = "\
+\u{2588}  \u{2588} \u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}  \u{2588}
+\u{2588}  \u{2588} \u{2588}    \u{2588}  \u{2588} \u{2588}
+\u{2588}\u{2588}\u{2588}\u{2588} \u{2588}\u{2588}\u{2588}  \u{2588}    \u{2588}
+\u{2588}  \u{2588} \u{2588}    \u{2588}    \u{2588}
+\u{2588}  \u{2588} \u{2588}    \u{2588}  \u{2588} \u{2588}
+\u{2588}  \u{2588} \u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}";
+        assert_eq!(recognize(&pixels(drawing)).as_deref(), Some("HECL"));
+
+        // Including letters with empty columns, and a margin
+        let drawing = "\n..###.#...#\n...#..#...#\n...#...#.#.\n...#....#..\n...#....#..\n..###...#..\n";
+        assert_eq!(recognize(&pixels(drawing)).as_deref(), Some("IY"));
+
+        // The `Y` uses all columns up to the next letter
+        let drawing = "\
+#....#...#####
+#....#...##...
+#.....#.#.###.
+#......#..#...
+#......#..#...
+####...#..#...";
+        assert_eq!(recognize(&pixels(drawing)).as_deref(), Some("LYF"));
+    }
+
+    #[test]
+    pub fn large() {
+        let rows: Vec<_> = LARGE[13].1.lines().zip(LARGE[10].1.lines())
+            .map(|(x, n)| format!("{x}..{n}"))
+            .collect();
+        assert_eq!(recognize(&pixels(&rows.join("\n"))).as_deref(), Some("XN"));
+    }
+
+    #[test]
+    pub fn unknown() {
+        assert_eq!(recognize(&pixels("####\n#..#\n####")), None);
+        assert_eq!(recognize(&pixels("")), None);
+        assert_eq!(text(&pixels("#.#\n.#.")), "\u{2588} \u{2588}\n \u{2588} ");
+    }
+}
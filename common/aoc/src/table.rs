@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use crate::{Answer, Puzzle, Report, format_duration};
+
+
+const ANSWER_WIDTH: usize = 24;
+/// Width of the year, day and part columns including separators.
+const INDENT: usize = 17;
+
+/// Prints reports as rows of a table, one row per part.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    solved: usize,
+    failed: usize,
+    total: Duration,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header(&self) {
+        println!("Year  Day  Part  {:<ANSWER_WIDTH$}  {:>10}", "Answer", "Time");
+        println!("{}", "-".repeat(INDENT + ANSWER_WIDTH + 12));
+    }
+
+    pub fn report(&mut self, puzzle: &Puzzle, report: &Report) {
+        self.row(puzzle, 1, &report.part1);
+        self.row(puzzle, 2, &report.part2);
+
+        self.solved += 1;
+        self.total += report.total();
+    }
+
+    /// Prints a row for a puzzle which could not be solved.
+    pub fn failure(&mut self, puzzle: &Puzzle, reason: &str) {
+        println!("{:>4}  {:>3}  {:>4}  {reason}", puzzle.year, puzzle.day, "-");
+        self.failed += 1;
+    }
+
+    /// Prints the number of solved puzzles and their total time, including parsing.
+    pub fn summary(&self) {
+        print!("{} solved in {}", self.solved, format_duration(self.total));
+        if self.failed > 0 {
+            print!(", {} failed", self.failed);
+        }
+        println!();
+    }
+
+    fn row(&self, puzzle: &Puzzle, part: u8, answer: &Answer) {
+        // Answers drawn as letters span multiple lines
+        let mut lines = answer.value.lines();
+        let first = lines.next().unwrap_or_default();
+
+        println!(
+            "{:>4}  {:>3}  {part:>4}  {first:<ANSWER_WIDTH$}  {:>10}",
+            puzzle.year,
+            puzzle.day,
+            format_duration(answer.time),
+        );
+        for line in lines {
+            println!("{:INDENT$}{line}", "");
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+
+use crate::words::{self, BITS, Iter};
+
+
+/// Set of small numbers, stored as the bits of `N` words, which is cheap to copy.
+///
+/// Panics when a number does not fit in the [`BitSet::CAPACITY`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitSet<const N: usize = 1>([u64; N]);
+
+impl<const N: usize> BitSet<N> {
+    /// Numbers below this fit in the set.
+    pub const CAPACITY: usize = N * BITS;
+
+    pub const fn new() -> Self {
+        Self([0; N])
+    }
+
+    pub fn contains(&self, n: usize) -> bool {
+        self.0.get(n / BITS).is_some_and(|word| (word >> (n % BITS)) & 1 == 1)
+    }
+
+    /// Adds the number, and returns whether it was not in the set yet.
+    pub fn insert(&mut self, n: usize) -> bool {
+        let added = !self.contains(n);
+        self.0[n / BITS] |= 1 << (n % BITS);
+        added
+    }
+
+    /// Removes the number, and returns whether it was in the set.
+    pub fn remove(&mut self, n: usize) -> bool {
+        let removed = self.contains(n);
+        self.0[n / BITS] &= !(1 << (n % BITS));
+        removed
+    }
+
+    /// Adds the number when it is not in the set, removes it otherwise.
+    pub fn toggle(&mut self, n: usize) {
+        self.0[n / BITS] ^= 1 << (n % BITS);
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = [0; N];
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.0)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        *self | *other
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        *self & *other
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        *self ^ *other
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        *self - *other
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        words::is_subset(&self.0, &other.0)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        words::is_disjoint(&self.0, &other.0)
+    }
+
+    /// All subsets, from the set itself down to the empty set.
+    pub fn subsets(&self) -> Subsets<N> {
+        Subsets { mask: *self, next: Some(*self) }
+    }
+}
+
+impl<const N: usize> Default for BitSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for BitSet<N> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const N: usize> Extend<usize> for BitSet<N> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for n in iter {
+            self.insert(n);
+        }
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a BitSet<N> {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Combines the sets word by word.
+macro_rules! operator {
+    ($Op:ident::$op:ident, $OpAssign:ident::$op_assign:ident, |$a:ident, $b:ident| $word:expr) => {
+        impl<const N: usize> $OpAssign for BitSet<N> {
+            fn $op_assign(&mut self, rhs: Self) {
+                for (word, &$b) in self.0.iter_mut().zip(&rhs.0) {
+                    let $a = *word;
+                    *word = $word;
+                }
+            }
+        }
+
+        impl<const N: usize> $Op for BitSet<N> {
+            type Output = Self;
+
+            fn $op(mut self, rhs: Self) -> Self::Output {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+    };
+}
+
+operator!(BitOr::bitor, BitOrAssign::bitor_assign, |a, b| a | b);
+operator!(BitAnd::bitand, BitAndAssign::bitand_assign, |a, b| a & b);
+operator!(BitXor::bitxor, BitXorAssign::bitxor_assign, |a, b| a ^ b);
+operator!(Sub::sub, SubAssign::sub_assign, |a, b| a & !b);
+
+/// Subsets of a [`BitSet`], from the largest.
+#[derive(Debug, Clone)]
+pub struct Subsets<const N: usize> {
+    mask: BitSet<N>,
+    next: Option<BitSet<N>>,
+}
+
+impl<const N: usize> Iterator for Subsets<N> {
+    type Item = BitSet<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let subset = self.next?;
+        let mut next = subset;
+        self.next = words::previous_subset(&mut next.0, &self.mask.0).then_some(next);
+        Some(subset)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn insert() {
+        let mut set = BitSet::<2>::new();
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(100));
+        assert!(set.contains(100));
+        assert!(!set.contains(1_000));
+        assert_eq!(set.len(), 2);
+
+        set.toggle(3);
+        set.toggle(64);
+        assert!(set.remove(100));
+        assert!(!set.remove(100));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [64]);
+    }
+
+    #[test]
+    pub fn algebra() {
+        let a: BitSet<2> = [1, 2, 70].into_iter().collect();
+        let b: BitSet<2> = [2, 3, 70, 127].into_iter().collect();
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), [1, 2, 3, 70, 127]);
+        assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [2, 70]);
+        assert_eq!(a.symmetric_difference(&b).iter().collect::<Vec<_>>(), [1, 3, 127]);
+        assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), [1]);
+
+        assert!((a & b).is_subset(&a));
+        assert!(!a.is_subset(&b));
+        assert!(b.is_superset(&(b - a)));
+        assert!((a - b).is_disjoint(&b));
+    }
+
+    #[test]
+    pub fn subsets() {
+        let set: BitSet<2> = [1, 63, 64].into_iter().collect();
+        let subsets: Vec<Vec<usize>> = set.subsets().map(|s| s.iter().collect()).collect();
+        assert_eq!(subsets, [
+            vec![1, 63, 64], vec![63, 64], vec![1, 64], vec![64],
+            vec![1, 63], vec![63], vec![1], vec![],
+        ]);
+        assert_eq!(BitSet::<1>::new().subsets().count(), 1);
+    }
+}
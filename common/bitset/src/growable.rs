@@ -0,0 +1,253 @@
+use std::iter;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+
+use crate::words::{self, BITS, Iter};
+
+
+/// Set of numbers like [`BitSet`](crate::BitSet), of which the words grow to fit the largest number.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GrowableBitSet {
+    /// Words without trailing zero words, so equal sets have equal words
+    words: Vec<u64>,
+}
+
+impl GrowableBitSet {
+    pub const fn new() -> Self {
+        Self { words: Vec::new() }
+    }
+
+    /// Empty set which fits the numbers below the capacity without growing.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { words: Vec::with_capacity(capacity.div_ceil(BITS)) }
+    }
+
+    pub fn contains(&self, n: usize) -> bool {
+        self.words.get(n / BITS).is_some_and(|word| (word >> (n % BITS)) & 1 == 1)
+    }
+
+    /// Adds the number, and returns whether it was not in the set yet.
+    pub fn insert(&mut self, n: usize) -> bool {
+        let added = !self.contains(n);
+        self.grow(n);
+        self.words[n / BITS] |= 1 << (n % BITS);
+        added
+    }
+
+    /// Removes the number, and returns whether it was in the set.
+    pub fn remove(&mut self, n: usize) -> bool {
+        let removed = self.contains(n);
+        if removed {
+            self.words[n / BITS] &= !(1 << (n % BITS));
+            self.trim();
+        }
+
+        removed
+    }
+
+    /// Adds the number when it is not in the set, removes it otherwise.
+    pub fn toggle(&mut self, n: usize) {
+        self.grow(n);
+        self.words[n / BITS] ^= 1 << (n % BITS);
+        self.trim();
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.words)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self & other
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        words::is_subset(&self.words, &other.words)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        words::is_disjoint(&self.words, &other.words)
+    }
+
+    /// All subsets, from the set itself down to the empty set.
+    pub fn subsets(&self) -> GrowableSubsets<'_> {
+        GrowableSubsets { mask: &self.words, next: Some(self.words.clone()) }
+    }
+
+    /// Adds words until the number fits.
+    fn grow(&mut self, n: usize) {
+        if self.words.len() <= n / BITS {
+            self.words.resize(n / BITS + 1, 0);
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+impl FromIterator<usize> for GrowableBitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for GrowableBitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for n in iter {
+            self.insert(n);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a GrowableBitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Combines the sets word by word, where the shorter set has zero words at the end.
+macro_rules! operator {
+    ($Op:ident::$op:ident, $OpAssign:ident::$op_assign:ident, |$a:ident, $b:ident| $word:expr) => {
+        impl $OpAssign<&GrowableBitSet> for GrowableBitSet {
+            fn $op_assign(&mut self, rhs: &GrowableBitSet) {
+                if self.words.len() < rhs.words.len() {
+                    self.words.resize(rhs.words.len(), 0);
+                }
+
+                for (word, &$b) in self.words.iter_mut().zip(rhs.words.iter().chain(iter::repeat(&0))) {
+                    let $a = *word;
+                    *word = $word;
+                }
+
+                self.trim();
+            }
+        }
+
+        impl $Op for &GrowableBitSet {
+            type Output = GrowableBitSet;
+
+            fn $op(self, rhs: Self) -> Self::Output {
+                let mut set = self.clone();
+                set.$op_assign(rhs);
+                set
+            }
+        }
+    };
+}
+
+operator!(BitOr::bitor, BitOrAssign::bitor_assign, |a, b| a | b);
+operator!(BitAnd::bitand, BitAndAssign::bitand_assign, |a, b| a & b);
+operator!(BitXor::bitxor, BitXorAssign::bitxor_assign, |a, b| a ^ b);
+operator!(Sub::sub, SubAssign::sub_assign, |a, b| a & !b);
+
+/// Subsets of a [`GrowableBitSet`], from the largest.
+#[derive(Debug, Clone)]
+pub struct GrowableSubsets<'a> {
+    mask: &'a [u64],
+    /// Words of the next subset, as many as the mask
+    next: Option<Vec<u64>>,
+}
+
+impl Iterator for GrowableSubsets<'_> {
+    type Item = GrowableBitSet;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+        self.next = words::previous_subset(&mut next, self.mask).then_some(next);
+
+        let mut subset = GrowableBitSet { words: current };
+        subset.trim();
+        Some(subset)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::hash::{BuildHasher, RandomState};
+
+    use super::*;
+
+    #[test]
+    pub fn grow() {
+        let mut set = GrowableBitSet::new();
+        assert!(set.insert(1_000));
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(1_000));
+        assert!(!set.contains(1_000));
+
+        // Shrinks back, so the sets are equal and hash the same
+        let other = GrowableBitSet::from_iter([3]);
+        assert_eq!(set, other);
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(&set), state.hash_one(&other));
+
+        set.toggle(3);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    pub fn algebra() {
+        let a = GrowableBitSet::from_iter([1, 2, 200]);
+        let b = GrowableBitSet::from_iter([2, 3, 200, 300]);
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), [1, 2, 3, 200, 300]);
+        assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [2, 200]);
+        assert_eq!(a.symmetric_difference(&b).iter().collect::<Vec<_>>(), [1, 3, 300]);
+        assert_eq!(b.difference(&a), GrowableBitSet::from_iter([3, 300]));
+        assert_eq!(&a - &GrowableBitSet::from_iter([200]), GrowableBitSet::from_iter([1, 2]));
+
+        assert!(a.intersection(&b).is_subset(&a));
+        assert!(!b.is_subset(&a));
+        assert!(b.is_superset(&(&b - &a)));
+        assert!((&a - &b).is_disjoint(&b));
+    }
+
+    #[test]
+    pub fn subsets() {
+        let set = GrowableBitSet::from_iter([0, 150]);
+        let subsets: Vec<_> = set.subsets().collect();
+        assert_eq!(subsets, [
+            set.clone(),
+            GrowableBitSet::from_iter([150]),
+            GrowableBitSet::from_iter([0]),
+            GrowableBitSet::new(),
+        ]);
+    }
+}
@@ -0,0 +1,7 @@
+pub use fixed::{BitSet, Subsets};
+pub use growable::{GrowableBitSet, GrowableSubsets};
+pub use words::Iter;
+
+mod fixed;
+mod growable;
+mod words;
@@ -0,0 +1,58 @@
+/// Bits in a word of a set.
+pub(crate) const BITS: usize = u64::BITS as usize;
+
+/// Numbers in a set, from the lowest.
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    words: &'a [u64],
+    index: usize,
+    /// Bits of the current word which were not returned yet
+    word: u64,
+}
+
+impl<'a> Iter<'a> {
+    pub(crate) fn new(words: &'a [u64]) -> Self {
+        Self { words, index: 0, word: words.first().copied().unwrap_or(0) }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            self.index += 1;
+            self.word = *self.words.get(self.index)?;
+        }
+
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.index * BITS + bit)
+    }
+}
+
+/// Steps to the next lower subset of the mask, or returns false when the subset was already empty.
+pub(crate) fn previous_subset(subset: &mut [u64], mask: &[u64]) -> bool {
+    // Subtracting one borrows from the lowest set bit
+    let Some(lowest) = subset.iter().position(|&word| word != 0) else {
+        return false;
+    };
+
+    subset[..lowest].fill(u64::MAX);
+    subset[lowest] -= 1;
+    for (word, mask) in subset.iter_mut().zip(mask) {
+        *word &= mask;
+    }
+
+    true
+}
+
+pub(crate) fn is_subset(words: &[u64], other: &[u64]) -> bool {
+    words.iter()
+        .enumerate()
+        .all(|(i, word)| word & !other.get(i).copied().unwrap_or(0) == 0)
+}
+
+pub(crate) fn is_disjoint(words: &[u64], other: &[u64]) -> bool {
+    words.iter().zip(other).all(|(a, b)| a & b == 0)
+}
@@ -0,0 +1,103 @@
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+
+
+/// Cycle in the states of a simulation, which repeat forever once it started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before the first state of the cycle.
+    pub start: usize,
+    /// Number of steps after which a state of the cycle repeats.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Finds the cycle with Brent's algorithm, which only keeps two states around.
+    ///
+    /// Never returns when the states do not repeat.
+    pub fn brent<S: Clone + Eq>(initial: &S, mut step: impl FnMut(&mut S)) -> Self {
+        // Move the tortoise to the hare at every power of two, until the hare meets it
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = initial.clone();
+        let mut hare = initial.clone();
+        step(&mut hare);
+        while tortoise != hare {
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+
+            step(&mut hare);
+            length += 1;
+        }
+
+        // Walk both from the start, a length apart, until they meet at the start of the cycle
+        let mut tortoise = initial.clone();
+        let mut hare = initial.clone();
+        for _ in 0..length {
+            step(&mut hare);
+        }
+
+        let mut start = 0;
+        while tortoise != hare {
+            step(&mut tortoise);
+            step(&mut hare);
+            start += 1;
+        }
+
+        Self { start, length }
+    }
+
+    /// Finds the cycle by remembering a key of every state, until a key repeats.
+    ///
+    /// The key can be the state itself, or a part of it which decides the next states.
+    pub fn hashed<S: Clone, K: Eq + Hash>(
+        initial: &S,
+        mut step: impl FnMut(&mut S),
+        mut key: impl FnMut(&S) -> K,
+    ) -> Self {
+        let mut seen = HashMap::new();
+        let mut state = initial.clone();
+        for steps in 0.. {
+            if let Some(start) = seen.insert(key(&state), steps) {
+                return Self { start, length: steps - start };
+            }
+
+            step(&mut state);
+        }
+
+        unreachable!()
+    }
+
+    /// Lowest number of steps which leads to the same state as `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn find() {
+        // 1, 2, 4, 8, 16, 32, 64, 28, 56, 12, 24, 48, 96, 92, 84, 68, 36, 72, 44, 88, 76, 52, 4, ...
+        let double = |n: &mut u32| *n = *n * 2 % 100;
+        let cycle = Cycle { start: 2, length: 20 };
+        assert_eq!(Cycle::brent(&1, double), cycle);
+        assert_eq!(Cycle::hashed(&1, double, |&n| n), cycle);
+        assert_eq!(Cycle::hashed(&(0, 1), |(i, n)| (*i, *n) = (*i + 1, *n * 2 % 100), |&(_, n)| n), cycle);
+
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(22), 2);
+        assert_eq!(cycle.reduce(1_000), 20);
+    }
+}
@@ -0,0 +1,104 @@
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use hashbrown::HashMap;
+
+
+/// State after `n` steps, which stops simulating once the states repeat.
+///
+/// Looks for the cycle with Brent's algorithm like [`Cycle::brent`](crate::Cycle::brent), while the hare counts the steps,
+/// so states which never repeat are simulated up to step `n`.
+pub fn state_at<S: Clone + Eq>(initial: S, mut step: impl FnMut(&mut S), n: usize) -> S {
+    let mut power = 1;
+    let mut length = 0;
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for steps in 1..=n {
+        step(&mut hare);
+        length += 1;
+
+        if tortoise == hare {
+            // The states repeat every length steps from the tortoise on, which the hare is past
+            for _ in 0..(n - steps) % length {
+                step(&mut hare);
+            }
+            return hare;
+        }
+
+        // Move the tortoise to the hare at every power of two
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+    }
+
+    hare
+}
+
+/// Metric of the state after `n` steps, like the height of a tower which keeps growing.
+///
+/// The key decides the next states, like for [`Cycle::hashed`](crate::Cycle::hashed). Once it repeats, the metric
+/// is assumed to grow by the same amount every cycle, which is zero when it only depends on the key.
+pub fn extrapolate<S, K, M>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+    n: usize,
+) -> M
+where
+    K: Eq + Hash,
+    M: Copy + Add<Output = M> + Sub<Output = M> + Mul<Output = M> + TryFrom<usize>,
+{
+    let mut seen = HashMap::new();
+    let mut metrics = Vec::new();
+    for steps in 0..n {
+        metrics.push(metric(&state));
+        if let Some(start) = seen.insert(key(&state), steps) {
+            let length = steps - start;
+            let growth = metrics[steps] - metrics[start];
+            let Ok(cycles) = M::try_from((n - start) / length) else {
+                panic!("Too many cycles for the metric");
+            };
+
+            return metrics[start + (n - start) % length] + growth * cycles;
+        }
+
+        step(&mut state);
+    }
+
+    metric(&state)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn state() {
+        let double = |n: &mut u32| *n = *n * 2 % 100;
+        assert_eq!(state_at(1, double, 5), 32);
+        assert_eq!(state_at(1, double, 1_000), 76);
+        let mut simulated = 1;
+        for n in 0..100 {
+            assert_eq!(state_at(1, double, n), simulated);
+            double(&mut simulated);
+        }
+
+        // Never repeats, so it simulates only the steps which are asked for
+        let count = |n: &mut u64| *n += 1;
+        assert_eq!(state_at(0, count, 3), 3);
+        assert_eq!(state_at(1, double, 0), 1);
+    }
+
+    #[test]
+    pub fn metric() {
+        // Counts up, while the key only cycles through the remainders
+        let count = |n: &mut u64| *n += 3;
+        assert_eq!(extrapolate(0, count, |&n| n % 7, |&n| n, 5), 15);
+        assert_eq!(extrapolate(0, count, |&n| n % 7, |&n| n, 1_000_000_000_000), 3_000_000_000_000);
+        assert_eq!(extrapolate(0, count, |&n| n % 7, |&n| n % 7, 1_000_000), 3_000_000 % 7);
+    }
+}
@@ -0,0 +1,5 @@
+pub use cycle::Cycle;
+pub use extrapolate::{extrapolate, state_at};
+
+mod cycle;
+mod extrapolate;
@@ -0,0 +1,9 @@
+pub use point::Point;
+pub use polygon::Polygon;
+pub use rectangle::Rectangle;
+pub use segment::Segment;
+
+mod point;
+mod polygon;
+mod rectangle;
+mod segment;
@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+
+use crate::{Point, Rectangle, Segment};
+use crate::rectangle::Coverage;
+
+
+/// Simple polygon through its vertices, of which the last connects back to the first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub const fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Edges between the vertices, ending with the one back to the first vertex.
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().zip(next).map(|(&from, &to)| Segment::new(from, to))
+    }
+
+    /// Twice the area with the shoelace formula, which is positive when the vertices go counter-clockwise
+    /// with `y` growing upwards.
+    pub fn double_area(&self) -> i64 {
+        self.edges().map(|e| e.from.cross(e.to)).sum()
+    }
+
+    /// Number of points with integer coordinates on the edges.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges().map(|e| math::gcd(e.to.x - e.from.x, e.to.y - e.from.y)).sum()
+    }
+
+    /// Number of points with integer coordinates strictly inside, with Pick's theorem.
+    pub fn interior_points(&self) -> i64 {
+        // A = I + B / 2 - 1
+        (self.double_area().abs() - self.boundary_points() + 2) / 2
+    }
+
+    /// Number of points with integer coordinates inside or on the edges.
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Number of times the edges go counter-clockwise around the point, with `y` growing upwards.
+    ///
+    /// Zero for points outside, and not meaningful for points on the edges.
+    pub fn winding_number(&self, point: Point) -> i64 {
+        self.edges()
+            .map(|e| {
+                let side = point.orientation(e.from, e.to);
+                if e.from.y <= point.y && e.to.y > point.y && side == Ordering::Greater {
+                    1
+                } else if e.from.y > point.y && e.to.y <= point.y && side == Ordering::Less {
+                    -1
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    /// Whether the point is inside or on the edges.
+    pub fn contains(&self, point: Point) -> bool {
+        self.edges().any(|e| e.contains(point)) || self.winding_number(point) != 0
+    }
+
+    /// Largest rectangle with two opposite corners on vertices which is inside or on the edges, by the
+    /// number of points with integer coordinates it covers.
+    ///
+    /// Panics when an edge is not horizontal or vertical.
+    pub fn largest_rectangle(&self) -> Option<Rectangle> {
+        let coverage = Coverage::new(self);
+
+        let mut largest: Option<Rectangle> = None;
+        for (i, &a) in self.vertices.iter().enumerate() {
+            for &b in &self.vertices[i + 1..] {
+                let rectangle = Rectangle::from_corners(a, b);
+                if largest.is_none_or(|l| l.lattice_points() < rectangle.lattice_points())
+                    && coverage.contains(&rectangle)
+                {
+                    largest = Some(rectangle);
+                }
+            }
+        }
+
+        largest
+    }
+}
+
+impl FromIterator<Point> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// U shape, open at the top
+    fn u_shape() -> Polygon {
+        [(0, 0), (6, 0), (6, 4), (4, 4), (4, 2), (2, 2), (2, 4), (0, 4)].into_iter()
+            .map(Point::from)
+            .collect()
+    }
+
+    #[test]
+    pub fn area() {
+        let polygon = u_shape();
+        assert_eq!(polygon.double_area(), 2 * 20);
+        assert_eq!(polygon.boundary_points(), 24);
+        assert_eq!(polygon.interior_points(), 9);
+        assert_eq!(polygon.lattice_points(), 33);
+
+        // Triangle going clockwise, with diagonal edges
+        let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(0, 3), Point::new(3, 0)]);
+        assert_eq!(triangle.double_area(), -9);
+        assert_eq!(triangle.boundary_points(), 9);
+        assert_eq!(triangle.interior_points(), 1);
+    }
+
+    #[test]
+    pub fn containment() {
+        let polygon = u_shape();
+        assert_eq!(polygon.winding_number(Point::new(1, 1)), 1);
+        assert!(polygon.contains(Point::new(1, 3)));
+        assert!(polygon.contains(Point::new(3, 2)));
+        assert!(!polygon.contains(Point::new(3, 3)));
+        assert!(!polygon.contains(Point::new(7, 0)));
+
+        let reversed = Polygon::new(polygon.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.winding_number(Point::new(5, 3)), -1);
+        assert_eq!(reversed.winding_number(Point::new(3, 3)), 0);
+    }
+
+    #[test]
+    pub fn largest_rectangle() {
+        // Every corner of the bounding box is a vertex, but the middle of its top edge is outside
+        let largest = u_shape().largest_rectangle().unwrap();
+        assert_eq!(largest, Rectangle::from_corners(Point::new(4, 2), Point::new(0, 0)));
+        assert_eq!(largest.lattice_points(), 15);
+
+        let square = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)]);
+        assert_eq!(square.largest_rectangle().unwrap().lattice_points(), 25);
+    }
+}
@@ -0,0 +1,129 @@
+use interval::IntervalSet;
+
+use crate::{Point, Polygon, Segment};
+
+
+/// Axis-aligned rectangle, including its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rectangle {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rectangle {
+    /// Rectangle with any two opposite corners.
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        Self {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// Corners, counter-clockwise from the minimum with `y` growing upwards.
+    pub const fn corners(&self) -> [Point; 4] {
+        [self.min, Point::new(self.max.x, self.min.y), self.max, Point::new(self.min.x, self.max.y)]
+    }
+
+    pub const fn contains(&self, point: Point) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x && self.min.y <= point.y && point.y <= self.max.y
+    }
+
+    /// Number of points with integer coordinates inside or on the edges.
+    pub const fn lattice_points(&self) -> u64 {
+        (self.max.x.abs_diff(self.min.x) + 1) * (self.max.y.abs_diff(self.min.y) + 1)
+    }
+}
+
+/// Inside of a polygon with only horizontal and vertical edges, on a grid compressed to the coordinates of
+/// its vertices.
+///
+/// The cells alternate between the lines through the vertices and the gaps between them, so every cell is
+/// completely inside or completely outside.
+#[derive(Debug, Clone)]
+pub(crate) struct Coverage {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    width: usize,
+    /// Number of cells outside above and to the left of every cell, after a row and column of zeros
+    outside: Vec<u32>,
+}
+
+impl Coverage {
+    pub(crate) fn new(polygon: &Polygon) -> Self {
+        assert!(
+            polygon.edges().all(|e| e.from.x == e.to.x || e.from.y == e.to.y),
+            "Edge is not horizontal or vertical",
+        );
+
+        let coordinates = |axis: fn(&Point) -> i64| {
+            let mut coordinates: Vec<_> = polygon.vertices().iter().map(axis).collect();
+            coordinates.sort_unstable();
+            coordinates.dedup();
+            coordinates
+        };
+        let (xs, ys) = (coordinates(|p| p.x), coordinates(|p| p.y));
+
+        // Doubled coordinates of a point in every cell, which puts the one of a gap in its middle
+        let cells = |coordinates: &[i64]| -> Vec<i64> {
+            (0..(2 * coordinates.len()).saturating_sub(1))
+                .map(|i| if i % 2 == 0 { 2 * coordinates[i / 2] } else { coordinates[i / 2] + coordinates[i / 2 + 1] })
+                .collect()
+        };
+        let (cell_xs, cell_ys) = (cells(&xs), cells(&ys));
+        let edges: Vec<_> = polygon.edges().map(|e| Segment::new(e.from * 2, e.to * 2)).collect();
+
+        let width = cell_xs.len() + 1;
+        let mut outside = vec![0; width * (cell_ys.len() + 1)];
+        for (j, &y) in cell_ys.iter().enumerate() {
+            // Edges on the row, and the vertical edges it crosses
+            let mut boundary = IntervalSet::new();
+            let mut crossings = Vec::new();
+            for e in &edges {
+                if e.from.y.min(e.to.y) <= y && y <= e.from.y.max(e.to.y) {
+                    boundary.insert(e.from.x.min(e.to.x)..e.from.x.max(e.to.x) + 1);
+                }
+                if (e.from.y > y) != (e.to.y > y) {
+                    crossings.push(e.from.x);
+                }
+            }
+            crossings.sort_unstable();
+
+            for (i, &x) in cell_xs.iter().enumerate() {
+                let inside = boundary.contains(x) || crossings.partition_point(|&c| c < x) % 2 == 1;
+                outside[(j + 1) * width + i + 1] = outside[j * width + i + 1] + outside[(j + 1) * width + i]
+                    - outside[j * width + i] + u32::from(!inside);
+            }
+        }
+
+        Self { xs, ys, width, outside }
+    }
+
+    /// Whether the rectangle is inside, which has to have the coordinates of vertices.
+    pub(crate) fn contains(&self, rectangle: &Rectangle) -> bool {
+        let cell = |coordinates: &[i64], c: i64| {
+            2 * coordinates.binary_search(&c).expect("Coordinate is not on a vertex")
+        };
+        let (x1, x2) = (cell(&self.xs, rectangle.min.x), cell(&self.xs, rectangle.max.x) + 1);
+        let (y1, y2) = (cell(&self.ys, rectangle.min.y), cell(&self.ys, rectangle.max.y) + 1);
+
+        let at = |x: usize, y: usize| self.outside[y * self.width + x];
+        at(x2, y2) + at(x1, y1) == at(x2, y1) + at(x1, y2)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn rectangle() {
+        let rectangle = Rectangle::from_corners(Point::new(2, 5), Point::new(-1, 3));
+        assert_eq!(rectangle.min, Point::new(-1, 3));
+        assert_eq!(rectangle.max, Point::new(2, 5));
+        assert_eq!(rectangle.lattice_points(), 12);
+        assert_eq!(rectangle.corners()[1], Point::new(2, 3));
+        assert!(rectangle.contains(Point::new(2, 4)));
+        assert!(!rectangle.contains(Point::new(3, 4)));
+    }
+}
@@ -0,0 +1,72 @@
+use std::cmp::Ordering;
+
+use crate::Point;
+
+
+/// Line segment between two points, including both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub from: Point,
+    pub to: Point,
+}
+
+impl Segment {
+    pub const fn new(from: Point, to: Point) -> Self {
+        Self { from, to }
+    }
+
+    /// Whether the point is on the segment, including its ends.
+    pub fn contains(&self, point: Point) -> bool {
+        point.orientation(self.from, self.to) == Ordering::Equal
+            && self.from.x.min(self.to.x) <= point.x && point.x <= self.from.x.max(self.to.x)
+            && self.from.y.min(self.to.y) <= point.y && point.y <= self.from.y.max(self.to.y)
+    }
+
+    /// Whether the segments have a point in common, which includes touching and overlapping.
+    pub fn intersects(&self, other: &Self) -> bool {
+        if self.crosses(other) {
+            return true;
+        }
+
+        self.contains(other.from) || self.contains(other.to) || other.contains(self.from) || other.contains(self.to)
+    }
+
+    /// Whether the segments cross in a single point which is not an end of either.
+    pub fn crosses(&self, other: &Self) -> bool {
+        let opposite = |a: Ordering, b: Ordering| a != Ordering::Equal && a == b.reverse();
+
+        opposite(other.from.orientation(self.from, self.to), other.to.orientation(self.from, self.to))
+            && opposite(self.from.orientation(other.from, other.to), self.to.orientation(other.from, other.to))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn segment(from: (i64, i64), to: (i64, i64)) -> Segment {
+        Segment::new(from.into(), to.into())
+    }
+
+    #[test]
+    pub fn intersection() {
+        let a = segment((0, 0), (4, 4));
+        assert!(a.crosses(&segment((0, 4), (4, 0))));
+        assert!(a.contains(Point::new(3, 3)));
+        assert!(!a.contains(Point::new(5, 5)));
+
+        // Touching at an end
+        let b = segment((2, 2), (5, 0));
+        assert!(!a.crosses(&b));
+        assert!(a.intersects(&b));
+
+        // Overlapping on the same line
+        let c = segment((3, 3), (6, 6));
+        assert!(!a.crosses(&c));
+        assert!(a.intersects(&c));
+
+        assert!(!a.intersects(&segment((5, 5), (6, 6))));
+        assert!(!a.intersects(&segment((1, 0), (4, 3))));
+    }
+}
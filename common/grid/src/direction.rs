@@ -0,0 +1,275 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid::Cell;
+use crate::point::Point;
+
+
+/// Heading which turns in fixed steps, like the directions on a grid.
+///
+/// Offsets are in `(x, y)`, with `y` growing downwards like [`Point`].
+pub trait Heading: Copy + Eq + 'static {
+    /// All headings, clockwise.
+    const ALL: &'static [Self];
+
+    /// Position in [`Heading::ALL`].
+    fn index(self) -> usize;
+
+    /// Offset of a single step in this heading.
+    fn offset(self) -> (isize, isize);
+
+    /// Turns the number of headings clockwise, or counterclockwise when negative.
+    fn turn(self, steps: isize) -> Self {
+        let count = Self::ALL.len() as isize;
+        Self::ALL[(self.index() as isize + steps).rem_euclid(count) as usize]
+    }
+
+    /// Next heading clockwise.
+    fn turn_right(self) -> Self {
+        self.turn(1)
+    }
+
+    /// Next heading counterclockwise.
+    fn turn_left(self) -> Self {
+        self.turn(-1)
+    }
+
+    fn reverse(self) -> Self {
+        self.turn(Self::ALL.len() as isize / 2)
+    }
+
+    /// Turns by the angle in degrees, clockwise when positive.
+    ///
+    /// Panics when the angle is not a multiple of the angle between two headings.
+    fn rotate(self, degrees: i32) -> Self {
+        let step = 360 / Self::ALL.len() as i32;
+        assert!(degrees % step == 0, "Can not turn {degrees} degrees in steps of {step} degrees");
+        self.turn((degrees / step) as isize)
+    }
+
+    /// Steps from the point, or `None` when that would go below zero.
+    fn step(self, p: Point) -> Option<Point> {
+        p.checked_offset(self.offset())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseHeadingError(pub String);
+
+impl fmt::Display for ParseHeadingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid heading {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseHeadingError {}
+
+/// Horizontal and vertical directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North, East, South, West,
+}
+
+impl Direction {
+    /// Parses the arrows `^v<>`, the compass `NESW`, the letters `UDLR`,
+    /// or the digits `0` to `3` in the order right, down, left, up.
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' | 'U' | '3' => Some(Self::North),
+            '>' | 'E' | 'R' | '0' => Some(Self::East),
+            'v' | 'S' | 'D' | '1' => Some(Self::South),
+            '<' | 'W' | 'L' | '2' => Some(Self::West),
+            _ => None,
+        }
+    }
+
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Self::East | Self::West)
+    }
+
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Self::North | Self::South)
+    }
+}
+
+impl Heading for Direction {
+    const ALL: &'static [Self] = &[Self::North, Self::East, Self::South, Self::West];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::East  => (1, 0),
+            Self::South => (0, 1),
+            Self::West  => (-1, 0),
+        }
+    }
+}
+
+impl Cell for Direction {
+    fn from_char(c: char) -> Option<Self> {
+        Direction::from_char(c)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseHeadingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Self::from_char), chars.next()) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err(ParseHeadingError(s.to_owned())),
+        }
+    }
+}
+
+/// All directions including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
+}
+
+impl Heading for Direction8 {
+    const ALL: &'static [Self] = &[
+        Self::North, Self::NorthEast, Self::East, Self::SouthEast,
+        Self::South, Self::SouthWest, Self::West, Self::NorthWest,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Self::North     => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East      => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South     => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West      => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[2 * direction.index()]
+    }
+}
+
+/// Parses the compass directions, like `N` or `SW`.
+impl FromStr for Direction8 {
+    type Err = ParseHeadingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N"  => Ok(Self::North),
+            "NE" => Ok(Self::NorthEast),
+            "E"  => Ok(Self::East),
+            "SE" => Ok(Self::SouthEast),
+            "S"  => Ok(Self::South),
+            "SW" => Ok(Self::SouthWest),
+            "W"  => Ok(Self::West),
+            "NW" => Ok(Self::NorthWest),
+            _ => Err(ParseHeadingError(s.to_owned())),
+        }
+    }
+}
+
+/// Directions on a grid of hexagons with pointy tops.
+///
+/// The offsets are in axial coordinates: `x` grows to the east and `y` to the south east.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HexDirection {
+    East, SouthEast, SouthWest, West, NorthWest, NorthEast,
+}
+
+impl HexDirection {
+    /// Splits the direction at the start of the string, like `se` in `sesw`, from the rest.
+    pub fn split_first(s: &str) -> Option<(Self, &str)> {
+        let length = if s.starts_with(['n', 's']) { 2 } else { 1 };
+        let direction = s.get(..length)?.parse().ok()?;
+        Some((direction, &s[length..]))
+    }
+}
+
+impl Heading for HexDirection {
+    const ALL: &'static [Self] = &[
+        Self::East, Self::SouthEast, Self::SouthWest,
+        Self::West, Self::NorthWest, Self::NorthEast,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Self::East      => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::SouthWest => (0, 1),
+            Self::West      => (-1, 0),
+            Self::NorthWest => (-1, -1),
+            Self::NorthEast => (0, -1),
+        }
+    }
+}
+
+/// Parses the lowercase directions, like `e` or `nw`.
+impl FromStr for HexDirection {
+    type Err = ParseHeadingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e"  => Ok(Self::East),
+            "se" => Ok(Self::SouthEast),
+            "sw" => Ok(Self::SouthWest),
+            "w"  => Ok(Self::West),
+            "nw" => Ok(Self::NorthWest),
+            "ne" => Ok(Self::NorthEast),
+            _ => Err(ParseHeadingError(s.to_owned())),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn turn() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.rotate(-270), Direction::South);
+        assert_eq!(Direction::South.reverse(), Direction::North);
+        assert_eq!(Direction8::NorthEast.rotate(90), Direction8::SouthEast);
+        assert_eq!(Direction8::from(Direction::West).turn_right(), Direction8::NorthWest);
+        assert_eq!(HexDirection::NorthEast.turn_right(), HexDirection::East);
+        assert_eq!(HexDirection::SouthEast.reverse(), HexDirection::NorthWest);
+    }
+
+    #[test]
+    pub fn step() {
+        assert_eq!(Direction::North.step(Point::new(1, 0)), None);
+        assert_eq!(Direction::West.step(Point::new(1, 0)), Some(Point::new(0, 0)));
+        assert_eq!(Direction8::SouthEast.step(Point::new(1, 0)), Some(Point::new(2, 1)));
+    }
+
+    #[test]
+    pub fn parse() {
+        assert!("^NU3".chars().all(|c| Direction::from_char(c) == Some(Direction::North)));
+        assert!("<WL2".chars().all(|c| Direction::from_char(c) == Some(Direction::West)));
+        assert_eq!("v".parse(), Ok(Direction::South));
+        assert_eq!("RR".parse::<Direction>(), Err(ParseHeadingError("RR".to_owned())));
+        assert_eq!("SW".parse(), Ok(Direction8::SouthWest));
+        assert_eq!(HexDirection::split_first("nwe"), Some((HexDirection::NorthWest, "e")));
+        assert_eq!(HexDirection::split_first("n"), None);
+    }
+}
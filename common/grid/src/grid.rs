@@ -0,0 +1,258 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::point::{OFFSETS_4, OFFSETS_8, Point};
+use crate::view::Transposed;
+
+
+/// Cell of a grid which is parsed from a single character.
+pub trait Cell: Sized {
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl Cell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+/// Digits, like heights or energy levels.
+impl Cell for u8 {
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|d| d as u8)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    InvalidCell(Point, char),
+    /// The row has a different width than the first row.
+    Ragged(usize),
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "Empty grid"),
+            ParseGridError::InvalidCell(p, c) => write!(f, "Invalid cell {c:?} at {p}"),
+            ParseGridError::Ragged(y) => write!(f, "Row {y} has a different width"),
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+/// Rectangular grid, stored row by row in a single vector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid filled with the value, which panics when the width is zero.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0, "Grid has a width of zero");
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Grid with the given width, filled row by row with the cells.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "Cells do not fill rows of width {width}");
+        Self { width, height: cells.len() / width, cells }
+    }
+
+    /// Grid with the value of `f` for every point, which panics when the width is zero.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        assert!(width > 0, "Grid has a width of zero");
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Self { width, height, cells }
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub const fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[p.y * self.width + p.x])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.contains(p).then(|| &mut self.cells[p.y * self.width + p.x])
+    }
+
+    /// Moves by the offset, or `None` when that leaves the grid.
+    pub fn step(&self, p: Point, offset: (isize, isize)) -> Option<Point> {
+        p.checked_offset(offset).filter(|&p| self.contains(p))
+    }
+
+    /// Horizontal and vertical neighbors inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        OFFSETS_4.into_iter().filter_map(move |offset| self.step(p, offset))
+    }
+
+    /// All neighbors including diagonals inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        OFFSETS_8.into_iter().filter_map(move |offset| self.step(p, offset))
+    }
+
+    /// All points, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells with their point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(&mut self.cells)
+    }
+
+    /// First point, row by row, of which the cell matches the predicate.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {x} outside of grid of width {}", self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// View with rows and columns swapped.
+    pub fn transposed(&self) -> Transposed<'_, T> {
+        Transposed::new(self)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        assert!(self.contains(p), "{p} outside of grid of size {}x{}", self.width, self.height);
+        &self.cells[p.y * self.width + p.x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        assert!(self.contains(p), "{p} outside of grid of size {}x{}", self.width, self.height);
+        &mut self.cells[p.y * self.width + p.x]
+    }
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for (y, line) in s.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(T::from_char(c).ok_or(ParseGridError::InvalidCell(Point::new(x, y), c))?);
+            }
+
+            let row_width = cells.len() - row_start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseGridError::Ragged(y));
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::from_vec(width, cells)),
+            _ => Err(ParseGridError::Empty),
+        }
+    }
+}
+
+/// Draws every row on its own line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn parse() {
+        let grid: Grid<u8> = "123\n456\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), [&2, &5]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        assert_eq!("12\n3".parse::<Grid<u8>>(), Err(ParseGridError::Ragged(1)));
+        assert_eq!("12\n3x".parse::<Grid<u8>>(), Err(ParseGridError::InvalidCell(Point::new(1, 1), 'x')));
+        assert_eq!("".parse::<Grid<u8>>(), Err(ParseGridError::Empty));
+    }
+
+    #[test]
+    #[should_panic(expected = "width of zero")]
+    pub fn zero_width() {
+        Grid::from_fn(0, 3, |_| 0);
+    }
+
+    #[test]
+    pub fn neighbors() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.neighbors4(Point::new(2, 1)).collect::<Vec<_>>(), [Point::new(2, 0), Point::new(1, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.step(Point::new(2, 1), (1, 0)), None);
+    }
+}
@@ -0,0 +1,9 @@
+pub use direction::{Direction, Direction8, Heading, HexDirection, ParseHeadingError};
+pub use grid::{Cell, Grid, ParseGridError};
+pub use point::{OFFSETS_4, OFFSETS_8, Point};
+pub use view::Transposed;
+
+mod direction;
+mod grid;
+mod point;
+mod view;
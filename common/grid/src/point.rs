@@ -0,0 +1,94 @@
+use std::fmt;
+use std::ops::Add;
+
+
+/// Offsets to the horizontal and vertical neighbors, clockwise starting north.
+pub const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all neighbors including diagonals, clockwise starting north.
+pub const OFFSETS_8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// Position in a grid, with `y` growing downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves by the offset, or `None` when that would go below zero.
+    pub const fn checked_offset(self, (dx, dy): (isize, isize)) -> Option<Self> {
+        match (self.x.checked_add_signed(dx), self.y.checked_add_signed(dy)) {
+            (Some(x), Some(y)) => Some(Self { x, y }),
+            _ => None,
+        }
+    }
+
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match (self.x.checked_add(other.x), self.y.checked_add(other.y)) {
+            (Some(x), Some(y)) => Some(Self { x, y }),
+            _ => None,
+        }
+    }
+
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match (self.x.checked_sub(other.x), self.y.checked_sub(other.y)) {
+            (Some(x), Some(y)) => Some(Self { x, y }),
+            _ => None,
+        }
+    }
+
+    pub const fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Horizontal and vertical neighbors which are not below zero.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        OFFSETS_4.into_iter().filter_map(move |offset| self.checked_offset(offset))
+    }
+
+    /// All neighbors including diagonals which are not below zero.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        OFFSETS_8.into_iter().filter_map(move |offset| self.checked_offset(offset))
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn checked() {
+        let p = Point::new(0, 2);
+        assert_eq!(p.checked_offset((1, -2)), Some(Point::new(1, 0)));
+        assert_eq!(p.checked_offset((-1, 0)), None);
+        assert_eq!(p.checked_sub(Point::new(0, 3)), None);
+        assert_eq!(p.neighbors4().collect::<Vec<_>>(), [Point::new(0, 1), Point::new(1, 2), Point::new(0, 3)]);
+        assert_eq!(Point::new(0, 0).neighbors8().count(), 3);
+    }
+}
@@ -0,0 +1,71 @@
+use std::ops::Index;
+
+use crate::grid::Grid;
+use crate::point::Point;
+
+
+/// Grid with its rows and columns swapped, without copying the cells.
+#[derive(Debug, Clone, Copy)]
+pub struct Transposed<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Transposed<'a, T> {
+    pub(crate) fn new(grid: &'a Grid<T>) -> Self {
+        Self { grid }
+    }
+
+    pub const fn width(&self) -> usize {
+        self.grid.height()
+    }
+
+    pub const fn height(&self) -> usize {
+        self.grid.width()
+    }
+
+    pub fn get(&self, p: Point) -> Option<&'a T> {
+        self.grid.get(Point::new(p.y, p.x))
+    }
+
+    /// Rows of the view, which are the columns of the grid.
+    pub fn rows(self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &'a T>> {
+        self.grid.columns()
+    }
+
+    /// Columns of the view, which are the rows of the grid.
+    pub fn columns(self) -> impl DoubleEndedIterator<Item = &'a [T]> {
+        self.grid.rows()
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width(), self.height(), |p| self[p].clone())
+    }
+}
+
+impl<T> Index<Point> for Transposed<'_, T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        &self.grid[Point::new(p.y, p.x)]
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn transposed() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        let transposed = grid.transposed();
+
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed[Point::new(1, 0)], 'd');
+        assert_eq!(transposed.rows().map(|r| r.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+        assert_eq!(transposed.to_grid().to_string(), "ad\nbe\ncf\n");
+    }
+}
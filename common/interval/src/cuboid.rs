@@ -0,0 +1,145 @@
+use std::array;
+use std::ops::Range;
+
+use crate::Number;
+
+
+/// Box of values in `N` dimensions, with a half-open interval along every axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub ranges: [Range<T>; N],
+}
+
+impl<T: Number, const N: usize> Cuboid<T, N> {
+    pub const fn new(ranges: [Range<T>; N]) -> Self {
+        Self { ranges }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(Range::is_empty)
+    }
+
+    /// Number of values in the cuboid.
+    pub fn volume(&self) -> T {
+        if self.is_empty() {
+            return T::default();
+        }
+
+        self.ranges.iter()
+            .map(|r| r.end - r.start)
+            .reduce(|volume, length| volume * length)
+            .unwrap_or_default()
+    }
+
+    /// Values in both cuboids, which can be empty.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let ranges = array::from_fn(|axis| {
+            let (a, b) = (&self.ranges[axis], &other.ranges[axis]);
+            a.start.max(b.start)..a.end.min(b.end)
+        });
+
+        Self { ranges }
+    }
+
+    /// Splits the cuboid along the axis, into the values below `at` and the values from it.
+    pub fn split(&self, axis: usize, at: T) -> (Self, Self) {
+        let r = &self.ranges[axis];
+        let at = at.max(r.start).min(r.end);
+
+        let mut below = self.clone();
+        let mut above = self.clone();
+        below.ranges[axis].end = at;
+        above.ranges[axis].start = at;
+        (below, above)
+    }
+
+    /// Values outside of the other cuboid, as at most `2 * N` cuboids which do not overlap.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.intersection(other).is_empty() {
+            return vec![self.clone()];
+        }
+
+        // Cut off the parts before and after the other cuboid along every axis
+        let mut pieces = Vec::new();
+        let mut rest = self.clone();
+        for (axis, r) in other.ranges.iter().enumerate() {
+            let (before, middle) = rest.split(axis, r.start);
+            let (middle, after) = middle.split(axis, r.end);
+            pieces.extend([before, after].into_iter().filter(|c| !c.is_empty()));
+            rest = middle;
+        }
+
+        pieces
+    }
+}
+
+/// Set of values in `N` dimensions, stored as cuboids which do not overlap.
+#[derive(Debug, Clone)]
+pub struct CuboidSet<T, const N: usize> {
+    cuboids: Vec<Cuboid<T, N>>,
+}
+
+impl<T: Number, const N: usize> CuboidSet<T, N> {
+    pub const fn new() -> Self {
+        Self { cuboids: Vec::new() }
+    }
+
+    pub fn cuboids(&self) -> &[Cuboid<T, N>] {
+        &self.cuboids
+    }
+
+    /// Turns on the values of the cuboid.
+    pub fn insert(&mut self, cuboid: Cuboid<T, N>) {
+        self.remove(&cuboid);
+        if !cuboid.is_empty() {
+            self.cuboids.push(cuboid);
+        }
+    }
+
+    /// Turns off the values of the cuboid.
+    pub fn remove(&mut self, cuboid: &Cuboid<T, N>) {
+        self.cuboids = self.cuboids.iter()
+            .flat_map(|c| c.difference(cuboid))
+            .collect();
+    }
+
+    /// Number of values in the set.
+    pub fn volume(&self) -> T {
+        self.cuboids.iter().fold(T::default(), |volume, c| volume + c.volume())
+    }
+}
+
+impl<T: Number, const N: usize> Default for CuboidSet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn difference() {
+        let a = Cuboid::new([0..4, 0..4, 0..4]);
+        let b = Cuboid::new([1..3, 1..3, 1..3]);
+        let pieces = a.difference(&b);
+        assert_eq!(pieces.len(), 6);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<i32>(), 64 - 8);
+
+        let c = Cuboid::new([3..6, 0..2, 4..5]);
+        assert_eq!(a.difference(&c), vec![a.clone()]);
+        assert!(a.intersection(&c).is_empty());
+    }
+
+    #[test]
+    pub fn on_off() {
+        let mut set = CuboidSet::new();
+        set.insert(Cuboid::new([10..13, 10..13, 10..13]));
+        set.insert(Cuboid::new([11..14, 11..14, 11..14]));
+        set.remove(&Cuboid::new([9..12, 9..12, 9..12]));
+        set.insert(Cuboid::new([10..11, 10..11, 10..11]));
+        assert_eq!(set.volume(), 39);
+    }
+}
@@ -0,0 +1,15 @@
+use std::ops::{Add, Mul, Sub};
+
+pub use cuboid::{Cuboid, CuboidSet};
+pub use map::RangeMap;
+pub use set::IntervalSet;
+
+mod cuboid;
+mod map;
+mod set;
+
+
+/// Bound of an interval, like an integer, of which the default is zero.
+pub trait Number: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Default> Number for T {}
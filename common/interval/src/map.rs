@@ -0,0 +1,116 @@
+use std::ops::Range;
+
+use crate::Number;
+use crate::set::IntervalSet;
+
+
+/// Piecewise map which moves intervals of values to another start.
+///
+/// Values which no interval covers map to themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeMap<T> {
+    /// Source intervals, sorted and apart, with the destination of their start
+    entries: Vec<(Range<T>, T)>,
+}
+
+impl<T: Number> RangeMap<T> {
+    pub const fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Maps the interval to the values starting at the destination.
+    ///
+    /// Panics when the interval overlaps one which was inserted before.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        let i = self.entries.partition_point(|(r, _)| r.end <= source.start);
+        assert!(
+            self.entries.get(i).is_none_or(|(r, _)| source.end <= r.start),
+            "Overlapping source interval",
+        );
+
+        self.entries.insert(i, (source, destination));
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let i = self.entries.partition_point(|(r, _)| r.end <= value);
+        match self.entries.get(i) {
+            Some((r, destination)) if r.start <= value => *destination + (value - r.start),
+            _ => value,
+        }
+    }
+
+    /// Maps all values of the set, which splits the intervals wherever the offset changes.
+    pub fn map(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut mapped = IntervalSet::new();
+        for range in set.ranges() {
+            let mut start = range.start;
+            let first = self.entries.partition_point(|(r, _)| r.end <= range.start);
+            for (r, destination) in &self.entries[first..] {
+                if range.end <= r.start {
+                    break;
+                }
+
+                // Values before the entry are not moved
+                mapped.insert(start..r.start.max(start));
+
+                let end = r.end.min(range.end);
+                let from = start.max(r.start);
+                mapped.insert(*destination + (from - r.start)..*destination + (end - r.start));
+                start = end;
+            }
+
+            mapped.insert(start..range.end);
+        }
+
+        mapped
+    }
+
+    /// Map in the other direction, which only undoes this map when no values end up on the same value.
+    pub fn inverse(&self) -> Self {
+        self.entries.iter()
+            .map(|(r, destination)| (*destination..*destination + (r.end - r.start), r.start))
+            .collect()
+    }
+}
+
+impl<T: Number> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Number> FromIterator<(Range<T>, T)> for RangeMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (source, destination) in iter {
+            map.insert(source, destination);
+        }
+
+        map
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn get() {
+        // Swaps 10..15 with 20..25
+        let map = RangeMap::from_iter([(20..25, 10), (10..15, 20)]);
+        assert_eq!(map.get(9), 9);
+        assert_eq!(map.get(12), 22);
+        assert_eq!(map.get(24), 14);
+        assert_eq!(map.get(25), 25);
+        assert_eq!(map.inverse().get(22), 12);
+    }
+
+    #[test]
+    pub fn map() {
+        let map = RangeMap::from_iter([(20..25, 10), (10..15, 20)]);
+        let set = IntervalSet::from_iter([8..12, 14..22]);
+        assert_eq!(map.map(&set).ranges(), [8..12, 15..22, 24..25]);
+        assert_eq!(map.map(&IntervalSet::from(12..13)), IntervalSet::from(22..23));
+    }
+}
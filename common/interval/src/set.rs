@@ -0,0 +1,177 @@
+use std::ops::Range;
+
+use crate::Number;
+
+
+/// Set of values stored as half-open intervals, which are kept sorted, apart and without gaps of zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Number> IntervalSet<T> {
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Sorted intervals of the set.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    /// Number of values in the set.
+    pub fn total_length(&self) -> T {
+        self.ranges.iter().fold(T::default(), |length, r| length + (r.end - r.start))
+    }
+
+    /// Adds the values of the interval, and merges it with the intervals it touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = match &self.ranges[first..last] {
+            [] => range,
+            touched => touched[0].start.min(range.start)..touched[touched.len() - 1].end.max(range.end),
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Removes the values of the interval, and splits the intervals it partly covers.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first == last {
+            return;
+        }
+
+        let before = self.ranges[first].start..range.start;
+        let after = range.end..self.ranges[last - 1].end;
+        self.ranges.splice(first..last, [before, after].into_iter().filter(|r| !r.is_empty()));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for r in &other.ranges {
+            union.insert(r.clone());
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for r in &other.ranges {
+            difference.remove(r.clone());
+        }
+
+        difference
+    }
+
+    /// Values of the interval which are not in the set.
+    pub fn complement(&self, within: Range<T>) -> Self {
+        Self::from(within).difference(self)
+    }
+}
+
+impl<T: Number> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Number> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Number> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Number> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn insert_remove() {
+        let mut set = IntervalSet::from_iter([10..15, 3..6, 16..21, 12..19]);
+        assert_eq!(set.ranges(), [3..6, 10..21]);
+        set.insert(6..8);
+        assert_eq!(set.ranges(), [3..8, 10..21]);
+        assert_eq!(set.total_length(), 16);
+
+        set.remove(5..12);
+        assert_eq!(set.ranges(), [3..5, 12..21]);
+        set.remove(0..4);
+        set.remove(14..15);
+        assert_eq!(set.ranges(), [4..5, 12..14, 15..21]);
+
+        assert!(set.contains(4));
+        assert!(!set.contains(14));
+        assert!(set.contains(20));
+        assert!(!set.contains(21));
+    }
+
+    #[test]
+    pub fn algebra() {
+        let a = IntervalSet::from_iter([0..10, 20..30]);
+        let b = IntervalSet::from_iter([5..25, 28..40]);
+        assert_eq!(a.union(&b), IntervalSet::from(0..40));
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28]);
+        assert_eq!(a.complement(-5..35).ranges(), [-5..0, 10..20, 30..35]);
+        assert!(a.intersection(&IntervalSet::from(10..20)).is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::gcd::{checked_lcm, extended_gcd};
+use crate::modular::mod_mul;
+
+
+/// Lowest `x` which is equal to every remainder modulo its modulus, with the least common multiple of the moduli,
+/// after which `x` repeats.
+///
+/// The moduli can share divisors, which gives `None` when the remainders contradict each other.
+/// Panics when the least common multiple does not fit.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0, 1);
+    for (remainder, modulus) in congruences {
+        let remainder = remainder.rem_euclid(modulus);
+        let (g, inverse, _) = extended_gcd(m, modulus);
+        if (remainder - x) % g != 0 {
+            return None;
+        }
+
+        // Solve x + m * t = remainder, where m / g has the inverse modulo modulus / g
+        let t = mod_mul((remainder - x) / g, inverse, modulus / g);
+        let lcm = checked_lcm(m, modulus).expect("Least common multiple of the moduli does not fit");
+        x = ((x as i128 + m as i128 * t as i128) % lcm as i128) as i64;
+        m = lcm;
+    }
+
+    Some((x, m))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn coprime() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 4), (0, 3)]), Some((3, 12)));
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[test]
+    pub fn shared_divisors() {
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(2, 4), (3, 6)]), None);
+        assert_eq!(crt([(5, 10), (5, 10)]), Some((5, 10)));
+    }
+}
@@ -0,0 +1,7 @@
+pub use crt::crt;
+pub use gcd::{checked_lcm, extended_gcd, gcd};
+pub use modular::{discrete_log, mod_inv, mod_mul, mod_pow};
+
+mod crt;
+mod gcd;
+mod modular;
@@ -0,0 +1,91 @@
+use hashbrown::HashMap;
+
+use crate::gcd::extended_gcd;
+
+
+/// Product modulo `m`, without overflowing.
+pub fn mod_mul(a: i64, b: i64, m: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(m as i128) as i64
+}
+
+/// Power modulo `m`, by squaring.
+pub fn mod_pow(base: i64, exp: u64, m: i64) -> i64 {
+    let mut result = 1 % m;
+    let mut base = base.rem_euclid(m);
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Inverse modulo `m`, or `None` when `a` and `m` share a divisor.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Lowest exponent `x` for which `base^x = target` modulo `m`, with baby-step giant-step.
+///
+/// The base has to share no divisor with `m`.
+pub fn discrete_log(base: i64, target: i64, m: i64) -> Option<u64> {
+    let target = target.rem_euclid(m);
+    if target == 1 % m {
+        return Some(0);
+    }
+
+    // Baby steps: target * base^j, where a later j is kept to find the lowest exponent
+    let n = (m as f64).sqrt().ceil() as i64;
+    let mut baby_steps = HashMap::new();
+    let mut value = target;
+    for j in 0..n {
+        baby_steps.insert(value, j);
+        value = mod_mul(value, base, m);
+    }
+
+    // Giant steps: base^(i * n) = target * base^j, so x = i * n - j
+    let giant_step = mod_pow(base, n as u64, m);
+    let mut value = 1 % m;
+    for i in 1..=n {
+        value = mod_mul(value, giant_step, m);
+        if let Some(&j) = baby_steps.get(&value) {
+            return Some((i * n - j) as u64);
+        }
+    }
+
+    None
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn arithmetic() {
+        assert_eq!(mod_mul(i64::MAX, i64::MAX, 1_000_000_007), 737_564_071);
+        assert_eq!(mod_pow(2, 10, 1_000), 24);
+        assert_eq!(mod_pow(-3, 3, 10), 3);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(4, 8), None);
+    }
+
+    #[test]
+    pub fn logarithm() {
+        // Public key of the card in the example of 2020 day 25
+        assert_eq!(discrete_log(7, 5_764_801, 20_201_227), Some(8));
+        assert_eq!(discrete_log(2, 1, 11), Some(0));
+        assert_eq!(discrete_log(2, 10, 11), Some(5));
+        // Powers of 4 are only ever 1, 4, 5, 9 and 3 modulo 11
+        assert_eq!(discrete_log(4, 2, 11), None);
+    }
+}
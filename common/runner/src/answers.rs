@@ -0,0 +1,179 @@
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use aoc::{Puzzle, Report};
+
+
+/// Answers recorded for one input file of a day, parts are missing when they are not known.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recorded {
+    pub file: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Recorded {
+    /// Answers of a solved input, without the parts that have no answer.
+    pub fn new(file: &str, report: &Report) -> Self {
+        let answer = |value: &String| (value != "-").then(|| value.clone());
+        Self {
+            file: file.to_owned(),
+            part1: answer(&report.part1.value),
+            part2: answer(&report.part2.value),
+        }
+    }
+}
+
+/// Recorded answers of a single day, read from `answers.txt` next to its input.
+/// The answers of the puzzle input are kept in `answers.local.txt`, which is not committed like the input.
+///
+/// Each input file gets a section with the answer of each part,
+/// multi-line answers are written with `\n`:
+/// ```text
+/// [input.txt]
+/// part1 = 24000
+/// part2 = 45000
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub inputs: Vec<Recorded>,
+}
+
+impl Answers {
+    pub fn path(puzzle: &Puzzle) -> PathBuf {
+        puzzle.input_path().with_file_name("answers.txt")
+    }
+
+    pub fn local_path(puzzle: &Puzzle) -> PathBuf {
+        puzzle.input_path().with_file_name("answers.local.txt")
+    }
+
+    /// Reads the answers of both files of the day, either of which can be missing.
+    pub fn load(puzzle: &Puzzle) -> Result<Self, String> {
+        let mut inputs = Vec::new();
+        for path in [Self::path(puzzle), Self::local_path(puzzle)] {
+            let Ok(answers) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let answers: Self = answers.parse().map_err(|e| format!("{}: {e}", path.display()))?;
+            inputs.extend(answers.inputs);
+        }
+
+        Ok(Self { inputs })
+    }
+
+    pub fn get(&self, file: &str) -> Option<&Recorded> {
+        self.inputs.iter().find(|r| r.file == file)
+    }
+
+    /// Replaces the answers of the same input file, or adds them.
+    pub fn record(&mut self, recorded: Recorded) {
+        match self.inputs.iter_mut().find(|r| r.file == recorded.file) {
+            Some(r) => *r = recorded,
+            None => self.inputs.push(recorded),
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut inputs: Vec<Recorded> = Vec::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(file) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                inputs.push(Recorded { file: file.to_owned(), ..Default::default() });
+                continue;
+            }
+
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| format!("Line {}: expected `part = answer`", i + 1))?;
+            let recorded = inputs.last_mut()
+                .ok_or_else(|| format!("Line {}: answer outside of an [input] section", i + 1))?;
+            let part = match key.trim() {
+                "part1" => &mut recorded.part1,
+                "part2" => &mut recorded.part2,
+                key => return Err(format!("Line {}: unknown part {key}", i + 1)),
+            };
+            *part = Some(value.trim().replace("\\n", "\n"));
+        }
+
+        Ok(Self { inputs })
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, recorded) in self.inputs.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "[{}]", recorded.file)?;
+            for (part, answer) in [(1, &recorded.part1), (2, &recorded.part2)] {
+                if let Some(answer) = answer {
+                    writeln!(f, "part{part} = {}", answer.replace('\n', "\\n"))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn parse() {
+        let answers: Answers = "\
+            # Comment\n\
+            [input.txt]\n\
+            part1 = 24000\n\
+            part2 = 45000\n\
+            \n\
+            [example.txt]\n\
+            part2 = #.\\n.#\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(answers.inputs, [
+            Recorded {
+                file: String::from("input.txt"),
+                part1: Some(String::from("24000")),
+                part2: Some(String::from("45000")),
+            },
+            Recorded {
+                file: String::from("example.txt"),
+                part1: None,
+                part2: Some(String::from("#.\n.#")),
+            },
+        ]);
+    }
+
+    #[test]
+    pub fn record() {
+        let mut answers: Answers = "[example.txt]\npart1 = 1\n".parse().unwrap();
+        answers.record(Recorded { file: String::from("input.txt"), part1: Some(String::from("#\n#")), part2: None });
+        answers.record(Recorded { file: String::from("example.txt"), part1: None, part2: Some(String::from("2")) });
+
+        let written = answers.to_string();
+        assert_eq!(written, "[example.txt]\npart2 = 2\n\n[input.txt]\npart1 = #\\n#\n");
+        assert_eq!(written.parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    pub fn outside_section() {
+        assert!("part1 = 1".parse::<Answers>().is_err());
+    }
+}
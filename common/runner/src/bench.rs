@@ -0,0 +1,327 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use aoc::{Puzzle, Report};
+use serde::{Deserialize, Serialize};
+
+
+/// Increases below this are noise, no matter the threshold.
+const NOISE: Duration = Duration::from_micros(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part 1",
+            Phase::Part2 => "part 2",
+        }
+    }
+
+    fn of(self, report: &Report) -> Duration {
+        match self {
+            Phase::Parse => report.parse,
+            Phase::Part1 => report.part1.time,
+            Phase::Part2 => report.part2.time,
+        }
+    }
+}
+
+/// How often each puzzle is solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Runs {
+    Exactly(usize),
+    /// Like hyperfine, at least `min` runs and until `time` has passed, but no more than `max` runs.
+    Adaptive { min: usize, max: usize, time: Duration },
+}
+
+impl Default for Runs {
+    fn default() -> Self {
+        Runs::Adaptive { min: 3, max: 100, time: Duration::from_millis(500) }
+    }
+}
+
+/// Reports of solving the same puzzle a number of times.
+#[derive(Debug, Clone)]
+pub struct Samples {
+    pub reports: Vec<Report>,
+}
+
+impl Samples {
+    /// Solves the puzzle after a single warmup run.
+    pub fn measure(puzzle: &Puzzle, input: &str, runs: Runs) -> Self {
+        puzzle.solve(input);
+
+        let start = Instant::now();
+        let mut reports = Vec::new();
+        loop {
+            let done = match runs {
+                Runs::Exactly(n) => reports.len() >= n,
+                Runs::Adaptive { min, max, time } =>
+                    reports.len() >= max || (reports.len() >= min && start.elapsed() >= time),
+            };
+            if done {
+                break;
+            }
+            reports.push(puzzle.solve(input));
+        }
+
+        Self { reports }
+    }
+
+    pub fn times(&self, phase: Phase) -> Vec<Duration> {
+        self.reports.iter().map(|r| phase.of(r)).collect()
+    }
+
+    pub fn timing(&self, commit: &str, puzzle: &Puzzle) -> Timing {
+        let median = |phase| median(&self.times(phase)).as_nanos() as u64;
+        Timing {
+            commit: commit.to_owned(),
+            year: puzzle.year,
+            day: puzzle.day,
+            parse: median(Phase::Parse),
+            part1: median(Phase::Part1),
+            part2: median(Phase::Part2),
+        }
+    }
+}
+
+pub fn median(times: &[Duration]) -> Duration {
+    let mut times = times.to_vec();
+    times.sort_unstable();
+    match times.len() {
+        0 => Duration::ZERO,
+        n if n % 2 == 0 => (times[n / 2 - 1] + times[n / 2]) / 2,
+        n => times[n / 2],
+    }
+}
+
+/// Median times of one day at one commit, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub commit: String,
+    pub year: u16,
+    pub day: u8,
+    pub parse: u64,
+    pub part1: u64,
+    pub part2: u64,
+}
+
+impl Timing {
+    pub fn get(&self, phase: Phase) -> Duration {
+        Duration::from_nanos(match phase {
+            Phase::Parse => self.parse,
+            Phase::Part1 => self.part1,
+            Phase::Part2 => self.part2,
+        })
+    }
+
+    pub fn total(&self) -> Duration {
+        Phase::ALL.into_iter().map(|p| self.get(p)).sum()
+    }
+}
+
+/// Phase of a day which became slower than its baseline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    pub baseline: String,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+/// Phases which are more than `threshold` (a fraction, `0.1` is 10%) slower than before.
+pub fn regressions(before: &Timing, after: &Timing, threshold: f64) -> Vec<Regression> {
+    Phase::ALL.into_iter()
+        .filter_map(|phase| {
+            let (old, new) = (before.get(phase), after.get(phase));
+            let regressed = new.as_secs_f64() > old.as_secs_f64() * (1.0 + threshold)
+                && new.saturating_sub(old) > NOISE;
+            regressed.then(|| Regression {
+                year: after.year,
+                day: after.day,
+                phase,
+                baseline: before.commit.clone(),
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
+/// Slowest days of each year by total time, at most `count` per year.
+pub fn leaderboard(timings: &[Timing], count: usize) -> BTreeMap<u16, Vec<&Timing>> {
+    let mut years: BTreeMap<u16, Vec<&Timing>> = BTreeMap::new();
+    for timing in timings {
+        years.entry(timing.year).or_default().push(timing);
+    }
+    for days in years.values_mut() {
+        days.sort_by_key(|t| std::cmp::Reverse(t.total()));
+        days.truncate(count);
+    }
+    years
+}
+
+/// Timings of every benchmarked commit, stored as JSON lines in chronological order.
+#[derive(Debug, Clone, Default)]
+pub struct Database {
+    pub timings: Vec<Timing>,
+}
+
+impl Database {
+    /// Default location, ignored by git since timings depend on the machine.
+    pub fn path() -> PathBuf {
+        aoc::root().join("timings.jsonl")
+    }
+
+    /// Loads the database, which is empty when the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut timings = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                timings.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(Self { timings })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        for timing in &self.timings {
+            serde_json::to_writer(&mut writer, timing)?;
+            writeln!(writer)?;
+        }
+        writer.flush()
+    }
+
+    /// Latest timing of the day at another commit.
+    pub fn baseline(&self, timing: &Timing) -> Option<&Timing> {
+        self.timings.iter()
+            .rev()
+            .find(|t| t.year == timing.year && t.day == timing.day && t.commit != timing.commit)
+    }
+
+    /// Adds the timing, replacing an earlier one of the same day and commit.
+    pub fn record(&mut self, timing: Timing) {
+        self.timings.retain(|t| (t.year, t.day, &t.commit) != (timing.year, timing.day, &timing.commit));
+        self.timings.push(timing);
+    }
+}
+
+/// Short hash of the checked out commit, with a `-dirty` suffix when there are uncommitted changes.
+pub fn commit() -> Option<String> {
+    let root = aoc::root();
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(&root)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_owned();
+
+    let clean = Command::new("git")
+        .args(["diff", "--quiet", "HEAD"])
+        .current_dir(&root)
+        .status()
+        .is_ok_and(|s| s.success());
+
+    Some(if clean { hash } else { format!("{hash}-dirty") })
+}
+
+/// Single result in the format of `hyperfine --export-json`, times are in seconds.
+#[derive(Debug, Clone, Serialize)]
+struct HyperfineResult {
+    command: String,
+    mean: f64,
+    stddev: f64,
+    median: f64,
+    min: f64,
+    max: f64,
+    times: Vec<f64>,
+}
+
+impl HyperfineResult {
+    fn new(command: String, durations: &[Duration]) -> Self {
+        let times: Vec<_> = durations.iter().map(Duration::as_secs_f64).collect();
+        let n = times.len() as f64;
+        let mean = times.iter().sum::<f64>() / n;
+        let variance = times.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0);
+
+        Self {
+            command,
+            mean,
+            stddev: variance.sqrt(),
+            median: median(durations).as_secs_f64(),
+            min: times.iter().copied().fold(f64::INFINITY, f64::min),
+            max: times.iter().copied().fold(0.0, f64::max),
+            times,
+        }
+    }
+}
+
+/// Writes every phase of every puzzle as a separate command, so hyperfine's scripts can plot them.
+pub fn export_hyperfine(path: &Path, samples: &[(Puzzle, Samples)]) -> io::Result<()> {
+    let results: Vec<_> = samples.iter()
+        .flat_map(|(puzzle, samples)| Phase::ALL.map(|phase| HyperfineResult::new(
+            format!("{} day {} {}", puzzle.year, puzzle.day, phase.name()),
+            &samples.times(phase),
+        )))
+        .collect();
+
+    let json = serde_json::to_string_pretty(&serde_json::json!({ "results": results }))?;
+    fs::write(path, json)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn timing(commit: &str, day: u8, parse: u64, part1: u64, part2: u64) -> Timing {
+        Timing { commit: commit.to_owned(), year: 2020, day, parse, part1, part2 }
+    }
+
+    #[test]
+    pub fn regressions_beyond_threshold() {
+        let before = timing("a", 23, 1_000, 1_000_000, 1_000_000);
+        let after = timing("b", 23, 5_000, 1_050_000, 1_200_000);
+
+        // Parse is 5 times slower, but still below the noise
+        let found: Vec<_> = regressions(&before, &after, 0.1).into_iter().map(|r| r.phase).collect();
+        assert_eq!(found, [Phase::Part2]);
+    }
+
+    #[test]
+    pub fn database_baseline() {
+        let mut db = Database::default();
+        db.record(timing("a", 23, 0, 1, 1));
+        db.record(timing("b", 23, 0, 2, 2));
+        db.record(timing("b", 23, 0, 3, 3));
+
+        assert_eq!(db.timings.len(), 2);
+        assert_eq!(db.baseline(&timing("b", 23, 0, 0, 0)).map(|t| t.part1), Some(1));
+        assert_eq!(db.baseline(&timing("c", 23, 0, 0, 0)).map(|t| t.part1), Some(3));
+        assert_eq!(db.baseline(&timing("c", 15, 0, 0, 0)), None);
+    }
+}
@@ -0,0 +1,194 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, fmt, fs, io, thread};
+
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Minimum time between two requests to the server.
+pub const DEFAULT_DELAY: Duration = Duration::from_secs(1);
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"), " (rust; ureq)");
+/// Served instead of an input when the session is not valid.
+const NOT_LOGGED_IN: &str = "Puzzle inputs differ by user.";
+
+#[derive(Debug)]
+pub enum DownloadError {
+    Io(io::Error),
+    /// No session token is set, which is needed when an input is not cached.
+    NoSession,
+    NotLoggedIn,
+    Status(u16, String),
+    Transport(String),
+}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadError::Io(e) => write!(f, "{e}"),
+            DownloadError::NoSession => write!(f, "No session token, set ADVENT_OF_CODE_SESSION"),
+            DownloadError::NotLoggedIn => write!(f, "Session token is not valid"),
+            DownloadError::Status(status, body) => write!(f, "Server responded with {status}: {}", body.trim()),
+            DownloadError::Transport(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for DownloadError {}
+
+impl From<io::Error> for DownloadError {
+    fn from(e: io::Error) -> Self {
+        DownloadError::Io(e)
+    }
+}
+
+impl From<ureq::Error> for DownloadError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                DownloadError::Status(status, response.into_string().unwrap_or_default())
+            },
+            ureq::Error::Transport(e) => DownloadError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// Directory of a single day in the repository, `<year>/day<day>`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub dir: PathBuf,
+}
+
+impl Day {
+    pub fn input_path(&self) -> PathBuf {
+        self.dir.join("input.txt")
+    }
+
+    /// Whether the input is missing or empty.
+    pub fn needs_input(&self) -> bool {
+        fs::metadata(self.input_path()).map_or(true, |m| m.len() == 0)
+    }
+}
+
+/// Finds the directories of all days below the root, optionally only of a single year or day.
+/// Directories not following the `<year>/day<day>` layout are skipped.
+pub fn days(root: &Path, year: Option<u16>, day: Option<u8>) -> io::Result<Vec<Day>> {
+    let mut days = Vec::new();
+
+    for year_entry in fs::read_dir(root)? {
+        let year_dir = year_entry?.path();
+        let Some(y) = number::<u16>(&year_dir, "", 4) else {
+            continue;
+        };
+        if !year_dir.is_dir() || year.is_some_and(|year| year != y) {
+            continue;
+        }
+
+        for day_entry in fs::read_dir(&year_dir)? {
+            let day_dir = day_entry?.path();
+            let Some(d) = number::<u8>(&day_dir, "day", 2) else {
+                continue;
+            };
+            if day_dir.is_dir() && day.is_none_or(|day| day == d) {
+                days.push(Day { year: y, day: d, dir: day_dir });
+            }
+        }
+    }
+
+    days.sort();
+    Ok(days)
+}
+
+/// Parses file names like `2024` or `day05`, consisting of a prefix and a fixed number of digits.
+fn number<T: std::str::FromStr>(path: &Path, prefix: &str, digits: usize) -> Option<T> {
+    let number = path.file_name()?.to_str()?.strip_prefix(prefix)?;
+    if number.len() != digits || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Default cache directory, `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`.
+pub fn default_cache() -> Option<PathBuf> {
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .map(|dir| dir.join("aoc"))
+}
+
+/// Where an input came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Cache,
+    Server,
+}
+
+/// Downloads puzzle inputs, waiting between requests so the server is not flooded.
+///
+/// Every downloaded input is stored in the cache, so it is only ever requested once.
+#[derive(Debug)]
+pub struct Downloader {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+    cache: Option<PathBuf>,
+    delay: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Downloader {
+    pub fn new(base_url: &str, session: Option<String>, cache: Option<PathBuf>, delay: Duration) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            cache,
+            delay,
+            last_request: None,
+        }
+    }
+
+    /// Input of a single day, from the cache when it was downloaded before.
+    pub fn input(&mut self, year: u16, day: u8) -> Result<(String, Source), DownloadError> {
+        let cached = self.cache.as_ref().map(|dir| dir.join(year.to_string()).join(format!("day{day:02}.txt")));
+        if let Some(input) = cached.as_ref().and_then(|path| fs::read_to_string(path).ok())
+            && !input.is_empty()
+        {
+            return Ok((input, Source::Cache));
+        }
+
+        let cookie = format!("session={}", self.session.as_ref().ok_or(DownloadError::NoSession)?);
+        self.wait();
+        let response = self.agent.get(&format!("{}/{year}/day/{day}/input", self.base_url))
+            .set("Cookie", &cookie)
+            .call();
+        self.last_request = Some(Instant::now());
+        let input = response?.into_string()?;
+        if input.starts_with(NOT_LOGGED_IN) {
+            return Err(DownloadError::NotLoggedIn);
+        }
+
+        if let Some(path) = cached {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, &input)?;
+        }
+        Ok((input, Source::Server))
+    }
+
+    /// Writes the input of the day to its directory.
+    pub fn download(&mut self, day: &Day) -> Result<Source, DownloadError> {
+        let (input, source) = self.input(day.year, day.day)?;
+        fs::write(day.input_path(), input)?;
+        Ok(source)
+    }
+
+    /// Waits until the delay has passed since the server answered the last request.
+    fn wait(&self) {
+        if let Some(last) = self.last_request {
+            thread::sleep(self.delay.saturating_sub(last.elapsed()));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub use puzzles::PUZZLES;
+
+pub mod answers;
+pub mod bench;
+pub mod download;
+mod puzzles;
+pub mod scaffold;
@@ -0,0 +1,396 @@
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+use aoc::{Puzzle, Table, format_duration};
+use runner::PUZZLES;
+use runner::answers::{Answers, Recorded};
+use runner::bench::{self, Database, Phase, Runs, Samples};
+use runner::download::{self, DownloadError, Downloader, Source};
+use runner::scaffold;
+
+
+const USAGE: &str = "\
+Usage: aoc run [year] [day] [input]
+       aoc record <year> <day> [--part1 ANSWER] [--part2 ANSWER] [--force]
+       aoc bench [year] [day] [--runs N] [--threshold PERCENT] [--export-json FILE]
+       aoc download [year] [day] [--base-url URL] [--delay SECONDS] [--cache DIR]
+       aoc new <year> <day> [--download] [download flags]
+
+Runs all puzzles, all puzzles of a year, or a single day.
+Inputs are read from <year>/day<day>/input.txt in the repository.
+A single day can be given another input file, or - to read it from stdin.
+
+Record writes the answers accepted by Advent of Code for the input of a day to answers.local.txt
+next to it, which the answers test compares against. The answers of the code are shown next to them.
+Answers which were recorded before are only replaced with --force.
+
+Bench times the parse, part 1 and part 2 phases of each puzzle, and records the median times
+of the current commit in timings.jsonl. Phases more than --threshold percent (default 10) slower
+than the previously benchmarked commit are reported as regressions.
+--export-json writes all times in the format of hyperfine --export-json.
+
+Download fetches missing or empty inputs of all <year>/day<day> directories, using the session token
+in ADVENT_OF_CODE_SESSION. Requests are at least --delay seconds (default 1) apart, and inputs are
+cached in --cache (default ~/.cache/aoc) so they are only requested once.
+
+New creates the crate of a day from the templates in common/runner/templates/day,
+and adds it to the workspace and the runner. --download also fetches its input.";
+
+/// Slowest days shown per year after benchmarking.
+const LEADERBOARD_SIZE: usize = 5;
+
+fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Puzzle> {
+    PUZZLES.iter()
+        .filter(|p| year.is_none_or(|y| p.year == y))
+        .filter(|p| day.is_none_or(|d| p.day == d))
+        .collect()
+}
+
+fn run(year: Option<u16>, day: Option<u8>, input: Option<&Path>) {
+    let puzzles = select(year, day);
+    if puzzles.is_empty() {
+        eprintln!("No puzzles found");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.header();
+
+    for puzzle in puzzles {
+        let path = input.map_or_else(|| puzzle.input_path(), Path::to_path_buf);
+        let input = match aoc::read_input(&path) {
+            Ok(input) if !input.trim().is_empty() => input,
+            _ => {
+                table.failure(puzzle, "missing input");
+                continue;
+            },
+        };
+
+        // Keep going when a single puzzle fails, the panic message is already printed
+        match panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(&input))) {
+            Ok(report) => table.report(puzzle, &report),
+            Err(_) => table.failure(puzzle, "panicked"),
+        }
+    }
+
+    table.summary();
+}
+
+/// Records the accepted answers of the puzzle input of a day, and compares them to the answers of the code.
+fn record(year: u16, day: u8, flags: &Flags) -> Result<(), String> {
+    let [puzzle] = select(Some(year), Some(day))[..] else {
+        return Err(String::from("No puzzles found"));
+    };
+
+    let mut accepted = Recorded { file: String::from("input.txt"), ..Default::default() };
+    let mut force = false;
+    for &(name, value) in flags {
+        match name {
+            "part1" => accepted.part1 = Some(value.replace("\\n", "\n")),
+            "part2" => accepted.part2 = Some(value.replace("\\n", "\n")),
+            "force" => force = true,
+            _ => return Err(format!("Unknown flag --{name}\n\n{USAGE}")),
+        }
+    }
+    if accepted.part1.is_none() && accepted.part2.is_none() {
+        return Err(format!("Give the accepted answers with --part1 and --part2\n\n{USAGE}"));
+    }
+
+    let input = match aoc::read_input(&puzzle.input_path()) {
+        Ok(input) if !input.trim().is_empty() => input,
+        _ => return Err(format!("No input of {year} day {day}")),
+    };
+
+    let path = Answers::local_path(puzzle);
+    let mut answers = match fs::read_to_string(&path) {
+        Ok(answers) => answers.parse().map_err(|e| format!("{}: {e}", path.display()))?,
+        Err(_) => Answers::default(),
+    };
+    if !force && answers.get("input.txt").is_some() {
+        return Err(format!("{} already has answers of the input, use --force to replace them", path.display()));
+    }
+
+    answers.record(accepted.clone());
+    fs::write(&path, answers.to_string()).map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+
+    let Ok(report) = panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(&input))) else {
+        println!("Recorded, but the code panicked on the input");
+        return Ok(());
+    };
+    let parts = [(1, &accepted.part1, &report.part1.value), (2, &accepted.part2, &report.part2.value)];
+    for (part, expected, answer) in parts {
+        match expected {
+            Some(expected) if expected == answer => println!("Part {part}: {answer}"),
+            Some(expected) => println!("Part {part}: {expected}, but the code answers {answer}"),
+            None => println!("Part {part}: not recorded, the code answers {answer}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default)]
+struct BenchOptions {
+    runs: Runs,
+    /// Fraction a phase may become slower before it is a regression.
+    threshold: f64,
+    export: Option<PathBuf>,
+}
+
+fn bench(year: Option<u16>, day: Option<u8>, options: &BenchOptions) -> Result<(), String> {
+    let puzzles = select(year, day);
+    if puzzles.is_empty() {
+        return Err(String::from("No puzzles found"));
+    }
+
+    let path = Database::path();
+    let mut database = Database::load(&path)
+        .map_err(|e| format!("Could not load {}: {e}", path.display()))?;
+    let commit = bench::commit().unwrap_or_else(|| String::from("unknown"));
+
+    println!("Benchmarking commit {commit}");
+    println!("Year  Day  {:>10}  {:>10}  {:>10}  {:>10}  Runs", "Parse", "Part 1", "Part 2", "Total");
+    println!("{}", "-".repeat(63));
+
+    let mut timings = Vec::new();
+    let mut samples = Vec::new();
+    let mut regressions = Vec::new();
+
+    for puzzle in puzzles {
+        let input = match aoc::read_input(&puzzle.input_path()) {
+            Ok(input) if !input.trim().is_empty() => input,
+            _ => {
+                println!("{:>4}  {:>3}  missing input", puzzle.year, puzzle.day);
+                continue;
+            },
+        };
+
+        let Ok(measured) = panic::catch_unwind(AssertUnwindSafe(|| Samples::measure(puzzle, &input, options.runs))) else {
+            println!("{:>4}  {:>3}  panicked", puzzle.year, puzzle.day);
+            continue;
+        };
+
+        let timing = measured.timing(&commit, puzzle);
+        let regressed = database.baseline(&timing)
+            .map(|baseline| bench::regressions(baseline, &timing, options.threshold))
+            .unwrap_or_default();
+
+        let phase = |phase: Phase| {
+            let marker = if regressed.iter().any(|r| r.phase == phase) { "!" } else { "" };
+            format!("{marker}{}", format_duration(timing.get(phase)))
+        };
+        println!(
+            "{:>4}  {:>3}  {:>10}  {:>10}  {:>10}  {:>10}  {:>4}",
+            puzzle.year,
+            puzzle.day,
+            phase(Phase::Parse),
+            phase(Phase::Part1),
+            phase(Phase::Part2),
+            format_duration(timing.total()),
+            measured.reports.len(),
+        );
+
+        regressions.extend(regressed);
+        database.record(timing.clone());
+        timings.push(timing);
+        samples.push((*puzzle, measured));
+    }
+
+    database.save(&path)
+        .map_err(|e| format!("Could not save {}: {e}", path.display()))?;
+    if let Some(export) = &options.export {
+        bench::export_hyperfine(export, &samples)
+            .map_err(|e| format!("Could not export {}: {e}", export.display()))?;
+    }
+
+    if !regressions.is_empty() {
+        println!("\n{} regressions beyond {:.0}%:", regressions.len(), options.threshold * 100.0);
+        for r in &regressions {
+            println!(
+                "{:>4}  {:>3}  {:<6}  {:>10} -> {:>10}  (+{:.0}% since {})",
+                r.year,
+                r.day,
+                r.phase.name(),
+                format_duration(r.before),
+                format_duration(r.after),
+                (r.after.as_secs_f64() / r.before.as_secs_f64() - 1.0) * 100.0,
+                r.baseline,
+            );
+        }
+    }
+
+    for (year, days) in bench::leaderboard(&timings, LEADERBOARD_SIZE) {
+        let total: std::time::Duration = timings.iter().filter(|t| t.year == year).map(|t| t.total()).sum();
+        println!("\nSlowest days of {year}, {} in total:", format_duration(total));
+        for timing in days {
+            println!(
+                "  day {:>2}  {:>10}  {:>5.1}%",
+                timing.day,
+                format_duration(timing.total()),
+                timing.total().as_secs_f64() / total.as_secs_f64() * 100.0,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn download(year: Option<u16>, day: Option<u8>, flags: &Flags) -> Result<(), String> {
+    let mut base_url = download::BASE_URL;
+    let mut delay = download::DEFAULT_DELAY;
+    let mut cache = download::default_cache();
+
+    for &(name, value) in flags {
+        match name {
+            "base-url" => base_url = value,
+            "delay" => delay = value.parse()
+                .ok()
+                .and_then(|s| Duration::try_from_secs_f64(s).ok())
+                .ok_or_else(|| format!("Invalid --{name}: {value}"))?,
+            "cache" => cache = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown flag --{name}\n\n{USAGE}")),
+        }
+    }
+
+    let days = download::days(&aoc::root(), year, day)
+        .map_err(|e| format!("Could not find days: {e}"))?;
+    let missing: Vec<_> = days.into_iter().filter(|d| d.needs_input()).collect();
+    if missing.is_empty() {
+        println!("No inputs missing");
+        return Ok(());
+    }
+
+    let session = env::var("ADVENT_OF_CODE_SESSION").ok().filter(|s| !s.is_empty());
+    let mut downloader = Downloader::new(base_url, session, cache, delay);
+
+    for day in &missing {
+        let status = match downloader.download(day) {
+            Ok(Source::Cache) => String::from("restored from cache"),
+            Ok(Source::Server) => String::from("downloaded"),
+            // Every other day would fail the same way
+            Err(e @ (DownloadError::NoSession | DownloadError::NotLoggedIn)) => return Err(e.to_string()),
+            Err(e) => e.to_string(),
+        };
+        println!("{:>4}  {:>3}  {status}", day.year, day.day);
+    }
+
+    Ok(())
+}
+
+fn new(year: u16, day: u8, flags: &Flags) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day: {day}"));
+    }
+
+    scaffold::create(&aoc::root(), &scaffold::templates(), year, day)
+        .map_err(|e| format!("Could not create day: {e}"))?;
+    println!("Created {year}/day{day:02}, run it with cargo run -p day{day:02}_{year}");
+
+    // Other flags are passed on to the download
+    let download_flags: Flags = flags.iter().copied().filter(|&(name, _)| name != "download").collect();
+    if download_flags.len() < flags.len() {
+        download(Some(year), Some(day), &download_flags)
+    } else if let Some((name, _)) = flags.first() {
+        Err(format!("--{name} is only used with --download"))
+    } else {
+        Ok(())
+    }
+}
+
+fn parse_arg<T: std::str::FromStr>(arg: Option<&String>, name: &str) -> Result<Option<T>, String> {
+    arg.map(|a| a.parse().map_err(|_| format!("Invalid {name}: {a}")))
+        .transpose()
+}
+
+/// Names and values of `--name value` flags, switches without a value have an empty value.
+type Flags<'a> = Vec<(&'a str, &'a str)>;
+
+/// Splits the arguments into positional arguments and flags.
+fn split_flags<'a>(args: &'a [String], switches: &[&str]) -> Result<(Vec<String>, Flags<'a>), String> {
+    let mut positional = Vec::new();
+    let mut flags = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if let Some(name) = arg.strip_prefix("--") {
+            if switches.contains(&name) {
+                flags.push((name, ""));
+                continue;
+            }
+            let value = args.next().ok_or_else(|| format!("Missing value of --{name}"))?;
+            flags.push((name, value.as_str()));
+        } else {
+            positional.push(arg.clone());
+        }
+    }
+
+    Ok((positional, flags))
+}
+
+fn bench_options(flags: &Flags) -> Result<BenchOptions, String> {
+    let mut options = BenchOptions { threshold: 0.1, ..Default::default() };
+    let invalid = |name: &str, value: &str| format!("Invalid --{name}: {value}");
+
+    for &(name, value) in flags {
+        match name {
+            "runs" => options.runs = value.parse()
+                .ok()
+                .filter(|&runs| runs > 0)
+                .map(Runs::Exactly)
+                .ok_or_else(|| invalid(name, value))?,
+            "threshold" => options.threshold = value.parse::<f64>()
+                .map_err(|_| invalid(name, value))? / 100.0,
+            "export-json" => options.export = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown flag --{name}\n\n{USAGE}")),
+        }
+    }
+
+    Ok(options)
+}
+
+fn main() {
+    let args: Vec<_> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") if args.len() <= 4 => parse_arg(args.get(1), "year")
+            .and_then(|year| Ok((year, parse_arg(args.get(2), "day")?)))
+            .map(|(year, day)| run(year, day, args.get(3).map(Path::new))),
+        Some("new") => split_flags(&args[1..], &["download"]).and_then(|(positional, flags)| {
+            let [year, day] = &positional[..] else {
+                return Err(String::from(USAGE));
+            };
+            let year = year.parse().map_err(|_| format!("Invalid year: {year}"))?;
+            let day = day.parse().map_err(|_| format!("Invalid day: {day}"))?;
+            new(year, day, &flags)
+        }),
+        Some("record") => split_flags(&args[1..], &["force"]).and_then(|(positional, flags)| {
+            let [year, day] = &positional[..] else {
+                return Err(String::from(USAGE));
+            };
+            let year = year.parse().map_err(|_| format!("Invalid year: {year}"))?;
+            let day = day.parse().map_err(|_| format!("Invalid day: {day}"))?;
+            record(year, day, &flags)
+        }),
+        Some(command @ ("bench" | "download")) => split_flags(&args[1..], &[]).and_then(|(positional, flags)| {
+            if positional.len() > 2 {
+                return Err(String::from(USAGE));
+            }
+            let year = parse_arg(positional.first(), "year")?;
+            let day = parse_arg(positional.get(1), "day")?;
+            match command {
+                "bench" => bench(year, day, &bench_options(&flags)?),
+                _ => download(year, day, &flags),
+            }
+        }),
+        _ => Err(String::from(USAGE)),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,174 @@
+use aoc::Puzzle;
+
+
+/// Every solved day, in chronological order.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day01_2019::Day01>(),
+    Puzzle::new::<day02_2019::Day02>(),
+    Puzzle::new::<day03_2019::Day03>(),
+    Puzzle::new::<day04_2019::Day04>(),
+    Puzzle::new::<day05_2019::Day05>(),
+    Puzzle::new::<day06_2019::Day06>(),
+    Puzzle::new::<day07_2019::Day07>(),
+    Puzzle::new::<day08_2019::Day08>(),
+    Puzzle::new::<day09_2019::Day09>(),
+    Puzzle::new::<day10_2019::Day10>(),
+    Puzzle::new::<day11_2019::Day11>(),
+    Puzzle::new::<day12_2019::Day12>(),
+    Puzzle::new::<day13_2019::Day13>(),
+    Puzzle::new::<day14_2019::Day14>(),
+    Puzzle::new::<day15_2019::Day15>(),
+    Puzzle::new::<day16_2019::Day16>(),
+    Puzzle::new::<day17_2019::Day17>(),
+    Puzzle::new::<day18_2019::Day18>(),
+    Puzzle::new::<day19_2019::Day19>(),
+    Puzzle::new::<day20_2019::Day20>(),
+    Puzzle::new::<day21_2019::Day21>(),
+    Puzzle::new::<day22_2019::Day22>(),
+    Puzzle::new::<day23_2019::Day23>(),
+    Puzzle::new::<day24_2019::Day24>(),
+    Puzzle::new::<day25_2019::Day25>(),
+
+    Puzzle::new::<day01_2020::Day01>(),
+    Puzzle::new::<day02_2020::Day02>(),
+    Puzzle::new::<day03_2020::Day03>(),
+    Puzzle::new::<day04_2020::Day04>(),
+    Puzzle::new::<day05_2020::Day05>(),
+    Puzzle::new::<day06_2020::Day06>(),
+    Puzzle::new::<day07_2020::Day07>(),
+    Puzzle::new::<day08_2020::Day08>(),
+    Puzzle::new::<day09_2020::Day09>(),
+    Puzzle::new::<day10_2020::Day10>(),
+    Puzzle::new::<day11_2020::Day11>(),
+    Puzzle::new::<day12_2020::Day12>(),
+    Puzzle::new::<day13_2020::Day13>(),
+    Puzzle::new::<day14_2020::Day14>(),
+    Puzzle::new::<day15_2020::Day15>(),
+    Puzzle::new::<day16_2020::Day16>(),
+    Puzzle::new::<day17_2020::Day17>(),
+    Puzzle::new::<day19_2020::Day19>(),
+    Puzzle::new::<day20_2020::Day20>(),
+    Puzzle::new::<day21_2020::Day21>(),
+    Puzzle::new::<day22_2020::Day22>(),
+    Puzzle::new::<day23_2020::Day23>(),
+    Puzzle::new::<day24_2020::Day24>(),
+    Puzzle::new::<day25_2020::Day25>(),
+
+    Puzzle::new::<day01_2021::Day01>(),
+    Puzzle::new::<day02_2021::Day02>(),
+    Puzzle::new::<day03_2021::Day03>(),
+    Puzzle::new::<day04_2021::Day04>(),
+    Puzzle::new::<day05_2021::Day05>(),
+    Puzzle::new::<day06_2021::Day06>(),
+    Puzzle::new::<day07_2021::Day07>(),
+    Puzzle::new::<day08_2021::Day08>(),
+    Puzzle::new::<day09_2021::Day09>(),
+    Puzzle::new::<day10_2021::Day10>(),
+    Puzzle::new::<day11_2021::Day11>(),
+    Puzzle::new::<day12_2021::Day12>(),
+    Puzzle::new::<day13_2021::Day13>(),
+    Puzzle::new::<day14_2021::Day14>(),
+    Puzzle::new::<day15_2021::Day15>(),
+    Puzzle::new::<day16_2021::Day16>(),
+    Puzzle::new::<day17_2021::Day17>(),
+    Puzzle::new::<day18_2021::Day18>(),
+    Puzzle::new::<day19_2021::Day19>(),
+    Puzzle::new::<day20_2021::Day20>(),
+    Puzzle::new::<day21_2021::Day21>(),
+    Puzzle::new::<day22_2021::Day22>(),
+    Puzzle::new::<day23_2021::Day23>(),
+    Puzzle::new::<day24_2021::Day24>(),
+    Puzzle::new::<day25_2021::Day25>(),
+
+    Puzzle::new::<day01_2022::Day01>(),
+    Puzzle::new::<day02_2022::Day02>(),
+    Puzzle::new::<day03_2022::Day03>(),
+    Puzzle::new::<day04_2022::Day04>(),
+    Puzzle::new::<day05_2022::Day05>(),
+    Puzzle::new::<day06_2022::Day06>(),
+    Puzzle::new::<day07_2022::Day07>(),
+    Puzzle::new::<day08_2022::Day08>(),
+    Puzzle::new::<day09_2022::Day09>(),
+    Puzzle::new::<day10_2022::Day10>(),
+    Puzzle::new::<day11_2022::Day11>(),
+    Puzzle::new::<day12_2022::Day12>(),
+    Puzzle::new::<day13_2022::Day13>(),
+    Puzzle::new::<day14_2022::Day14>(),
+    Puzzle::new::<day15_2022::Day15>(),
+    Puzzle::new::<day16_2022::Day16>(),
+    Puzzle::new::<day17_2022::Day17>(),
+    Puzzle::new::<day18_2022::Day18>(),
+    Puzzle::new::<day19_2022::Day19>(),
+    Puzzle::new::<day20_2022::Day20>(),
+    Puzzle::new::<day21_2022::Day21>(),
+    Puzzle::new::<day22_2022::Day22>(),
+    Puzzle::new::<day23_2022::Day23>(),
+    Puzzle::new::<day24_2022::Day24>(),
+    Puzzle::new::<day25_2022::Day25>(),
+
+    Puzzle::new::<day01_2023::Day01>(),
+    Puzzle::new::<day02_2023::Day02>(),
+    Puzzle::new::<day03_2023::Day03>(),
+    Puzzle::new::<day04_2023::Day04>(),
+    Puzzle::new::<day05_2023::Day05>(),
+    Puzzle::new::<day06_2023::Day06>(),
+    Puzzle::new::<day07_2023::Day07>(),
+    Puzzle::new::<day08_2023::Day08>(),
+    Puzzle::new::<day09_2023::Day09>(),
+    Puzzle::new::<day10_2023::Day10>(),
+    Puzzle::new::<day11_2023::Day11>(),
+    Puzzle::new::<day12_2023::Day12>(),
+    Puzzle::new::<day13_2023::Day13>(),
+    Puzzle::new::<day14_2023::Day14>(),
+    Puzzle::new::<day15_2023::Day15>(),
+    Puzzle::new::<day16_2023::Day16>(),
+    Puzzle::new::<day17_2023::Day17>(),
+    Puzzle::new::<day18_2023::Day18>(),
+    Puzzle::new::<day19_2023::Day19>(),
+    Puzzle::new::<day20_2023::Day20>(),
+    Puzzle::new::<day21_2023::Day21>(),
+    Puzzle::new::<day22_2023::Day22>(),
+    Puzzle::new::<day23_2023::Day23>(),
+    Puzzle::new::<day24_2023::Day24>(),
+    Puzzle::new::<day25_2023::Day25>(),
+
+    Puzzle::new::<day01_2024::Day01>(),
+    Puzzle::new::<day02_2024::Day02>(),
+    Puzzle::new::<day03_2024::Day03>(),
+    Puzzle::new::<day04_2024::Day04>(),
+    Puzzle::new::<day05_2024::Day05>(),
+    Puzzle::new::<day06_2024::Day06>(),
+    Puzzle::new::<day07_2024::Day07>(),
+    Puzzle::new::<day08_2024::Day08>(),
+    Puzzle::new::<day09_2024::Day09>(),
+    Puzzle::new::<day10_2024::Day10>(),
+    Puzzle::new::<day11_2024::Day11>(),
+    Puzzle::new::<day12_2024::Day12>(),
+    Puzzle::new::<day13_2024::Day13>(),
+    Puzzle::new::<day14_2024::Day14>(),
+    Puzzle::new::<day15_2024::Day15>(),
+    Puzzle::new::<day16_2024::Day16>(),
+    Puzzle::new::<day17_2024::Day17>(),
+    Puzzle::new::<day18_2024::Day18>(),
+    Puzzle::new::<day19_2024::Day19>(),
+    Puzzle::new::<day20_2024::Day20>(),
+    Puzzle::new::<day21_2024::Day21>(),
+    Puzzle::new::<day22_2024::Day22>(),
+    Puzzle::new::<day23_2024::Day23>(),
+    Puzzle::new::<day24_2024::Day24>(),
+    Puzzle::new::<day25_2024::Day25>(),
+
+    Puzzle::new::<day01_2025::Day01>(),
+    Puzzle::new::<day02_2025::Day02>(),
+    Puzzle::new::<day03_2025::Day03>(),
+    Puzzle::new::<day04_2025::Day04>(),
+    Puzzle::new::<day05_2025::Day05>(),
+    Puzzle::new::<day06_2025::Day06>(),
+    Puzzle::new::<day07_2025::Day07>(),
+    Puzzle::new::<day08_2025::Day08>(),
+    Puzzle::new::<day09_2025::Day09>(),
+    #[cfg(feature = "highs")]
+    Puzzle::new::<day10_2025::Day10>(),
+    Puzzle::new::<day11_2025::Day11>(),
+    Puzzle::new::<day12_2025::Day12>(),
+];
@@ -0,0 +1,274 @@
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Io(io::Error),
+    /// The day already has a crate.
+    Exists(PathBuf),
+    /// A file of the workspace does not look as expected.
+    Unexpected(PathBuf, &'static str),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Io(e) => write!(f, "{e}"),
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Unexpected(path, e) => write!(f, "{}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        ScaffoldError::Io(e)
+    }
+}
+
+/// Templates of a new day in the repository.
+///
+/// Every `.tmpl` file is copied to the crate of the day without its extension,
+/// with `{{year}}`, `{{day}}` and `{{day:02}}` replaced.
+pub fn templates() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("templates/day")
+}
+
+pub fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day:02}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Creates the crate of a new day from the templates, and adds it to the workspace and the runner.
+/// Returns the directory of the new crate.
+///
+/// Nothing is left behind when a step fails: the workspace files are only written once the crate exists,
+/// and the files of the crate are removed again when writing them fails.
+pub fn create(root: &Path, templates: &Path, year: u16, day: u8) -> Result<PathBuf, ScaffoldError> {
+    let dir = root.join(year.to_string()).join(format!("day{day:02}"));
+    if dir.join("Cargo.toml").exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    let runner = root.join("common/runner");
+    let updates = [
+        Update::new(root.join("Cargo.toml"), |manifest| add_member(manifest, year))?,
+        Update::new(runner.join("Cargo.toml"), |manifest| add_dependency(manifest, year, day))?,
+        Update::new(runner.join("src/puzzles.rs"), |puzzles| add_puzzle(puzzles, year, day))?,
+    ];
+
+    let mut created = Vec::new();
+    let result = copy_templates(templates, &dir, year, day, &mut created)
+        .and_then(|()| Update::write_all(&updates));
+    if result.is_err() {
+        // Newest first, so directories are empty of new files by the time they are removed
+        for path in created.iter().rev() {
+            let _ = if path.is_dir() { fs::remove_dir_all(path) } else { fs::remove_file(path) };
+        }
+    }
+
+    result.map(|()| dir)
+}
+
+/// Copies the templates to the directory, and records the files and directories it creates.
+fn copy_templates(templates: &Path, dir: &Path, year: u16, day: u8, created: &mut Vec<PathBuf>) -> Result<(), ScaffoldError> {
+    if let Some(new) = dir.ancestors().take_while(|d| !d.exists()).last() {
+        created.push(new.to_path_buf());
+        fs::create_dir_all(dir)?;
+    }
+
+    for entry in fs::read_dir(templates)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap().to_string_lossy();
+
+        if path.is_dir() {
+            copy_templates(&path, &dir.join(&*name), year, day, created)?;
+        } else if let Some(name) = name.strip_suffix(".tmpl") {
+            let target = dir.join(name);
+            // Keep files which are already there, like a downloaded input
+            if !target.exists() {
+                created.push(target.clone());
+                fs::write(target, fill(&fs::read_to_string(&path)?, year, day))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// File of the workspace, with its content before and after adding a day.
+struct Update {
+    path: PathBuf,
+    content: String,
+    updated: String,
+}
+
+impl Update {
+    fn new(path: PathBuf, f: impl Fn(&str) -> Result<String, &'static str>) -> Result<Self, ScaffoldError> {
+        let content = fs::read_to_string(&path)?;
+        let updated = f(&content).map_err(|e| ScaffoldError::Unexpected(path.clone(), e))?;
+        Ok(Self { path, content, updated })
+    }
+
+    /// Writes the changed files, and restores the ones already written when one fails.
+    fn write_all(updates: &[Update]) -> Result<(), ScaffoldError> {
+        let changed: Vec<_> = updates.iter().filter(|u| u.updated != u.content).collect();
+        for (i, update) in changed.iter().enumerate() {
+            if let Err(e) = fs::write(&update.path, &update.updated) {
+                for written in &changed[..i] {
+                    let _ = fs::write(&written.path, &written.content);
+                }
+                return Err(e.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds `<year>/*` to the members of the workspace, unless it is already a member.
+pub fn add_member(manifest: &str, year: u16) -> Result<String, &'static str> {
+    let glob = format!("{year}/*");
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+
+    let line = lines.iter_mut()
+        .find(|l| l.trim_start().starts_with("members"))
+        .ok_or("No workspace members")?;
+    // Keep what surrounds the list, like a comment after it
+    let (start, list, end) = line.split_once('[')
+        .and_then(|(start, rest)| rest.split_once(']').map(|(list, end)| (start, list, end)))
+        .ok_or("Workspace members should be on a single line")?;
+
+    let mut members: Vec<_> = list.split(',')
+        .map(|m| m.trim().trim_matches('"').to_owned())
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&glob) {
+        return Ok(manifest.to_owned());
+    }
+
+    // Shared crates first, then the years in order
+    members.push(glob);
+    members.sort_by_key(|m| (m.as_bytes()[0].is_ascii_digit(), m.clone()));
+
+    let members: Vec<_> = members.iter().map(|m| format!("\"{m}\"")).collect();
+    *line = format!("{start}[{}]{end}", members.join(", "));
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day as path dependency of the runner.
+pub fn add_dependency(manifest: &str, year: u16, day: u8) -> Result<String, &'static str> {
+    let line = format!("day{day:02}_{year} = {{ path = \"../../{year}/day{day:02}\" }}");
+    insert_sorted(manifest, line, (year, day), false)
+}
+
+/// Adds the day to the list of puzzles of the runner, in which the years are separated by a blank line.
+pub fn add_puzzle(puzzles: &str, year: u16, day: u8) -> Result<String, &'static str> {
+    let line = format!("    Puzzle::new::<day{day:02}_{year}::Day{day:02}>(),");
+    insert_sorted(puzzles, line, (year, day), true)
+}
+
+/// Year and day of a line mentioning a day crate, like `day05_2024 = ...` or `Puzzle::new::<day05_2024::Day05>(),`.
+fn day_of(line: &str) -> Option<(u16, u8)> {
+    let line = line.trim_start();
+    let name = line.strip_prefix("Puzzle::new::<").unwrap_or(line).get(..10)?;
+    let (day, year) = name.strip_prefix("day")?.split_once('_')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Inserts the line of a day between the lines of the days before and after it.
+fn insert_sorted(text: &str, line: String, key: (u16, u8), separate_years: bool) -> Result<String, &'static str> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let days: Vec<(usize, (u16, u8))> = lines.iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, day_of(l)?)))
+        .collect();
+
+    if days.iter().any(|&(_, k)| k == key) {
+        return Ok(text.to_owned());
+    }
+    let before = days.iter().rev().find(|&&(_, k)| k < key).copied();
+    let after = days.iter().find(|&&(_, k)| k > key).copied();
+
+    // Attributes like `#[cfg(...)]` belong to the day after them
+    let after = after.map(|(mut i, k)| {
+        while i > 0 && lines[i - 1].trim_start().starts_with("#[") {
+            i -= 1;
+        }
+        (i, k)
+    });
+
+    let same_year = |day: Option<(usize, (u16, u8))>| day.is_some_and(|(_, (year, _))| year == key.0);
+    match (before, after) {
+        (_, Some((i, _))) if !separate_years || same_year(after) => lines.insert(i, line),
+        (Some((i, _)), _) if !separate_years || same_year(before) => lines.insert(i + 1, line),
+        // First day of a new year
+        (Some((i, _)), _) => lines.splice(i + 1..i + 1, [String::new(), line]).for_each(drop),
+        (None, Some((i, _))) => lines.splice(i..i, [line, String::new()]).for_each(drop),
+        (None, None) => return Err("No days to insert after"),
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn members() {
+        let manifest = "[workspace]\nmembers = [\"common/*\", \"2024/*\"]\nresolver = \"2\"\n";
+        assert_eq!(add_member(manifest, 2024).unwrap(), manifest);
+        assert_eq!(
+            add_member(manifest, 2023).unwrap(),
+            "[workspace]\nmembers = [\"common/*\", \"2023/*\", \"2024/*\"]\nresolver = \"2\"\n",
+        );
+
+        let commented = "[workspace]\n  members = [\"common/*\", \"2024/*\"] # See [the docs]\n";
+        assert_eq!(
+            add_member(commented, 2025).unwrap(),
+            "[workspace]\n  members = [\"common/*\", \"2024/*\", \"2025/*\"] # See [the docs]\n",
+        );
+    }
+
+    #[test]
+    pub fn puzzles() {
+        let puzzles = "\
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day01_2024::Day01>(),
+    Puzzle::new::<day03_2024::Day03>(),
+
+    Puzzle::new::<day01_2025::Day01>(),
+];
+";
+        assert_eq!(add_puzzle(puzzles, 2024, 3).unwrap(), puzzles);
+        assert_eq!(add_puzzle(puzzles, 2024, 2).unwrap(), puzzles.replace(
+            "Day01>(),\n    Puzzle::new::<day03",
+            "Day01>(),\n    Puzzle::new::<day02_2024::Day02>(),\n    Puzzle::new::<day03",
+        ));
+        assert_eq!(add_puzzle(puzzles, 2025, 2).unwrap(), puzzles.replace(
+            "2025::Day01>(),\n",
+            "2025::Day01>(),\n    Puzzle::new::<day02_2025::Day02>(),\n",
+        ));
+        assert_eq!(add_puzzle(puzzles, 2026, 1).unwrap(), puzzles.replace(
+            "2025::Day01>(),\n",
+            "2025::Day01>(),\n\n    Puzzle::new::<day01_2026::Day01>(),\n",
+        ));
+        assert_eq!(add_puzzle(puzzles, 2023, 25).unwrap(), puzzles.replace(
+            "&[\n",
+            "&[\n    Puzzle::new::<day25_2023::Day25>(),\n\n",
+        ));
+
+        let optional = puzzles.replace("    Puzzle::new::<day03", "    #[cfg(feature = \"x\")]\n    Puzzle::new::<day03");
+        assert_eq!(add_puzzle(&optional, 2024, 2).unwrap(), optional.replace(
+            "2024::Day01>(),\n",
+            "2024::Day01>(),\n    Puzzle::new::<day02_2024::Day02>(),\n",
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+
+use runner::PUZZLES;
+use runner::answers::Answers;
+
+
+/// Solves every input with recorded answers, and compares both parts.
+/// Inputs without answers, or whose input file is missing, are skipped.
+/// With `AOC_STRICT_ANSWERS` set, a puzzle input without recorded answers fails instead.
+#[test]
+pub fn recorded_answers() {
+    let strict = env::var_os("AOC_STRICT_ANSWERS").is_some();
+    let mut checked = 0;
+    let mut skipped = 0;
+    let mut failures = Vec::new();
+
+    for puzzle in PUZZLES {
+        let path = puzzle.input_path();
+        let answers = Answers::load(puzzle).unwrap_or_else(|e| panic!("Invalid answers {e}"));
+
+        let has_input = fs::read_to_string(&path).is_ok_and(|input| !input.trim().is_empty());
+        if has_input && answers.get("input.txt").is_none() {
+            if strict {
+                failures.push(format!("{} day {:2}: no recorded answers of the input, run `aoc record`", puzzle.year, puzzle.day));
+            } else {
+                skipped += 1;
+            }
+        }
+        if answers.inputs.is_empty() {
+            skipped += 1;
+            continue;
+        }
+
+        for recorded in answers.inputs {
+            let name = format!("{} day {:2} {}", puzzle.year, puzzle.day, recorded.file);
+
+            let input = match fs::read_to_string(path.with_file_name(&recorded.file)) {
+                Ok(input) if !input.trim().is_empty() => input,
+                _ => {
+                    skipped += 1;
+                    continue;
+                },
+            };
+
+            let Ok(report) = panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(&input))) else {
+                failures.push(format!("{name}: panicked"));
+                continue;
+            };
+
+            let parts = [(1, recorded.part1, report.part1.value), (2, recorded.part2, report.part2.value)];
+            for (part, expected, answer) in parts {
+                if let Some(expected) = expected
+                    && expected != answer
+                {
+                    failures.push(format!("{name} part {part}: expected {expected:?}, got {answer:?}"));
+                }
+            }
+            checked += 1;
+        }
+    }
+
+    println!("{checked} inputs checked, {skipped} skipped");
+    assert!(failures.is_empty(), "Answers changed or missing:\n{}", failures.join("\n"));
+}
@@ -0,0 +1,121 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use std::{fs, process, thread};
+
+use runner::download::{self, DownloadError, Downloader, Source};
+
+
+#[derive(Debug, Clone)]
+struct Request {
+    path: String,
+    cookie: String,
+    user_agent: String,
+    time: Instant,
+}
+
+/// Stand-in for the advent of code server, serving `<year> <day>` as input of every day up to 25.
+/// Returns its base URL and the requests it received.
+fn serve() -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut lines = BufReader::new(&stream).lines().map(Result::unwrap);
+
+            let path = lines.next().unwrap().split(' ').nth(1).unwrap().to_owned();
+            let mut request = Request { path, cookie: String::new(), user_agent: String::new(), time: Instant::now() };
+            for line in lines.take_while(|l| !l.is_empty()) {
+                let (name, value) = line.split_once(": ").unwrap();
+                match name.to_ascii_lowercase().as_str() {
+                    "cookie" => request.cookie = value.to_owned(),
+                    "user-agent" => request.user_agent = value.to_owned(),
+                    _ => {},
+                }
+            }
+
+            let parts: Vec<_> = request.path.split('/').collect();
+            let (status, body) = match parts[..] {
+                _ if request.cookie == "session=invalid" => ("200 OK", String::from("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")),
+                ["", year, "day", day, "input"] if day.parse::<u8>().is_ok_and(|d| d <= 25) => ("200 OK", format!("{year} {day}\n")),
+                _ => ("404 Not Found", String::from("404 Not Found\n")),
+            };
+            received.lock().unwrap().push(request);
+
+            write!(stream, "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+        }
+    });
+
+    (url, requests)
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-download-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+pub fn downloads_missing_inputs() {
+    let (url, requests) = serve();
+    let root = temp_dir("root");
+    let cache = temp_dir("cache");
+
+    for day in ["day01", "day02", "day03"] {
+        fs::create_dir_all(root.join("2024").join(day)).unwrap();
+    }
+    fs::create_dir_all(root.join("2024/notes")).unwrap();
+    fs::create_dir_all(root.join("scripts")).unwrap();
+    fs::write(root.join("2024/day01/input.txt"), "").unwrap();
+    fs::write(root.join("2024/day02/input.txt"), "mine").unwrap();
+
+    let days = download::days(&root, None, None).unwrap();
+    assert_eq!(days.iter().map(|d| (d.year, d.day)).collect::<Vec<_>>(), [(2024, 1), (2024, 2), (2024, 3)]);
+
+    let missing: Vec<_> = days.into_iter().filter(|d| d.needs_input()).collect();
+    let delay = Duration::from_millis(100);
+    let mut downloader = Downloader::new(&url, Some(String::from("token")), Some(cache.clone()), delay);
+    for day in &missing {
+        assert_eq!(downloader.download(day).unwrap(), Source::Server);
+    }
+
+    assert_eq!(fs::read_to_string(root.join("2024/day01/input.txt")).unwrap(), "2024 1\n");
+    assert_eq!(fs::read_to_string(root.join("2024/day02/input.txt")).unwrap(), "mine");
+    assert_eq!(fs::read_to_string(root.join("2024/day03/input.txt")).unwrap(), "2024 3\n");
+
+    let received = requests.lock().unwrap().clone();
+    assert_eq!(received.iter().map(|r| r.path.as_str()).collect::<Vec<_>>(), ["/2024/day/1/input", "/2024/day/3/input"]);
+    assert!(received.iter().all(|r| r.cookie == "session=token" && r.user_agent.starts_with("aoc-runner/")));
+    assert!(received[1].time - received[0].time >= delay);
+
+    // Cached inputs are not requested again, and need no session
+    fs::remove_file(root.join("2024/day03/input.txt")).unwrap();
+    let mut downloader = Downloader::new(&url, None, Some(cache.clone()), delay);
+    assert_eq!(downloader.download(&missing[1]).unwrap(), Source::Cache);
+    assert_eq!(fs::read_to_string(root.join("2024/day03/input.txt")).unwrap(), "2024 3\n");
+    assert_eq!(requests.lock().unwrap().len(), 2);
+
+    fs::remove_dir_all(root).unwrap();
+    fs::remove_dir_all(cache).unwrap();
+}
+
+#[test]
+pub fn errors() {
+    let (url, _) = serve();
+
+    let mut downloader = Downloader::new(&url, Some(String::from("token")), None, Duration::ZERO);
+    assert!(matches!(downloader.input(2024, 26), Err(DownloadError::Status(404, _))));
+
+    let mut downloader = Downloader::new(&url, Some(String::from("invalid")), None, Duration::ZERO);
+    assert!(matches!(downloader.input(2024, 1), Err(DownloadError::NotLoggedIn)));
+
+    let mut downloader = Downloader::new(&url, None, None, Duration::ZERO);
+    assert!(matches!(downloader.input(2024, 1), Err(DownloadError::NoSession)));
+}
@@ -0,0 +1,79 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use runner::scaffold::{self, ScaffoldError};
+
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Workspace with a single day in the runner, of which the puzzles are given.
+fn workspace(name: &str, puzzles: &str) -> PathBuf {
+    let root = temp_dir(name);
+    fs::create_dir_all(root.join("common/runner/src")).unwrap();
+    fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\"common/*\", \"2024/*\"]\n").unwrap();
+    fs::write(root.join("common/runner/Cargo.toml"), "[dependencies]\nday01_2024 = { path = \"../../2024/day01\" }\n").unwrap();
+    fs::write(root.join("common/runner/src/puzzles.rs"), puzzles).unwrap();
+    root
+}
+
+fn files(root: &Path) -> Vec<(PathBuf, String)> {
+    ["Cargo.toml", "common/runner/Cargo.toml", "common/runner/src/puzzles.rs"].into_iter()
+        .map(|f| (root.join(f), fs::read_to_string(root.join(f)).unwrap()))
+        .collect()
+}
+
+#[test]
+pub fn creates_day() {
+    let root = workspace("created", "&[\n    Puzzle::new::<day01_2024::Day01>(),\n];\n");
+
+    let dir = scaffold::create(&root, &scaffold::templates(), 2025, 1).unwrap();
+    assert!(dir.join("Cargo.toml").exists() && dir.join("src/lib.rs").exists());
+    assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().contains("\"2025/*\""));
+    assert!(fs::read_to_string(root.join("common/runner/src/puzzles.rs")).unwrap().contains("day01_2025::Day01"));
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+pub fn leaves_nothing_behind() {
+    // The runner has no puzzles to insert the day next to
+    let root = workspace("unexpected", "&[\n];\n");
+    let before = files(&root);
+
+    let e = scaffold::create(&root, &scaffold::templates(), 2025, 1).unwrap_err();
+    assert!(matches!(e, ScaffoldError::Unexpected(..)));
+    assert!(!root.join("2025").exists());
+    assert_eq!(files(&root), before);
+
+    // The templates are missing, after the directory of the day was made
+    let root = workspace("templates", "&[\n    Puzzle::new::<day01_2024::Day01>(),\n];\n");
+    let before = files(&root);
+    fs::create_dir_all(root.join("2025/day01")).unwrap();
+    fs::write(root.join("2025/day01/input.txt"), "mine").unwrap();
+
+    let e = scaffold::create(&root, &root.join("missing"), 2025, 2).unwrap_err();
+    assert!(matches!(e, ScaffoldError::Io(_)));
+    assert!(!root.join("2025/day02").exists());
+    assert_eq!(files(&root), before);
+
+    // A template fails, possibly after another one was copied into the directory which already existed
+    let templates = temp_dir("broken-templates");
+    fs::write(templates.join("a.tmpl"), "{{year}}").unwrap();
+    fs::create_dir_all(templates.join("z")).unwrap();
+    fs::write(templates.join("z/c.tmpl"), [0xff]).unwrap();
+
+    let e = scaffold::create(&root, &templates, 2025, 1).unwrap_err();
+    assert!(matches!(e, ScaffoldError::Io(_)));
+    let left: Vec<_> = fs::read_dir(root.join("2025/day01")).unwrap().map(|e| e.unwrap().file_name()).collect();
+    assert_eq!(left, ["input.txt"]);
+    assert_eq!(files(&root), before);
+
+    fs::remove_dir_all(root).unwrap();
+    fs::remove_dir_all(templates).unwrap();
+}
@@ -0,0 +1,132 @@
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use hashbrown::hash_map::Entry;
+use hashbrown::{HashMap, HashSet};
+
+use crate::dijkstra::HeapItem;
+use crate::path::Cost;
+
+
+/// Goals reached by all of the cheapest paths.
+#[derive(Debug, Clone)]
+pub struct AllPaths<N, C> {
+    /// Goals which can be reached at the lowest cost.
+    pub goals: Vec<N>,
+    pub cost: C,
+    /// Parents on a cheapest path, and the cost, of every node which was reached.
+    predecessors: HashMap<N, (Vec<N>, C)>,
+}
+
+impl<N: Clone + Eq + Hash, C> AllPaths<N, C> {
+    /// Nodes which are on any of the cheapest paths to one of the goals.
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors[&node].0.iter().cloned());
+            }
+        }
+
+        nodes
+    }
+}
+
+/// All of the cheapest paths from the starts to the goals, where the successors come with the cost of the step.
+///
+/// Goals are not explored further, so paths never go through a goal.
+pub fn dijkstra_all<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<AllPaths<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut predecessors: HashMap<N, (Vec<N>, C)> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        predecessors.insert(start.clone(), (Vec::new(), C::default()));
+        heap.push(HeapItem { estimate: C::default(), cost: C::default(), node: start });
+    }
+
+    let mut goals = Vec::new();
+    while let Some(HeapItem { cost, node, .. }) = heap.pop() {
+        // Skip when a cheaper path to the node was found after pushing it
+        if predecessors[&node].1 < cost {
+            continue;
+        }
+
+        match goals.first() {
+            Some(goal) if predecessors[goal].1 < cost => break,
+            _ if is_goal(&node) => {
+                goals.push(node);
+                continue;
+            },
+            _ => {},
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            match predecessors.entry(next.clone()) {
+                Entry::Occupied(mut e) => {
+                    let (parents, best) = e.get_mut();
+                    if next_cost > *best {
+                        continue;
+                    }
+
+                    if next_cost == *best {
+                        // Another cheapest path, which does not need to be explored again
+                        parents.push(node.clone());
+                        continue;
+                    }
+
+                    *parents = vec![node.clone()];
+                    *best = next_cost;
+                },
+                Entry::Vacant(e) => {
+                    e.insert((vec![node.clone()], next_cost));
+                },
+            }
+
+            heap.push(HeapItem { estimate: next_cost, cost: next_cost, node: next });
+        }
+    }
+
+    let cost = predecessors[goals.first()?].1;
+    Some(AllPaths { goals, cost, predecessors })
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn all_cheapest() {
+        // Two paths of cost 4 from a to e, one of cost 5, and a second goal f at cost 4
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 1), ('c', 2), ('d', 1)],
+            'b' => vec![('e', 3)],
+            'c' => vec![('e', 2), ('f', 2)],
+            'd' => vec![('e', 4)],
+            _ => vec![],
+        };
+
+        let paths = dijkstra_all(['a'], edges, |&n| n == 'e').unwrap();
+        assert_eq!(paths.cost, 4);
+        assert_eq!(paths.goals, ['e']);
+        assert_eq!(paths.nodes(), HashSet::from(['a', 'b', 'c', 'e']));
+
+        let paths = dijkstra_all(['a'], edges, |&n| n == 'e' || n == 'f').unwrap();
+        let mut goals = paths.goals.clone();
+        goals.sort();
+        assert_eq!(goals, ['e', 'f']);
+        assert_eq!(paths.nodes(), HashSet::from(['a', 'b', 'c', 'e', 'f']));
+
+        assert!(dijkstra_all(['e'], edges, |&n| n == 'a').is_none());
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+use hashbrown::hash_map::Entry;
+
+use crate::path::Reached;
+
+
+/// Breadth first search from the starts until a goal, in which every step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Reached<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if parents.insert(start.clone(), (None, 0)).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(Reached { goal: node, cost: steps, parents });
+        }
+
+        for next in successors(&node) {
+            // Nodes are reached first by a shortest path
+            if let Entry::Vacant(e) = parents.entry(next.clone()) {
+                e.insert((Some(node.clone()), steps + 1));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn shortest() {
+        // Doubling or adding one, from 1 to 10
+        let reached = bfs([1], |&n| [n + 1, 2 * n], |&n| n == 10).unwrap();
+        assert_eq!(reached.cost, 4);
+        assert_eq!(reached.path(), [1, 2, 4, 5, 10]);
+
+        assert!(bfs([1], |&n| (n < 5).then_some(n + 1), |&n| n == 10).is_none());
+        assert_eq!(bfs([3, 8], |&n| [n + 1], |&n| n == 10).unwrap().path(), [8, 9, 10]);
+    }
+}
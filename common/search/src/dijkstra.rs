@@ -0,0 +1,142 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+use hashbrown::hash_map::Entry;
+
+use crate::path::{Cost, Reached};
+
+
+/// Node on the heap, ordered to pop the lowest estimated cost first.
+#[derive(Debug)]
+pub(crate) struct HeapItem<N, C> {
+    pub(crate) estimate: C,
+    pub(crate) cost: C,
+    pub(crate) node: N,
+}
+
+impl<N, C: Ord> Ord for HeapItem<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for HeapItem<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for HeapItem<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for HeapItem<N, C> {}
+
+/// Cheapest path from one of the starts to a goal, where the successors come with the cost of the step.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Reached<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Cheapest path like [`dijkstra`], which first explores the nodes with the lowest estimated total cost.
+///
+/// The heuristic estimates the remaining cost to a goal, and should never overestimate it.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Reached<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut parents: HashMap<N, (Option<N>, C)> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        parents.insert(start.clone(), (None, C::default()));
+        heap.push(HeapItem { estimate: heuristic(&start), cost: C::default(), node: start });
+    }
+
+    while let Some(HeapItem { cost, node, .. }) = heap.pop() {
+        // Skip when a cheaper path to the node was found after pushing it
+        if parents[&node].1 < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return Some(Reached { goal: node, cost, parents });
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            match parents.entry(next.clone()) {
+                Entry::Occupied(e) if e.get().1 <= next_cost => continue,
+                Entry::Occupied(mut e) => {
+                    e.insert((Some(node.clone()), next_cost));
+                },
+                Entry::Vacant(e) => {
+                    e.insert((Some(node.clone()), next_cost));
+                },
+            }
+
+            heap.push(HeapItem { estimate: next_cost + heuristic(&next), cost: next_cost, node: next });
+        }
+    }
+
+    None
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn cheapest() {
+        // Going directly from a to c is more expensive than going through b
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 2), ('c', 5)],
+            'b' => vec![('c', 1), ('d', 7)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+
+        let reached = dijkstra(['a'], edges, |&n| n == 'd').unwrap();
+        assert_eq!((reached.cost, reached.path()), (6, vec!['a', 'b', 'c', 'd']));
+        assert_eq!(reached.cost_to(&'c'), Some(3));
+
+        // Stopping at c leaves d with its cost through b, while the cheapest path goes through c
+        let reached = dijkstra(['a'], edges, |&n| n == 'c').unwrap();
+        assert_eq!(reached.cost_to(&'d'), Some(9));
+        assert!(dijkstra(['d'], edges, |&n| n == 'a').is_none());
+    }
+
+    #[test]
+    pub fn heuristic() {
+        // Walking on a line towards 20, estimating with the remaining distance
+        let mut expanded = 0;
+        let reached = astar(
+            [0i32],
+            |&n| { expanded += 1; [(n - 1, 1), (n + 1, 1)] },
+            |&n| (20 - n).abs(),
+            |&n| n == 20,
+        ).unwrap();
+
+        assert_eq!(reached.cost, 20);
+        assert_eq!(expanded, 20);
+    }
+}
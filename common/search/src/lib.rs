@@ -0,0 +1,9 @@
+pub use all_paths::{AllPaths, dijkstra_all};
+pub use bfs::bfs;
+pub use dijkstra::{astar, dijkstra};
+pub use path::{Cost, Reached};
+
+mod all_paths;
+mod bfs;
+mod dijkstra;
+mod path;
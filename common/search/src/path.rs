@@ -0,0 +1,40 @@
+use std::hash::Hash;
+use std::ops::Add;
+
+use hashbrown::HashMap;
+
+
+/// Cost of a path, like a distance or a time, of which the default is zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Goal reached by a search, with the cheapest path to it.
+#[derive(Debug, Clone)]
+pub struct Reached<N, C> {
+    pub goal: N,
+    pub cost: C,
+    /// Parent and cost of every node which was reached, the parent of a start is `None`.
+    pub(crate) parents: HashMap<N, (Option<N>, C)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Reached<N, C> {
+    /// Nodes of the path, from the start to the goal.
+    pub fn path(&self) -> Vec<N> {
+        let mut path = vec![self.goal.clone()];
+        while let Some((Some(parent), _)) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Cost of the cheapest path found to a node which was reached during the search.
+    ///
+    /// The cost is final for the nodes which were expanded, but a node which was only seen as a successor
+    /// may have a cheaper path which the search stopped before finding.
+    pub fn cost_to(&self, node: &N) -> Option<C> {
+        self.parents.get(node).map(|&(_, cost)| cost)
+    }
+}
@@ -35,13 +35,13 @@
           devShells =
             let
               globalPackages = with pkgs; [
-                aoc-cli
-
                 # For performance benchmarking
                 hyperfine
 
                 # Script shortcuts
-                (pkgs.writeScriptBin "di" (builtins.readFile ./scripts/download_current_day_puzzle_input.sh))
+                (pkgs.writeShellScriptBin "di" ''
+                  cargo run --release --quiet -p runner -- download "$(date +%Y)" "$(date +%-d)"
+                '')
               ];
             in
             {